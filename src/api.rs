@@ -1,7 +1,36 @@
 //! Types and functions to interact with the [Exercism](https://exercism.org) APIs.
+//!
+//! # Schema drift
+//!
+//! The [Exercism](https://exercism.org) APIs are undocumented and can change without notice.
+//! Models are deserialized leniently: unknown enum values are preserved in an `Unknown` variant
+//! and unknown JSON fields are ignored. Both are reported through [`tracing`] events emitted
+//! with the [`SCHEMA_DRIFT_TARGET`] target, which can be enabled to detect API changes.
+//!
+//! Clients can also be configured to compare each response against its model, reporting
+//! missing fields as well (see [`schema_drift`]).
+//!
+//! Responses that cannot be deserialized at all (for example, when a field has an unexpected
+//! type) result in an [`ApiResponseFormatError`](crate::Error::ApiResponseFormatError) instead
+//! of an [`ApiError`](crate::Error::ApiError).
 
 #[macro_use]
 pub(crate) mod detail;
 
+pub mod id;
+pub mod link;
+pub mod raw;
+pub mod request;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod schema_drift;
 pub mod v1;
 pub mod v2;
+
+pub use detail::IntoQuery;
+
+/// Target of the [`tracing`] events emitted when API responses contain unknown enum values or
+/// unknown JSON fields.
+///
+/// See [Schema drift](self#schema-drift).
+pub const SCHEMA_DRIFT_TARGET: &str = "mini_exercism::schema_drift";
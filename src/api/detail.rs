@@ -1,15 +1,24 @@
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::fmt::{Debug, Display};
 
+use bytes::Bytes;
 use derive_builder::UninitializedFieldError;
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
-use crate::Result;
+use crate::api::SCHEMA_DRIFT_TARGET;
+use crate::api::raw::{RawResponse, WithRaw};
+use crate::api::schema_drift::{SchemaDrift, SchemaDriftHandler, SchemaDriftKind};
 use crate::core::{BuildError, Credentials};
 use crate::http;
+use crate::http::header::CONTENT_TYPE;
 use crate::http::middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
 use crate::http::retry::after::{RetryAfterMiddleware, RetryAfterPolicy};
 use crate::http::retry::policies::ExponentialBackoff;
 use crate::http::{IntoUrl, Method};
+use crate::{Error, Result};
 
 pub const DEFAULT_MAX_RETRIES: u32 = 5;
 
@@ -18,6 +27,7 @@ pub struct ApiClient {
     http_client: ClientWithMiddleware,
     api_base_url: String,
     credentials: Option<Credentials>,
+    schema_drift: Option<SchemaDriftDetector>,
 }
 
 impl ApiClient {
@@ -32,12 +42,76 @@ impl ApiClient {
         self.api_base_url.as_str()
     }
 
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn with_credentials(&self, credentials: Option<Credentials>) -> Self {
+        Self {
+            http_client: self.http_client.clone(),
+            api_base_url: self.api_base_url.clone(),
+            credentials,
+            schema_drift: self.schema_drift.clone(),
+        }
+    }
+
     #[cfg_attr(not(coverage), tracing::instrument(skip(self, url), fields(url = %url), level = "debug"))]
     pub fn request<U>(&self, method: Method, url: U) -> ApiRequestBuilder
     where
         U: Display,
     {
-        ApiRequestBuilder::new(&self.http_client, method, self.api_url(url), &self.credentials)
+        self.request_to(method, url.to_string(), self.api_url(url))
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "debug"))]
+    pub fn follow(&self, default_api_base_url: &str, href: &str) -> ApiRequestBuilder {
+        match self.resolve_link(default_api_base_url, href) {
+            Some((endpoint, url)) => self.request_to(Method::GET, endpoint, url),
+            // Links to other hosts must never receive the user's API token.
+            None => self.request_to_with_credentials(Method::GET, href.into(), href.into(), &None),
+        }
+    }
+
+    fn request_to(&self, method: Method, endpoint: String, url: String) -> ApiRequestBuilder {
+        self.request_to_with_credentials(method, endpoint, url, &self.credentials)
+    }
+
+    fn request_to_with_credentials(
+        &self,
+        method: Method,
+        endpoint: String,
+        url: String,
+        credentials: &Option<Credentials>,
+    ) -> ApiRequestBuilder {
+        let schema_drift = self
+            .schema_drift
+            .clone()
+            .map(|detector| (detector, format!("{method} {endpoint}")));
+        ApiRequestBuilder::new(&self.http_client, method, url, credentials)
+            .with_schema_drift(schema_drift)
+    }
+
+    // Resolves a link returned by the API, returning its endpoint and URL. Links to the default
+    // API base URL or to the configured API base URL's host (or to their path) are resolved
+    // against the API base URL, while other paths are resolved against the website root.
+    // Absolute URLs pointing to other hosts are not resolved and return `None`.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, level = "trace"))]
+    fn resolve_link(&self, default_api_base_url: &str, href: &str) -> Option<(String, String)> {
+        let (default_root, api_path) = split_base_url(default_api_base_url);
+        let (root, _) = split_base_url(&self.api_base_url);
+        let path = match strip_path_prefix(href, default_root)
+            .or_else(|| strip_path_prefix(href, root).filter(|_| !root.is_empty()))
+        {
+            Some(path) => path,
+            None if href.starts_with('/') => href,
+            None => return None,
+        };
+
+        if let Some(endpoint) = strip_path_prefix(path, api_path) {
+            return Some((endpoint.into(), self.api_url(endpoint)));
+        }
+        let website_root = self
+            .api_base_url
+            .strip_suffix(api_path)
+            .unwrap_or(&self.api_base_url);
+        Some((path.into(), format!("{website_root}{path}")))
     }
 
     #[cfg_attr(not(coverage), tracing::instrument(skip(self, url), fields(url = %url), level = "debug"))]
@@ -48,6 +122,14 @@ impl ApiClient {
         self.request(Method::GET, url)
     }
 
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self, url), fields(url = %url), level = "debug"))]
+    pub fn patch<U>(&self, url: U) -> ApiRequestBuilder
+    where
+        U: Display,
+    {
+        self.request(Method::PATCH, url)
+    }
+
     #[cfg_attr(not(coverage), tracing::instrument(skip(self, url), fields(url = %url), ret, level = "trace"))]
     fn api_url<U>(&self, url: U) -> String
     where
@@ -57,6 +139,24 @@ impl ApiClient {
     }
 }
 
+// Splits a base URL into its root (scheme and host) and path, like
+// `("https://exercism.org", "/api/v2")`.
+fn split_base_url(base_url: &str) -> (&str, &str) {
+    let host_start = base_url.find("://").map_or(0, |index| index + 3);
+    let path_start = base_url[host_start..]
+        .find('/')
+        .map_or(base_url.len(), |index| host_start + index);
+    let (root, path) = base_url.split_at(path_start);
+    (root, path.trim_end_matches('/'))
+}
+
+// Strips `prefix` from `value` if it is followed by a path separator, query or fragment.
+fn strip_path_prefix<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .strip_prefix(prefix)
+        .filter(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']))
+}
+
 #[derive(Debug, Default)]
 pub struct ApiClientBuilder {
     http_client: Option<http::Client>,
@@ -64,6 +164,7 @@ pub struct ApiClientBuilder {
     client_with_middleware: Option<ClientWithMiddleware>,
     api_base_url: Option<String>,
     credentials: Option<Credentials>,
+    schema_drift: Option<SchemaDriftDetector>,
 }
 
 impl ApiClientBuilder {
@@ -101,6 +202,21 @@ impl ApiClientBuilder {
         self
     }
 
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn detect_schema_drift(&mut self, detect: bool) -> &mut Self {
+        self.schema_drift = match (detect, self.schema_drift.take()) {
+            (true, detector) => Some(detector.unwrap_or_default()),
+            (false, _) => None,
+        };
+        self
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, level = "trace"))]
+    pub fn on_schema_drift(&mut self, handler: SchemaDriftHandler) -> &mut Self {
+        self.schema_drift = Some(SchemaDriftDetector { handler: Some(handler) });
+        self
+    }
+
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err, level = "trace"))]
     pub fn build(&mut self) -> Result<ApiClient> {
         let api_base_url = match self.api_base_url.clone() {
@@ -119,7 +235,12 @@ impl ApiClientBuilder {
             },
         };
 
-        Ok(ApiClient { http_client, api_base_url, credentials: self.credentials.clone() })
+        Ok(ApiClient {
+            http_client,
+            api_base_url,
+            credentials: self.credentials.clone(),
+            schema_drift: self.schema_drift.clone(),
+        })
     }
 
     fn default_http_client() -> Result<http::Client> {
@@ -141,10 +262,28 @@ impl ApiClientBuilder {
     }
 }
 
+/// Trait for types that can add query parameters to a request to an [Exercism](https://exercism.org) API.
+///
+/// Implemented by the filters types of the API clients (like [`tracks::Filters`]), and by:
+///
+/// - `()`: adds no query parameters
+/// - `(&str, Option<V>)`: adds a query parameter if the value is `Some`
+/// - `(&str, Vec<V>)`: adds a query parameter for each value
+/// - `Option<Q>`: adds the query parameters of `Q` if the value is `Some`
+///
+/// [`tracks::Filters`]: crate::api::v2::tracks::Filters
 pub trait IntoQuery: Debug {
+    /// Adds query parameters to the given request.
     fn into_query(self, request: RequestBuilder) -> RequestBuilder;
 }
 
+impl IntoQuery for () {
+    #[cfg_attr(not(coverage), tracing::instrument(skip(request), level = "trace"))]
+    fn into_query(self, request: RequestBuilder) -> RequestBuilder {
+        request
+    }
+}
+
 impl<V> IntoQuery for (&str, Option<V>)
 where
     V: AsRef<str> + Debug,
@@ -225,8 +364,31 @@ impl QueryBuilder for RequestBuilder {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct SchemaDriftDetector {
+    handler: Option<SchemaDriftHandler>,
+}
+
+impl SchemaDriftDetector {
+    fn report(&self, endpoint: &str, kind: SchemaDriftKind) {
+        tracing::warn!(target: SCHEMA_DRIFT_TARGET, endpoint, %kind, "schema drift in API response");
+        if let Some(handler) = &self.handler {
+            handler(&SchemaDrift { endpoint: endpoint.into(), kind });
+        }
+    }
+}
+
+impl Debug for SchemaDriftDetector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchemaDriftDetector")
+            .field("handler", &self.handler.as_ref().map(|_| "..."))
+            .finish()
+    }
+}
+
 pub struct ApiRequestBuilder {
     request: RequestBuilder,
+    schema_drift: Option<(SchemaDriftDetector, String)>,
 }
 
 impl ApiRequestBuilder {
@@ -244,7 +406,12 @@ impl ApiRequestBuilder {
         if let Some(credentials) = credentials {
             request = request.bearer_auth(credentials.api_token());
         }
-        Self { request }
+        Self { request, schema_drift: None }
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn with_schema_drift(self, schema_drift: Option<(SchemaDriftDetector, String)>) -> Self {
+        Self { schema_drift, ..self }
     }
 
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "debug"))]
@@ -252,7 +419,20 @@ impl ApiRequestBuilder {
     where
         Q: IntoQuery,
     {
-        Self { request: query.into_query(self.request) }
+        Self { request: query.into_query(self.request), ..self }
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, level = "debug"))]
+    pub fn json<B>(self, body: &B) -> Self
+    where
+        B: Serialize + ?Sized,
+    {
+        Self { request: self.request.json(body), ..self }
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self, body), level = "debug"))]
+    pub fn body(self, content_type: &str, body: Bytes) -> Self {
+        Self { request: self.request.header(CONTENT_TYPE, content_type).body(body), ..self }
     }
 
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err, level = "debug"))]
@@ -260,25 +440,208 @@ impl ApiRequestBuilder {
         Ok(self.request.send().await?.error_for_status()?)
     }
 
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
+    pub async fn send_raw(self) -> Result<RawResponse> {
+        let response = self.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let body = response.bytes().await?;
+
+        Ok(RawResponse { status, headers, url, body })
+    }
+
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
     pub async fn execute<R>(self) -> Result<R>
     where
         R: DeserializeOwned,
     {
-        Ok(self.send().await?.json().await?)
+        Ok(self.execute_with_raw().await?.response)
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
+    pub async fn execute_with_raw<R>(self) -> Result<WithRaw<R>>
+    where
+        R: DeserializeOwned,
+    {
+        self.execute_and_detect_drift(None).await
+    }
+
+    // Same as `execute`, but for the crate's models, which can also be compared against the
+    // response to detect missing fields when schema drift detection is enabled.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
+    pub async fn execute_model<R>(self) -> Result<R>
+    where
+        R: DeserializeOwned + Serialize,
+    {
+        Ok(self.execute_model_with_raw().await?.response)
+    }
+
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
+    pub async fn execute_model_with_raw<R>(self) -> Result<WithRaw<R>>
+    where
+        R: DeserializeOwned + Serialize,
+    {
+        self.execute_and_detect_drift(Some(|response: &R| serde_json::to_value(response)))
+            .await
+    }
+
+    async fn execute_and_detect_drift<R>(
+        mut self,
+        to_value: Option<fn(&R) -> serde_json::Result<Value>>,
+    ) -> Result<WithRaw<R>>
+    where
+        R: DeserializeOwned,
+    {
+        let schema_drift = self.schema_drift.take();
+        let raw = self.send_raw().await?;
+        let body = &raw.body;
+
+        // Unknown fields are only looked for if someone is interested in them.
+        if schema_drift.is_none()
+            && !tracing::enabled!(target: SCHEMA_DRIFT_TARGET, tracing::Level::DEBUG)
+        {
+            let response = serde_json::from_slice(body).map_err(Error::ApiResponseFormatError)?;
+            return Ok(WithRaw { response, raw });
+        }
+
+        let mut unknown_fields = BTreeSet::new();
+        let (response, unknown_values) = collect_unknown_values(schema_drift.is_some(), || {
+            let mut deserializer = serde_json::Deserializer::from_slice(body);
+            serde_ignored::deserialize(&mut deserializer, |path| {
+                tracing::debug!(target: SCHEMA_DRIFT_TARGET, %path, "unknown field in API response");
+                unknown_fields.insert(drift_path(&path));
+            })
+            .and_then(|response| deserializer.end().map(|()| response))
+        });
+        let response = response.map_err(Error::ApiResponseFormatError)?;
+
+        if let Some((detector, endpoint)) = schema_drift {
+            let mut missing_fields = BTreeSet::new();
+            if let Some(to_value) = to_value
+                && let (Ok(raw), Ok(typed)) =
+                    (serde_json::from_slice::<Value>(body), to_value(&response))
+            {
+                find_missing_fields(&raw, &typed, "", &mut missing_fields);
+            }
+
+            unknown_fields
+                .into_iter()
+                .map(|path| SchemaDriftKind::UnknownField { path })
+                .chain(
+                    missing_fields
+                        .into_iter()
+                        .map(|path| SchemaDriftKind::MissingField { path }),
+                )
+                .chain(
+                    unknown_values
+                        .into_iter()
+                        .map(|(r#type, value)| SchemaDriftKind::UnknownEnumValue { r#type, value }),
+                )
+                .for_each(|kind| detector.report(&endpoint, kind));
+        }
+
+        Ok(WithRaw { response, raw })
     }
 }
 
+thread_local! {
+    // Unknown enum values encountered while deserializing a response, if they are being collected.
+    static UNKNOWN_ENUM_VALUES: RefCell<Option<BTreeSet<(&'static str, String)>>> =
+        const { RefCell::new(None) };
+}
+
+// Calls `f`, collecting the unknown enum values deserialized while it runs if `collect` is `true`.
+fn collect_unknown_values<F, T>(collect: bool, f: F) -> (T, BTreeSet<(&'static str, String)>)
+where
+    F: FnOnce() -> T,
+{
+    if collect {
+        UNKNOWN_ENUM_VALUES.set(Some(BTreeSet::new()));
+    }
+    let output = f();
+    (output, UNKNOWN_ENUM_VALUES.take().unwrap_or_default())
+}
+
+fn drift_path(path: &serde_ignored::Path<'_>) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, .. } => format!("{}[]", drift_path(parent)),
+        serde_ignored::Path::Map { parent, key } => join_path(&drift_path(parent), key),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => drift_path(parent),
+    }
+}
+
+fn join_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() { key.into() } else { format!("{parent}.{key}") }
+}
+
+// Finds fields present in the serialized model (`typed`) but absent from the response (`raw`).
+fn find_missing_fields(raw: &Value, typed: &Value, path: &str, missing: &mut BTreeSet<String>) {
+    match (raw, typed) {
+        (Value::Object(raw), Value::Object(typed)) => {
+            for (key, typed_value) in typed {
+                let path = join_path(path, key);
+                match raw.get(key) {
+                    Some(raw_value) => find_missing_fields(raw_value, typed_value, &path, missing),
+                    None => {
+                        missing.insert(path);
+                    },
+                }
+            }
+        },
+        (Value::Array(raw), Value::Array(typed)) => {
+            let path = format!("{path}[]");
+            for (raw_value, typed_value) in raw.iter().zip(typed) {
+                find_missing_fields(raw_value, typed_value, &path, missing);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Deserializes the value of an enum's `Unknown` variant, reporting it as schema drift.
+///
+/// Meant to be used with `#[serde(untagged, deserialize_with = "...")]` on the variant. Only
+/// API models should use it; enums read from local files (like a track's `config.json`) use a
+/// plain `#[serde(untagged)]` variant instead, since they are not part of the API's schema.
+pub fn deserialize_unknown<'de, D, T>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    tracing::debug!(
+        target: SCHEMA_DRIFT_TARGET,
+        r#type = std::any::type_name::<T>(),
+        %value,
+        "unknown enum value in API response",
+    );
+    UNKNOWN_ENUM_VALUES.with_borrow_mut(|values| {
+        if let Some(values) = values {
+            values.insert((std::any::type_name::<T>(), value.clone()));
+        }
+    });
+    Ok(value)
+}
+
+// Additional fields can be declared in braces after the base URL; they are added to both the
+// client and its builder. Fields must implement `Debug`, `Clone` and `Default`; their builder
+// setters must be implemented manually.
 macro_rules! define_api_client {
     (
         $(#[$attr:meta])*
-        $vis:vis struct $api_name:ident($base_url:expr);
+        $vis:vis struct $api_name:ident($base_url:expr) $({
+            $($field:ident: $field_ty:ty),* $(,)?
+        })?;
     ) => {
         paste::paste! {
             $(#[$attr])*
             #[derive(Debug, Clone)]
             $vis struct $api_name {
                 api_client: ::std::sync::Arc<$crate::api::detail::ApiClient>,
+                $($($field: $field_ty,)*)?
             }
 
             impl $api_name {
@@ -300,6 +663,21 @@ macro_rules! define_api_client {
                 pub fn builder() -> [<$api_name Builder>] {
                     [<$api_name Builder>]::default()
                 }
+
+                #[doc = r"
+                    Returns a new [`" $api_name r"`] that performs requests with the given
+                    [`Credentials`](crate::core::Credentials).
+
+                    The new client shares this client's HTTP client, including its connection pool
+                    and retry policy. This makes it cheap to perform requests on behalf of multiple users.
+                "]
+                #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+                pub fn with_credentials(&self, credentials: $crate::core::Credentials) -> Self {
+                    Self {
+                        api_client: ::std::sync::Arc::new(self.api_client.with_credentials(Some(credentials))),
+                        $($($field: self.$field.clone(),)*)?
+                    }
+                }
             }
 
             #[doc = r"
@@ -314,6 +692,7 @@ macro_rules! define_api_client {
             $vis struct [<$api_name Builder>] {
                 api_client_builder: $crate::api::detail::ApiClientBuilder,
                 error: ::std::option::Option<$crate::Error>,
+                $($($field: $field_ty,)*)?
             }
 
             impl [<$api_name Builder>] {
@@ -469,12 +848,51 @@ macro_rules! define_api_client {
                     self
                 }
 
+                #[doc = r"
+                    Enables or disables [schema drift detection](crate::api::schema_drift).
+
+                    When enabled, each response body is compared against the model it is
+                    deserialized into; differences are reported through `warn`-level
+                    [`tracing`] events emitted with the
+                    [`SCHEMA_DRIFT_TARGET`](crate::api::SCHEMA_DRIFT_TARGET) target.
+
+                    Disabled by default. Disabling detection also removes any handler set
+                    via [`on_schema_drift`](Self::on_schema_drift).
+                "]
+                #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+                pub fn detect_schema_drift(&mut self, value: bool) -> &mut Self {
+                    if self.error.is_none() {
+                        self.api_client_builder.detect_schema_drift(value);
+                    }
+                    self
+                }
+
+                #[doc = r"
+                    Enables [schema drift detection](crate::api::schema_drift) and sets a
+                    handler that will be called for each
+                    [`SchemaDrift`](crate::api::schema_drift::SchemaDrift) detected.
+
+                    Schema drift is also reported through [`tracing`] events (see
+                    [`detect_schema_drift`](Self::detect_schema_drift)).
+                "]
+                #[cfg_attr(not(coverage), tracing::instrument(skip_all, level = "trace"))]
+                pub fn on_schema_drift<F>(&mut self, handler: F) -> &mut Self
+                where
+                    F: ::std::ops::Fn(&$crate::api::schema_drift::SchemaDrift) + Send + Sync + 'static,
+                {
+                    if self.error.is_none() {
+                        self.api_client_builder.on_schema_drift(::std::sync::Arc::new(handler));
+                    }
+                    self
+                }
+
                 #[doc = "Builds a new [`" $api_name "`] instance using the parameters of this builder."]
                 #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err, level = "trace"))]
                 pub fn build(&mut self) -> $crate::Result<$api_name> {
                     match self.error.take() {
                         None => Ok($api_name {
                             api_client: ::std::sync::Arc::new(self.api_client_builder.build()?),
+                            $($($field: self.$field.clone(),)*)?
                         }),
                         Some(err) => Err(err),
                     }
@@ -491,7 +909,11 @@ macro_rules! define_api_client {
                 fn default() -> Self {
                     let mut api_client_builder = $crate::api::detail::ApiClient::builder();
                     api_client_builder.api_base_url($base_url);
-                    Self { api_client_builder, error: None }
+                    Self {
+                        api_client_builder,
+                        error: None,
+                        $($($field: ::std::default::Default::default(),)*)?
+                    }
                 }
             }
         }
@@ -691,6 +1113,75 @@ mod tests {
             let _ = ApiClient::builder().build();
         }
 
+        #[rstest]
+        #[case::api_url(
+            "https://exercism.org/api/v2/solutions/1/submissions/2/files",
+            "/solutions/1/submissions/2/files",
+            "http://localhost:1234/api/v2/solutions/1/submissions/2/files"
+        )]
+        #[case::api_path(
+            "/api/v2/tracks?criteria=rust",
+            "/tracks?criteria=rust",
+            "http://localhost:1234/api/v2/tracks?criteria=rust"
+        )]
+        #[case::website_url(
+            "https://exercism.org/tracks/rust",
+            "/tracks/rust",
+            "http://localhost:1234/tracks/rust"
+        )]
+        #[case::website_path(
+            "/tracks/rust/exercises/poker",
+            "/tracks/rust/exercises/poker",
+            "http://localhost:1234/tracks/rust/exercises/poker"
+        )]
+        #[case::similar_path(
+            "/api/v20/tracks",
+            "/api/v20/tracks",
+            "http://localhost:1234/api/v20/tracks"
+        )]
+        #[case::configured_host(
+            "http://localhost:1234/api/v2/tracks",
+            "/tracks",
+            "http://localhost:1234/api/v2/tracks"
+        )]
+        fn test_resolve_link(
+            #[case] href: &str,
+            #[case] expected_endpoint: &str,
+            #[case] expected_url: &str,
+        ) {
+            let api_client = ApiClient::builder()
+                .api_base_url("http://localhost:1234/api/v2")
+                .build()
+                .unwrap();
+
+            let (endpoint, url) = api_client
+                .resolve_link("https://exercism.org/api/v2", href)
+                .unwrap();
+            assert_eq!(expected_endpoint, endpoint);
+            assert_eq!(expected_url, url);
+        }
+
+        #[rstest]
+        #[case::other_host("https://example.com/api/v2/tracks")]
+        #[case::other_port("http://localhost:4321/api/v2/tracks")]
+        #[case::similar_host("https://exercism.org.example.com/api/v2/tracks")]
+        fn test_resolve_foreign_link(#[case] href: &str) {
+            let api_client = ApiClient::builder()
+                .api_base_url("http://localhost:1234/api/v2")
+                .build()
+                .unwrap();
+
+            assert_eq!(None, api_client.resolve_link("https://exercism.org/api/v2", href));
+        }
+
+        #[rstest]
+        #[case::with_path("https://exercism.org/api/v2", ("https://exercism.org", "/api/v2"))]
+        #[case::trailing_slash("https://api.exercism.io/v1/", ("https://api.exercism.io", "/v1"))]
+        #[case::without_path("http://localhost:1234", ("http://localhost:1234", ""))]
+        fn test_split_base_url(#[case] base_url: &str, #[case] expected: (&str, &str)) {
+            assert_eq!(expected, split_base_url(base_url));
+        }
+
         #[rstest]
         #[awt]
         #[tokio::test]
@@ -778,6 +1269,199 @@ mod tests {
             }
         }
 
+        mod execute {
+            use super::*;
+
+            async fn mock_server(body: &str) -> MockServer {
+                let mock_server = MockServer::start().await;
+
+                Mock::given(method("GET"))
+                    .and(path(ROUTE))
+                    .respond_with(
+                        ResponseTemplate::new(StatusCode::OK)
+                            .set_body_raw(body.to_owned(), "application/json"),
+                    )
+                    .mount(&mock_server)
+                    .await;
+
+                mock_server
+            }
+
+            #[tokio::test]
+            #[test_log::test]
+            async fn test_unknown_fields() {
+                let mock_server =
+                    mock_server(r#"{ "message": "test message", "extra": { "field": 42 } }"#).await;
+                let client = api_client(&mock_server.uri(), true, false);
+
+                let output: TestOutput = client.get(ROUTE).execute().await.unwrap();
+                assert_eq!(TestOutput::default(), output);
+            }
+
+            #[rstest]
+            #[case::invalid_type(r#"{ "message": 42 }"#)]
+            #[case::trailing_characters(r#"{ "message": "test message" } }"#)]
+            #[tokio::test]
+            #[test_log::test]
+            async fn test_format_error(#[case] body: &str) {
+                let mock_server = mock_server(body).await;
+                let client = api_client(&mock_server.uri(), true, false);
+
+                let result = client.get(ROUTE).execute::<TestOutput>().await;
+                assert_matches!(result, Err(crate::Error::ApiResponseFormatError(_)));
+            }
+
+            mod schema_drift {
+                use std::sync::{Arc, Mutex};
+
+                use super::*;
+
+                #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+                struct DriftOutput {
+                    pub message: String,
+                    #[serde(default)]
+                    pub note: Option<String>,
+                    pub items: Vec<DriftItem>,
+                }
+
+                #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+                struct DriftItem {
+                    pub status: DriftStatus,
+                    #[serde(default)]
+                    pub count: i32,
+                }
+
+                #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+                #[serde(rename_all = "snake_case")]
+                enum DriftStatus {
+                    Known,
+                    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, DriftStatus>")]
+                    Unknown(String),
+                }
+
+                const BODY: &str = r#"{
+                    "message": "test message",
+                    "extra": true,
+                    "items": [
+                        { "status": "known", "count": 1, "added": 1 },
+                        { "status": "brand_new" },
+                        { "status": "brand_new", "added": 2 }
+                    ]
+                }"#;
+
+                fn api_client_with_handler(
+                    api_base_url: &str,
+                ) -> (ApiClient, Arc<Mutex<Vec<SchemaDrift>>>) {
+                    let drifts = Arc::new(Mutex::new(Vec::new()));
+                    let handler_drifts = Arc::clone(&drifts);
+
+                    let mut builder = ApiClient::builder();
+                    builder.api_base_url(api_base_url).on_schema_drift(Arc::new(
+                        move |drift: &SchemaDrift| {
+                            handler_drifts.lock().unwrap().push(drift.clone());
+                        },
+                    ));
+
+                    (builder.build().unwrap(), drifts)
+                }
+
+                #[tokio::test]
+                #[test_log::test]
+                async fn test_report() {
+                    let mock_server = mock_server(BODY).await;
+                    let (client, drifts) = api_client_with_handler(&mock_server.uri());
+
+                    let output: DriftOutput = client.get(ROUTE).execute_model().await.unwrap();
+                    assert_eq!(DriftStatus::Unknown("brand_new".into()), output.items[1].status);
+
+                    let drift = |kind| SchemaDrift { endpoint: format!("GET {ROUTE}"), kind };
+                    let expected = vec![
+                        drift(SchemaDriftKind::UnknownField { path: "extra".into() }),
+                        drift(SchemaDriftKind::UnknownField { path: "items[].added".into() }),
+                        drift(SchemaDriftKind::MissingField { path: "items[].count".into() }),
+                        drift(SchemaDriftKind::MissingField { path: "note".into() }),
+                        drift(SchemaDriftKind::UnknownEnumValue {
+                            r#type: std::any::type_name::<DriftStatus>(),
+                            value: "brand_new".into(),
+                        }),
+                    ];
+                    assert_eq!(expected, *drifts.lock().unwrap());
+                }
+
+                #[tokio::test]
+                #[test_log::test]
+                async fn test_report_without_model() {
+                    #[derive(Debug, Deserialize)]
+                    struct UserOutput {
+                        #[allow(dead_code)]
+                        pub items: Vec<DriftItem>,
+                    }
+
+                    let mock_server = mock_server(BODY).await;
+                    let (client, drifts) = api_client_with_handler(&mock_server.uri());
+
+                    let _: UserOutput = client.get(ROUTE).execute().await.unwrap();
+
+                    let drift = |kind| SchemaDrift { endpoint: format!("GET {ROUTE}"), kind };
+                    let expected = vec![
+                        drift(SchemaDriftKind::UnknownField { path: "extra".into() }),
+                        drift(SchemaDriftKind::UnknownField { path: "items[].added".into() }),
+                        drift(SchemaDriftKind::UnknownField { path: "message".into() }),
+                        drift(SchemaDriftKind::UnknownEnumValue {
+                            r#type: std::any::type_name::<DriftStatus>(),
+                            value: "brand_new".into(),
+                        }),
+                    ];
+                    assert_eq!(expected, *drifts.lock().unwrap());
+                }
+
+                #[tokio::test]
+                #[test_log::test]
+                async fn test_disabled() {
+                    let mock_server = mock_server(BODY).await;
+                    let (client, drifts) = api_client_with_handler(&mock_server.uri());
+                    let client = ApiClient { schema_drift: None, ..client };
+
+                    let output: DriftOutput = client.get(ROUTE).execute().await.unwrap();
+                    assert_eq!(3, output.items.len());
+                    assert!(drifts.lock().unwrap().is_empty());
+                }
+
+                #[test]
+                fn test_local_enums_not_reported() {
+                    let (status, unknown_values) = collect_unknown_values(true, || {
+                        serde_json::from_str::<crate::tooling::test_runner::Status>(r#""queued""#)
+                    });
+                    assert_eq!(
+                        crate::tooling::test_runner::Status::Unknown("queued".into()),
+                        status.unwrap()
+                    );
+                    assert!(unknown_values.is_empty());
+                }
+
+                #[test]
+                fn test_detect_schema_drift() {
+                    let mut builder = ApiClient::builder();
+                    builder
+                        .api_base_url("https://example.org")
+                        .on_schema_drift(Arc::new(|_: &SchemaDrift| {}));
+                    assert_matches!(
+                        &builder.detect_schema_drift(true).schema_drift,
+                        Some(SchemaDriftDetector { handler: Some(_) })
+                    );
+                    assert_matches!(builder.detect_schema_drift(false).schema_drift, None);
+                    assert_matches!(
+                        builder
+                            .detect_schema_drift(true)
+                            .build()
+                            .unwrap()
+                            .schema_drift,
+                        Some(SchemaDriftDetector { handler: None })
+                    );
+                }
+            }
+        }
+
         mod retries {
             use std::sync::Mutex;
             use std::time::Duration;
@@ -1036,6 +1720,17 @@ mod tests {
                 assert!(result.is_ok());
             }
 
+            #[test]
+            #[test_log::test]
+            fn test_schema_drift() {
+                let result = TestApiClient::builder()
+                    .detect_schema_drift(true)
+                    .on_schema_drift(|drift| tracing::info!(%drift))
+                    .build();
+
+                assert!(result.is_ok());
+            }
+
             #[test]
             #[test_log::test]
             fn test_build_error() {
@@ -1095,6 +1790,26 @@ mod tests {
                 assert_eq!(test_api_client.api_base_url(), TEST_API_CLIENT_BASE_URL);
             }
 
+            #[test]
+            #[test_log::test]
+            fn test_with_credentials() {
+                let custom_api_base_url = "https://custom.api.client/api";
+                let test_api_client = TestApiClient::builder()
+                    .api_base_url(custom_api_base_url)
+                    .build()
+                    .unwrap();
+
+                let with_credentials =
+                    test_api_client.with_credentials(Credentials::from_api_token(TEST_API_TOKEN));
+
+                assert_eq!(with_credentials.api_base_url(), custom_api_base_url);
+                assert_eq!(
+                    with_credentials.api_client.credentials,
+                    Some(Credentials::from_api_token(TEST_API_TOKEN))
+                );
+                assert!(test_api_client.api_client.credentials.is_none());
+            }
+
             mod debug {
                 use super::*;
 
@@ -0,0 +1,313 @@
+//! Typed identifiers used by the [Exercism](https://exercism.org) APIs.
+//!
+//! Solutions, submissions and iterations are identified by UUIDs, while language tracks and
+//! exercises are identified by slugs. Wrapping them in distinct types makes it impossible to
+//! pass, say, a [`SubmissionUuid`] where a [`SolutionUuid`] is expected.
+//!
+//! Identifiers can be created in two ways:
+//!
+//! - [`new`](SolutionUuid::new) and [`parse`](str::parse) validate the identifier's format and
+//!   return an [`InvalidIdentifier`] error if it is not valid.
+//! - [`From`] conversions (like `From<&str>`) do not validate the identifier; they ease migration
+//!   of code that used plain strings. Use [`is_valid`](SolutionUuid::is_valid) to validate such
+//!   identifiers afterwards.
+//!
+//! Identifiers deserialized from API responses are not validated either, so that unexpected
+//! values returned by the API do not break deserialization (see
+//! [Schema drift](crate::api#schema-drift)).
+//!
+//! # Examples
+//!
+//! ```
+//! use mini_exercism::api::id::{SolutionUuid, TrackSlug};
+//!
+//! let solution_uuid: SolutionUuid = "00c717b68e1b4213b316df82636f5e0f".parse().unwrap();
+//! assert_eq!(solution_uuid, "00c717b68e1b4213b316df82636f5e0f");
+//!
+//! assert!(TrackSlug::new("common-lisp").is_ok());
+//! assert!(TrackSlug::new("Common Lisp").is_err());
+//!
+//! // Unvalidated conversion
+//! let track = TrackSlug::from("Common Lisp");
+//! assert!(!track.is_valid());
+//! ```
+//!
+//! [`InvalidIdentifier`]: crate::Error::InvalidIdentifier
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+macro_rules! define_id {
+    (
+        $(#[$attr:meta])*
+        $id:ident: $kind:literal, $validate:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        #[serde(transparent)]
+        pub struct $id(String);
+
+        impl $id {
+            #[doc = concat!("Creates a new ", $kind, ", validating its format.")]
+            ///
+            /// # Errors
+            ///
+            /// - [`InvalidIdentifier`]: `value` is not in the expected format
+            ///
+            /// [`InvalidIdentifier`]: crate::Error::InvalidIdentifier
+            pub fn new<V>(value: V) -> Result<Self>
+            where
+                V: Into<String>,
+            {
+                let id = Self(value.into());
+                if id.is_valid() {
+                    Ok(id)
+                } else {
+                    Err(Error::InvalidIdentifier { kind: $kind, value: id.0 })
+                }
+            }
+
+            #[doc = concat!("Returns whether this ", $kind, " is in the expected format.")]
+            pub fn is_valid(&self) -> bool {
+                $validate(&self.0)
+            }
+
+            /// Returns the identifier as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the identifier as a [`String`].
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl FromStr for $id {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl From<&str> for $id {
+            fn from(value: &str) -> Self {
+                Self(value.into())
+            }
+        }
+
+        impl From<String> for $id {
+            fn from(value: String) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<&String> for $id {
+            fn from(value: &String) -> Self {
+                Self(value.clone())
+            }
+        }
+
+        impl From<&$id> for $id {
+            fn from(value: &$id) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$id> for String {
+            fn from(value: $id) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $id {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl Deref for $id {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $id {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $id {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $id {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $id {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $id {
+            fn eq(&self, other: &String) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$id> for str {
+            fn eq(&self, other: &$id) -> bool {
+                self == other.0
+            }
+        }
+
+        impl PartialEq<$id> for &str {
+            fn eq(&self, other: &$id) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl PartialEq<$id> for String {
+            fn eq(&self, other: &$id) -> bool {
+                *self == other.0
+            }
+        }
+    };
+}
+
+define_id! {
+    /// Unique ID of a [solution](crate::api::v2::solution::Solution).
+    SolutionUuid: "solution UUID", is_valid_uuid
+}
+
+define_id! {
+    /// Unique ID of a submission, which holds the data sent for an
+    /// [iteration](crate::api::v2::iteration::Iteration).
+    SubmissionUuid: "submission UUID", is_valid_uuid
+}
+
+define_id! {
+    /// Unique ID of an [iteration](crate::api::v2::iteration::Iteration).
+    IterationUuid: "iteration UUID", is_valid_uuid
+}
+
+define_id! {
+    /// Name of a [language track](crate::api::v2::track::Track), like `common-lisp`.
+    /// Also called `slug`.
+    TrackSlug: "track slug", is_valid_slug
+}
+
+define_id! {
+    /// Name of an [exercise](crate::api::v2::exercise::Exercise), like `forth`.
+    /// Also called `slug`.
+    ExerciseSlug: "exercise slug", is_valid_slug
+}
+
+// UUIDs returned by the Exercism APIs are usually made of 32 hexadecimal digits, but
+// hyphenated UUIDs (like `42ee56ad-d3e6-48f1-8e3f-c84078d916fc`) are also accepted.
+fn is_valid_uuid(value: &str) -> bool {
+    const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+    match value.len() {
+        32 => value.bytes().all(|b| b.is_ascii_hexdigit()),
+        36 => value
+            .bytes()
+            .enumerate()
+            .all(|(i, b)| if HYPHENS.contains(&i) { b == b'-' } else { b.is_ascii_hexdigit() }),
+        _ => false,
+    }
+}
+
+// Slugs are made of lowercase ASCII letters, digits and hyphens, like `x86-64-assembly`.
+fn is_valid_slug(value: &str) -> bool {
+    !value.is_empty()
+        && !value.starts_with('-')
+        && !value.ends_with('-')
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use assert_matches::assert_matches;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::simple("00c717b68e1b4213b316df82636f5e0f", true)]
+    #[case::uppercase("00C717B68E1B4213B316DF82636F5E0F", true)]
+    #[case::hyphenated("42ee56ad-d3e6-48f1-8e3f-c84078d916fc", true)]
+    #[case::empty("", false)]
+    #[case::too_short("00c717b68e1b4213b316df82636f5e0", false)]
+    #[case::not_hex("00c717b68e1b4213b316df82636f5e0g", false)]
+    #[case::misplaced_hyphens("42ee56add-3e6-48f1-8e3f-c84078d916fc", false)]
+    fn test_uuid_validation(#[case] value: &str, #[case] valid: bool) {
+        assert_eq!(valid, SolutionUuid::from(value).is_valid());
+        assert_eq!(valid, SolutionUuid::new(value).is_ok());
+    }
+
+    #[rstest]
+    #[case::simple("rust", true)]
+    #[case::hyphenated("x86-64-assembly", true)]
+    #[case::digits("8th", true)]
+    #[case::empty("", false)]
+    #[case::uppercase("Rust", false)]
+    #[case::space("common lisp", false)]
+    #[case::leading_hyphen("-rust", false)]
+    #[case::trailing_hyphen("rust-", false)]
+    fn test_slug_validation(#[case] value: &str, #[case] valid: bool) {
+        assert_eq!(valid, TrackSlug::from(value).is_valid());
+        assert_eq!(valid, TrackSlug::new(value).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_identifier() {
+        let error = "not a uuid".parse::<SubmissionUuid>().unwrap_err();
+
+        assert_matches!(error, Error::InvalidIdentifier { kind: "submission UUID", value } => {
+            assert_eq!("not a uuid", value);
+        });
+    }
+
+    #[test]
+    fn test_conversions() {
+        let slug = ExerciseSlug::from("forth");
+
+        assert_eq!("forth", slug);
+        assert_eq!(slug, "forth");
+        assert_eq!(slug, String::from("forth"));
+        assert_eq!("forth", slug.to_string());
+        assert_eq!("forth", &*slug);
+        assert_eq!(slug, ExerciseSlug::from(&slug));
+        assert_eq!("forth", String::from(slug));
+    }
+
+    #[test]
+    fn test_serde() {
+        let uuid: IterationUuid = serde_json::from_str("\"1\"").unwrap();
+
+        assert_eq!("1", uuid);
+        assert!(!uuid.is_valid());
+        assert_eq!("\"1\"", serde_json::to_string(&uuid).unwrap());
+    }
+}
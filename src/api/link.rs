@@ -0,0 +1,143 @@
+//! Types used to navigate the links returned by the [Exercism](https://exercism.org) APIs.
+//!
+//! Some models contain navigation links (like [`iteration::Links`]). Each [`Link`] is typed
+//! after the resource it points to, so that it can be followed using [`v2::Client::follow`],
+//! which returns the resource's typed model. Links to pages of the website (typed
+//! [`Link<WebPage>`]) cannot be deserialized, but can still be fetched using
+//! [`v2::Client::follow_raw`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::api::v2::tests;
+//! use mini_exercism::core::Credentials;
+//!
+//! async fn get_latest_test_run(
+//!     api_token: &str,
+//!     solution_uuid: &str,
+//! ) -> anyhow::Result<Option<tests::run::Response>> {
+//!     let credentials = Credentials::from_api_token(api_token);
+//!     let client = api::v2::Client::builder()
+//!         .credentials(credentials)
+//!         .build()?;
+//!
+//!     let latest_iteration = client
+//!         .get_solution(solution_uuid, true)
+//!         .await?
+//!         .iterations
+//!         .into_iter()
+//!         .find(|iteration| iteration.is_latest);
+//!     match latest_iteration.and_then(|iteration| iteration.links.test_run) {
+//!         Some(test_run) => Ok(Some(client.follow(&test_run).await?)),
+//!         None => Ok(None),
+//!     }
+//! }
+//! ```
+//!
+//! [`iteration::Links`]: crate::api::v2::iteration::Links
+//! [`v2::Client::follow`]: crate::api::v2::Client::follow
+//! [`v2::Client::follow_raw`]: crate::api::v2::Client::follow_raw
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+/// Link to a resource of type `T`, as returned by the [Exercism](https://exercism.org) APIs.
+///
+/// The link's [`href`](Self::href) can be an absolute URL or a path, like `/tracks/rust`.
+/// When a link is [followed](crate::api::v2::Client::follow), links to the default API base
+/// URL are resolved against the client's [API base URL](crate::api::v2::ClientBuilder::api_base_url),
+/// while other paths are resolved against the root of the website. Links to other hosts never
+/// receive the client's credentials.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Link<T> {
+    href: String,
+
+    #[serde(skip)]
+    target: PhantomData<fn() -> T>,
+}
+
+impl<T> Link<T> {
+    /// Creates a new link to the given `href`.
+    pub fn new<H>(href: H) -> Self
+    where
+        H: Into<String>,
+    {
+        Self { href: href.into(), target: PhantomData }
+    }
+
+    /// Returns the link's target, as returned by the API.
+    pub fn href(&self) -> &str {
+        &self.href
+    }
+}
+
+impl<T> From<&str> for Link<T> {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<String> for Link<T> {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> fmt::Debug for Link<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Link").field(&self.href).finish()
+    }
+}
+
+impl<T> fmt::Display for Link<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.href)
+    }
+}
+
+impl<T> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.href.clone())
+    }
+}
+
+impl<T> PartialEq for Link<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.href == other.href
+    }
+}
+
+impl<T> Eq for Link<T> {}
+
+impl<T> Hash for Link<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.href.hash(state);
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<T> schemars::JsonSchema for Link<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Link".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(generator)
+    }
+}
+
+/// Target of a [`Link`] to a page of the [Exercism website](https://exercism.org).
+///
+/// Web pages are HTML documents, so such links can only be followed using
+/// [`follow_raw`](crate::api::v2::Client::follow_raw).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WebPage {}
@@ -0,0 +1,84 @@
+//! Types used to access the raw responses returned by the [Exercism](https://exercism.org) APIs.
+//!
+//! Typed models can lag behind the website. To read fields that are not modeled yet, most client
+//! methods have a `_with_raw` variant (like [`get_tracks_with_raw`]) returning a [`WithRaw`],
+//! which contains both the typed response and the [`RawResponse`] it was deserialized from.
+//! Endpoints that are not modeled at all can be queried using [`get_raw`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::api::id::TrackSlug;
+//!
+//! async fn get_track_slugs_and_raw_json() -> anyhow::Result<(Vec<TrackSlug>, serde_json::Value)> {
+//!     let client = api::v2::Client::new()?;
+//!
+//!     let response = client.get_tracks_with_raw(None).await?;
+//!     let json = response.raw.json()?;
+//!     let slugs = response
+//!         .response
+//!         .tracks
+//!         .into_iter()
+//!         .map(|track| track.name)
+//!         .collect();
+//!
+//!     Ok((slugs, json))
+//! }
+//! ```
+//!
+//! [`get_tracks_with_raw`]: crate::api::v2::Client::get_tracks_with_raw
+//! [`get_raw`]: crate::api::v2::Client::get_raw
+
+use bytes::Bytes;
+
+use crate::http::header::HeaderMap;
+use crate::http::{StatusCode, Url};
+use crate::{Error, Result};
+
+/// Raw response returned by an [Exercism](https://exercism.org) API.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// HTTP status code of the response.
+    pub status: StatusCode,
+
+    /// HTTP headers of the response.
+    pub headers: HeaderMap,
+
+    /// Final URL of the response, after any redirects and retries.
+    pub url: Url,
+
+    /// Body of the response.
+    pub body: Bytes,
+}
+
+impl RawResponse {
+    /// Parses the response body as JSON.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiResponseFormatError`]: Response body is not valid JSON
+    ///
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    pub fn json(&self) -> Result<serde_json::Value> {
+        serde_json::from_slice(&self.body).map_err(Error::ApiResponseFormatError)
+    }
+
+    /// Returns the response body as text.
+    ///
+    /// Invalid UTF-8 sequences are replaced with [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Typed response returned by an [Exercism](https://exercism.org) API, along with the
+/// [`RawResponse`] it was deserialized from.
+#[derive(Debug, Clone)]
+pub struct WithRaw<R> {
+    /// Typed response.
+    pub response: R,
+
+    /// Raw response.
+    pub raw: RawResponse,
+}
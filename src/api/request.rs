@@ -0,0 +1,241 @@
+//! Types used to perform arbitrary requests to the [Exercism](https://exercism.org) APIs.
+//!
+//! Endpoints that are not modeled by the API clients can be queried using a [`RequestBuilder`],
+//! returned by [`v1::Client::request`] or [`v2::Client::request`]. Requests are performed with
+//! the client's credentials, retry policy and [schema drift detection](crate::api::schema_drift).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::core::Credentials;
+//! use mini_exercism::http::Method;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Bookmark {
+//!     uuid: String,
+//! }
+//!
+//! async fn bookmark_solution(api_token: &str, solution_uuid: &str) -> anyhow::Result<Bookmark> {
+//!     let credentials = Credentials::from_api_token(api_token);
+//!     let client = api::v2::Client::builder()
+//!         .credentials(credentials)
+//!         .build()?;
+//!
+//!     Ok(client
+//!         .request(Method::POST, "/bookmarks")
+//!         .json(&serde_json::json!({ "solution_uuid": solution_uuid }))
+//!         .execute()
+//!         .await?)
+//! }
+//! ```
+//!
+//! [`v1::Client::request`]: crate::api::v1::Client::request
+//! [`v2::Client::request`]: crate::api::v2::Client::request
+
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+use crate::api::IntoQuery;
+use crate::api::detail::ApiRequestBuilder;
+use crate::api::raw::{RawResponse, WithRaw};
+
+/// Builder for a request to an [Exercism](https://exercism.org) API.
+///
+/// To create a builder instance, call [`v1::Client::request`](crate::api::v1::Client::request)
+/// or [`v2::Client::request`](crate::api::v2::Client::request).
+pub struct RequestBuilder {
+    request: Result<ApiRequestBuilder>,
+}
+
+impl RequestBuilder {
+    pub(crate) fn new(request: Result<ApiRequestBuilder>) -> Self {
+        Self { request }
+    }
+
+    /// Adds query parameters to the request.
+    ///
+    /// The `query` can be any [`IntoQuery`], like the filters types of the API clients.
+    pub fn query<Q>(self, query: Q) -> Self
+    where
+        Q: IntoQuery,
+    {
+        Self::new(self.request.map(|request| request.query(query)))
+    }
+
+    /// Sets the body of the request to the JSON representation of `body`.
+    ///
+    /// This also sets the `Content-Type` header to `application/json`.
+    pub fn json<B>(self, body: &B) -> Self
+    where
+        B: Serialize + ?Sized,
+    {
+        Self::new(self.request.map(|request| request.json(body)))
+    }
+
+    /// Sets the body of the request to a `multipart/form-data` [`Multipart`] form.
+    ///
+    /// This also sets the `Content-Type` header, including the form's boundary.
+    pub fn multipart(self, form: Multipart) -> Self {
+        let (content_type, body) = form.encode();
+        Self::new(
+            self.request
+                .map(|request| request.body(&content_type, body)),
+        )
+    }
+
+    /// Sends the request and returns the [raw response](RawResponse).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while performing request to API (including error status codes)
+    /// - [`InvalidPath`]: Request path does not start with a `/`
+    /// - [`OfflineUnsupported`]: Request was created by an [offline](crate::api::v2::ClientBuilder::offline) client
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`InvalidPath`]: crate::Error::InvalidPath
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    pub async fn send_raw(self) -> Result<RawResponse> {
+        self.request?.send_raw().await
+    }
+
+    /// Sends the request and deserializes the response body from JSON.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while performing request to API (including error status codes)
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`InvalidPath`]: Request path does not start with a `/`
+    /// - [`OfflineUnsupported`]: Request was created by an [offline](crate::api::v2::ClientBuilder::offline) client
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`InvalidPath`]: crate::Error::InvalidPath
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    pub async fn execute<R>(self) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        self.request?.execute().await
+    }
+
+    /// Same as [`execute`](Self::execute), but also returns the [raw response](RawResponse).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while performing request to API (including error status codes)
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`InvalidPath`]: Request path does not start with a `/`
+    /// - [`OfflineUnsupported`]: Request was created by an [offline](crate::api::v2::ClientBuilder::offline) client
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`InvalidPath`]: crate::Error::InvalidPath
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    pub async fn execute_with_raw<R>(self) -> Result<WithRaw<R>>
+    where
+        R: DeserializeOwned,
+    {
+        self.request?.execute_with_raw().await
+    }
+}
+
+/// `multipart/form-data` form that can be sent using [`RequestBuilder::multipart`].
+///
+/// The form is encoded in memory so that requests can be retried.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Multipart {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Part {
+    name: String,
+    file_name: Option<String>,
+    content: Bytes,
+}
+
+impl Multipart {
+    /// Creates a new, empty form.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a text field to the form.
+    pub fn text<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.parts.push(Part {
+            name: name.into(),
+            file_name: None,
+            content: Bytes::from(value.into()),
+        });
+        self
+    }
+
+    /// Adds a file to the form.
+    ///
+    /// The file is sent with the `application/octet-stream` content type.
+    pub fn file<N, F, C>(mut self, name: N, file_name: F, content: C) -> Self
+    where
+        N: Into<String>,
+        F: Into<String>,
+        C: Into<Bytes>,
+    {
+        self.parts.push(Part {
+            name: name.into(),
+            file_name: Some(file_name.into()),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Encodes the form, returning its content type and body.
+    pub fn encode(&self) -> (String, Bytes) {
+        let boundary = self.boundary();
+
+        let mut body = BytesMut::new();
+        for part in &self.parts {
+            body.put_slice(format!("--{boundary}\r\n").as_bytes());
+            body.put_slice(
+                format!("Content-Disposition: form-data; name=\"{}\"", escape(&part.name))
+                    .as_bytes(),
+            );
+            if let Some(file_name) = &part.file_name {
+                body.put_slice(format!("; filename=\"{}\"", escape(file_name)).as_bytes());
+                body.put_slice(b"\r\nContent-Type: application/octet-stream");
+            }
+            body.put_slice(b"\r\n\r\n");
+            body.put_slice(&part.content);
+            body.put_slice(b"\r\n");
+        }
+        body.put_slice(format!("--{boundary}--\r\n").as_bytes());
+
+        (format!("multipart/form-data; boundary={boundary}"), body.freeze())
+    }
+
+    // The boundary is derived from the form's content so that encoding is deterministic.
+    fn boundary(&self) -> String {
+        let mut hasher = sha1_smol::Sha1::new();
+        for part in &self.parts {
+            hasher.update(part.name.as_bytes());
+            hasher.update(part.file_name.as_deref().unwrap_or_default().as_bytes());
+            hasher.update(&part.content);
+        }
+        format!("mini-exercism-{}", hasher.digest())
+    }
+}
+
+// Escapes a field name or file name like browsers do (see
+// https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart-form-data).
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
@@ -0,0 +1,346 @@
+//! [JSON Schemas](https://json-schema.org) and [OpenAPI](https://www.openapis.org) description of
+//! the [Exercism](https://exercism.org) APIs.
+//!
+//! When the `schema` feature is enabled, all response models of the [`v1`] and
+//! [`v2`] APIs implement [`JsonSchema`]. Enums are described
+//! using their `snake_case` values; since unknown values are [preserved](crate::api#schema-drift),
+//! their schemas also accept any other string.
+//!
+//! The [`openapi`] function generates an [OpenAPI 3.0](https://spec.openapis.org/oas/v3.0.3)
+//! document describing the endpoints called by the API clients, which can be used to generate
+//! clients in other languages.
+//!
+//! # Examples
+//!
+//! ```
+//! use mini_exercism::api::schema;
+//! use mini_exercism::api::v2::tracks;
+//!
+//! let tracks_schema = schema::schemars::schema_for!(tracks::Response);
+//! assert!(tracks_schema.get("properties").is_some());
+//!
+//! let openapi = schema::openapi();
+//! assert!(openapi["paths"]["/api/v2/tracks"]["get"].is_object());
+//! ```
+
+use schemars::generate::{SchemaGenerator, SchemaSettings};
+use schemars::{JsonSchema, Schema};
+use serde_json::{Map, Value, json};
+use strum::VariantNames;
+
+use crate::api::v1::DEFAULT_V1_API_BASE_URL;
+use crate::api::v2::DEFAULT_V2_API_BASE_URL;
+use crate::api::{v1, v2};
+use crate::http::Url;
+
+/// Re-export of the [`schemars`](https://docs.rs/schemars) crate used to generate JSON Schemas.
+pub use schemars;
+
+/// Generates an [OpenAPI 3.0](https://spec.openapis.org/oas/v3.0.3) document describing the
+/// endpoints of the [Exercism](https://exercism.org) v1 and v2 APIs called by the API clients.
+///
+/// Since both APIs are served from different hosts, each path specifies its own server. Paths
+/// include the path of the API's default base URL (for example, `/api/v2/tracks`); schemas of
+/// response models are stored in the document's components.
+pub fn openapi() -> Value {
+    let mut generator = SchemaGenerator::new(SchemaSettings::openapi3());
+    let mut paths = Paths::default();
+
+    let v1 = paths.api(DEFAULT_V1_API_BASE_URL);
+    paths.add(
+        &v1,
+        "get",
+        "/solutions/{uuid}",
+        operation("v1GetSolution", "Returns information about a solution", [path_param("uuid")])
+            .json::<v1::solution::Response>(&mut generator),
+    );
+    paths.add(
+        &v1,
+        "get",
+        "/solutions/latest",
+        operation(
+            "v1GetLatestSolution",
+            "Returns the latest solution submitted by the user for an exercise",
+            [query_param("track_id", true, string()), query_param("exercise_id", true, string())],
+        )
+        .json::<v1::solution::Response>(&mut generator),
+    );
+    paths.add(
+        &v1,
+        "get",
+        "/solutions/{solution_uuid}/files/{file_path}",
+        operation(
+            "v1GetFile",
+            "Returns the contents of a file that is part of a solution",
+            [path_param("solution_uuid"), path_param("file_path")],
+        )
+        .content("application/octet-stream", json!({ "type": "string", "format": "binary" })),
+    );
+    paths.add(
+        &v1,
+        "get",
+        "/tracks/{track}",
+        operation(
+            "v1GetTrack",
+            "Returns information about a language track",
+            [path_param("track")],
+        )
+        .json::<v1::track::Response>(&mut generator),
+    );
+    paths.add(
+        &v1,
+        "get",
+        "/validate_token",
+        operation("v1ValidateToken", "Validates the API token", []).empty(),
+    );
+    paths.add(
+        &v1,
+        "get",
+        "/ping",
+        operation("v1Ping", "Returns the status of the service", [])
+            .json::<v1::ping::Response>(&mut generator),
+    );
+
+    let v2 = paths.api(DEFAULT_V2_API_BASE_URL);
+    paths.add(
+        &v2,
+        "get",
+        "/tracks",
+        operation(
+            "v2GetTracks",
+            "Returns a list of language tracks",
+            [
+                query_param("criteria", false, string()),
+                query_param("tags[]", false, json!({ "type": "array", "items": string() })),
+                query_param("status", false, variants::<v2::tracks::StatusFilter>()),
+            ],
+        )
+        .json::<v2::tracks::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/tracks/{track}/exercises",
+        operation(
+            "v2GetExercises",
+            "Returns a list of exercises for a language track",
+            [
+                path_param("track"),
+                query_param("criteria", false, string()),
+                query_param("sideload", false, json!({ "type": "string", "enum": ["solutions"] })),
+            ],
+        )
+        .json::<v2::exercises::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions",
+        operation(
+            "v2GetSolutions",
+            "Returns a page of the user's solutions",
+            [
+                query_param("criteria", false, string()),
+                query_param("track_slug", false, string()),
+                query_param("status", false, schema_for::<v2::solution::Status>(&mut generator)),
+                query_param(
+                    "mentoring_status",
+                    false,
+                    schema_for::<v2::solution::MentoringStatus>(&mut generator),
+                ),
+                query_param(
+                    "sync_status",
+                    false,
+                    json!({ "type": "string", "enum": ["up_to_date", "out_of_date"] }),
+                ),
+                query_param("tests_status", false, string()),
+                query_param("head_tests_status", false, string()),
+                query_param("page", false, json!({ "type": "integer" })),
+                query_param("per_page", false, json!({ "type": "integer" })),
+                query_param("order", false, variants::<v2::solutions::SortOrder>()),
+            ],
+        )
+        .json::<v2::solutions::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions/{uuid}",
+        operation(
+            "v2GetSolution",
+            "Returns information about a solution",
+            [
+                path_param("uuid"),
+                query_param("sideload", false, json!({ "type": "string", "enum": ["iterations"] })),
+            ],
+        )
+        .json::<v2::solution::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions/{solution_uuid}/submissions/{submission_uuid}/files",
+        operation(
+            "v2GetSubmissionFiles",
+            "Returns the files submitted for a solution iteration",
+            [path_param("solution_uuid"), path_param("submission_uuid")],
+        )
+        .json::<v2::submission::files::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions/{solution_uuid}/submissions/{submission_uuid}/test_run",
+        operation(
+            "v2GetTestRun",
+            "Returns the test run of a submission",
+            [path_param("solution_uuid"), path_param("submission_uuid")],
+        )
+        .json::<v2::tests::run::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions/{solution_uuid}/iterations/{iteration_uuid}/automated_feedback",
+        operation(
+            "v2GetAutomatedFeedback",
+            "Returns the automated feedback of a solution iteration",
+            [path_param("solution_uuid"), path_param("iteration_uuid")],
+        )
+        .json::<v2::iteration::Response>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "get",
+        "/solutions/{solution_uuid}/diff",
+        operation(
+            "v2GetExerciseDiff",
+            "Returns the changes made to a solution's exercise",
+            [path_param("solution_uuid")],
+        )
+        .json::<v2::solution::update::DiffResponse>(&mut generator),
+    );
+    paths.add(
+        &v2,
+        "patch",
+        "/solutions/{solution_uuid}/sync",
+        operation(
+            "v2UpdateSolution",
+            "Updates a solution to the latest version of its exercise",
+            [path_param("solution_uuid")],
+        )
+        .json::<v2::solution::Response>(&mut generator),
+    );
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Exercism API",
+            "description": "Undocumented APIs of the Exercism website, as used by mini_exercism.",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths.paths,
+        "components": {
+            "schemas": generator.take_definitions(true),
+            "securitySchemes": {
+                "apiToken": { "type": "http", "scheme": "bearer" },
+            },
+        },
+        "security": [{}, { "apiToken": [] }],
+    })
+}
+
+#[derive(Default)]
+struct Paths {
+    paths: Map<String, Value>,
+}
+
+// Server (scheme and host) and path prefix of an API.
+struct Api {
+    server: String,
+    prefix: String,
+}
+
+impl Paths {
+    fn api(&self, base_url: &str) -> Api {
+        let url = Url::parse(base_url).expect("default base URLs should be valid");
+        Api {
+            server: url.origin().ascii_serialization(),
+            prefix: url.path().trim_end_matches('/').into(),
+        }
+    }
+
+    fn add(&mut self, api: &Api, method: &str, path: &str, operation: Operation) {
+        let item = self
+            .paths
+            .entry(format!("{}{path}", api.prefix))
+            .or_insert_with(|| json!({ "servers": [{ "url": api.server }] }));
+        item[method] = operation.value;
+    }
+}
+
+struct Operation {
+    value: Value,
+}
+
+fn operation<const N: usize>(id: &str, summary: &str, parameters: [Value; N]) -> Operation {
+    Operation {
+        value: json!({
+            "operationId": id,
+            "summary": summary,
+            "parameters": Vec::from(parameters),
+        }),
+    }
+}
+
+impl Operation {
+    fn json<T>(self, generator: &mut SchemaGenerator) -> Self
+    where
+        T: JsonSchema,
+    {
+        let schema = schema_for::<T>(generator);
+        self.content("application/json", schema)
+    }
+
+    fn content(mut self, media_type: &str, schema: Value) -> Self {
+        self.value["responses"] = json!({
+            "200": {
+                "description": "Successful response",
+                "content": { media_type: { "schema": schema } },
+            },
+        });
+        self
+    }
+
+    fn empty(mut self) -> Self {
+        self.value["responses"] = json!({ "200": { "description": "Successful response" } });
+        self
+    }
+}
+
+fn path_param(name: &str) -> Value {
+    json!({ "name": name, "in": "path", "required": true, "schema": string() })
+}
+
+fn query_param(name: &str, required: bool, schema: Value) -> Value {
+    json!({ "name": name, "in": "query", "required": required, "schema": schema })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn variants<T>() -> Value
+where
+    T: VariantNames,
+{
+    json!({ "type": "string", "enum": T::VARIANTS })
+}
+
+fn schema_for<T>(generator: &mut SchemaGenerator) -> Value
+where
+    T: JsonSchema,
+{
+    let schema: Schema = generator.subschema_for::<T>();
+    schema.to_value()
+}
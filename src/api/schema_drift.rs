@@ -0,0 +1,94 @@
+//! Types used to report schema drift detected in [Exercism](https://exercism.org) API responses.
+//!
+//! By default, schema drift is only reported through `debug`-level [`tracing`] events (see
+//! [Schema drift](crate::api#schema-drift)). When schema drift detection is enabled on a client
+//! (see [`detect_schema_drift`] and [`on_schema_drift`]), each response body is also compared
+//! against the model it is deserialized into and every difference is reported as a
+//! [`SchemaDrift`], along with the endpoint that produced it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//!
+//! async fn get_tracks_with_drift_detection() -> anyhow::Result<api::v2::tracks::Response> {
+//!     let client = api::v2::Client::builder()
+//!         .on_schema_drift(|drift| eprintln!("schema drift: {drift}"))
+//!         .build()?;
+//!
+//!     Ok(client.get_tracks(None).await?)
+//! }
+//! ```
+//!
+//! [`detect_schema_drift`]: crate::api::v2::ClientBuilder::detect_schema_drift
+//! [`on_schema_drift`]: crate::api::v2::ClientBuilder::on_schema_drift
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Difference between an API response and the model it was deserialized into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDrift {
+    /// Endpoint that produced the response, like `GET /tracks`.
+    ///
+    /// The endpoint's path is relative to the API base URL and does not include the query string.
+    pub endpoint: String,
+
+    /// Kind of difference.
+    pub kind: SchemaDriftKind,
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.endpoint, self.kind)
+    }
+}
+
+/// Kind of [`SchemaDrift`].
+///
+/// Paths identify fields in the response body; they are made of field names separated by dots
+/// (`.`), with `[]` denoting array elements (like `tracks[].links.self`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchemaDriftKind {
+    /// Response contains a field that is not part of the model.
+    UnknownField {
+        /// Path of the field.
+        path: String,
+    },
+
+    /// Response is missing a field of the model.
+    ///
+    /// Since deserialization succeeded, the field must be optional or have a default value.
+    /// Only reported for the models of this crate, not for custom response types.
+    MissingField {
+        /// Path of the field.
+        path: String,
+    },
+
+    /// Response contains an enum value that is not known to the model.
+    ///
+    /// Such values are stored in the enum's `Unknown` variant.
+    UnknownEnumValue {
+        /// Name of the enum type.
+        r#type: &'static str,
+
+        /// Value returned by the API.
+        value: String,
+    },
+}
+
+impl fmt::Display for SchemaDriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField { path } => write!(f, "unknown field `{path}`"),
+            Self::MissingField { path } => write!(f, "missing field `{path}`"),
+            Self::UnknownEnumValue { r#type, value } => {
+                write!(f, "unknown value `{value}` for enum `{type}`")
+            },
+        }
+    }
+}
+
+/// Callback invoked for each [`SchemaDrift`] detected in API responses.
+pub type SchemaDriftHandler = Arc<dyn Fn(&SchemaDrift) + Send + Sync>;
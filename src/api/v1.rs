@@ -4,10 +4,17 @@ pub mod ping;
 pub mod solution;
 pub mod track;
 
+use std::fmt::Debug;
+
 use futures::future::Either;
 use futures::stream;
 
-use crate::http::StatusCode;
+use crate::api::IntoQuery;
+use crate::api::detail::ApiRequestBuilder;
+use crate::api::id::{ExerciseSlug, SolutionUuid, TrackSlug};
+use crate::api::raw::{RawResponse, WithRaw};
+use crate::api::request::RequestBuilder;
+use crate::http::{Method, StatusCode};
 use crate::stream::{Bytes, Stream, StreamExt, TryStreamExt};
 use crate::{Error, Result};
 
@@ -37,6 +44,7 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
     ///
     /// # Examples
     ///
@@ -58,11 +66,34 @@ impl Client {
     /// [`Solution::uuid`]: crate::api::v2::solution::Solution::uuid
     /// [`credentials`]: ClientBuilder::credentials
     /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_solution(&self, uuid: &str) -> Result<solution::Response> {
-        self.api_client
-            .get(format!("/solutions/{uuid}"))
-            .execute()
+    pub async fn get_solution<S>(&self, uuid: S) -> Result<solution::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let uuid = uuid.into();
+        self.get_solution_request(&uuid).execute_model().await
+    }
+
+    /// Same as [`get_solution`](Self::get_solution), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_solution_with_raw<S>(&self, uuid: S) -> Result<WithRaw<solution::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let uuid = uuid.into();
+        self.get_solution_request(&uuid)
+            .execute_model_with_raw()
             .await
     }
 
@@ -77,6 +108,7 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
     ///
     /// # Examples
     ///
@@ -103,17 +135,48 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_latest_solution(
+    pub async fn get_latest_solution<T, E>(
         &self,
-        track: &str,
-        exercise: &str,
-    ) -> Result<solution::Response> {
-        self.api_client
-            .get("/solutions/latest")
-            .query(("track_id", Some(track)))
-            .query(("exercise_id", Some(exercise)))
-            .execute()
+        track: T,
+        exercise: E,
+    ) -> Result<solution::Response>
+    where
+        T: Into<TrackSlug> + Debug,
+        E: Into<ExerciseSlug> + Debug,
+    {
+        let track = track.into();
+        let exercise = exercise.into();
+        self.get_latest_solution_request(&track, &exercise)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_latest_solution`](Self::get_latest_solution), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_latest_solution_with_raw<T, E>(
+        &self,
+        track: T,
+        exercise: E,
+    ) -> Result<WithRaw<solution::Response>>
+    where
+        T: Into<TrackSlug> + Debug,
+        E: Into<ExerciseSlug> + Debug,
+    {
+        let track = track.into();
+        let exercise = exercise.into();
+        self.get_latest_solution_request(&track, &exercise)
+            .execute_model_with_raw()
             .await
     }
 
@@ -164,11 +227,15 @@ impl Client {
     ///
     /// [`ApiError`]: Error::ApiError
     #[cfg_attr(not(coverage), tracing::instrument(skip(self)))]
-    pub async fn get_file(
+    pub async fn get_file<S>(
         &self,
-        solution_uuid: &str,
+        solution_uuid: S,
         file_path: &str,
-    ) -> impl Stream<Item = Result<Bytes>> + use<> {
+    ) -> impl Stream<Item = Result<Bytes>> + use<S>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
         let result = self
             .api_client
             .get(format!("/solutions/{solution_uuid}/files/{file_path}"))
@@ -193,6 +260,7 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching track information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
     ///
     /// # Examples
     ///
@@ -212,11 +280,34 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_track(&self, track: &str) -> Result<track::Response> {
-        self.api_client
-            .get(format!("/tracks/{track}"))
-            .execute()
+    pub async fn get_track<T>(&self, track: T) -> Result<track::Response>
+    where
+        T: Into<TrackSlug> + Debug,
+    {
+        let track = track.into();
+        self.get_track_request(&track).execute_model().await
+    }
+
+    /// Same as [`get_track`](Self::get_track), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching track information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_track_with_raw<T>(&self, track: T) -> Result<WithRaw<track::Response>>
+    where
+        T: Into<TrackSlug> + Debug,
+    {
+        let track = track.into();
+        self.get_track_request(&track)
+            .execute_model_with_raw()
             .await
     }
 
@@ -277,6 +368,7 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while pinging API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
     ///
     /// # Examples
     ///
@@ -300,8 +392,103 @@ impl Client {
     /// [`credentials`]: ClientBuilder::credentials
     /// [current implementation]: https://github.com/exercism/website/blob/2580b8fa2b13cad7aa7e8a877551bbd8552bee8b/app/controllers/api/v1/ping_controller.rb
     /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
     pub async fn ping(&self) -> Result<ping::Response> {
-        self.api_client.get("/ping").execute().await
+        self.ping_request().execute_model().await
+    }
+
+    /// Same as [`ping`](Self::ping), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while pinging API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: Error::ApiError
+    /// [`ApiResponseFormatError`]: Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn ping_with_raw(&self) -> Result<WithRaw<ping::Response>> {
+        self.ping_request().execute_model_with_raw().await
+    }
+
+    /// Performs a `GET` request to an arbitrary endpoint of the API and returns the
+    /// [raw response](crate::api::raw::RawResponse).
+    ///
+    /// This can be used to query endpoints that are not modeled by this client. The `path` is
+    /// relative to the [API base URL](ClientBuilder::api_base_url) and must start with a `/`;
+    /// `query` can be any [`IntoQuery`], or `()` to pass no query
+    /// parameters. Requests are performed with the client's [credentials](ClientBuilder::credentials)
+    /// and retry policy.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while performing request to API
+    /// - [`InvalidPath`]: `path` does not start with a `/`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    ///
+    /// async fn get_raw_ping() -> anyhow::Result<String> {
+    ///     let client = api::v1::Client::new()?;
+    ///
+    ///     Ok(client.get_raw("/ping", ()).await?.text())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: Error::ApiError
+    /// [`InvalidPath`]: Error::InvalidPath
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_raw<Q>(&self, path: &str, query: Q) -> Result<RawResponse>
+    where
+        Q: IntoQuery,
+    {
+        self.request(Method::GET, path)
+            .query(query)
+            .send_raw()
+            .await
+    }
+
+    /// Returns a [`RequestBuilder`] that can be used to perform a request to an arbitrary
+    /// endpoint of the API.
+    ///
+    /// This can be used to call endpoints that are not modeled by this client. The `path` is
+    /// relative to the [API base URL](ClientBuilder::api_base_url) and must start with a `/`.
+    /// The request is performed with the client's [credentials](ClientBuilder::credentials) and
+    /// retry policy.
+    ///
+    /// If `path` does not start with a `/`, sending the request will fail with an
+    /// [`InvalidPath`](Error::InvalidPath) error.
+    ///
+    /// See [`request`](crate::api::request) for an example.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "debug"))]
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        if !path.starts_with('/') {
+            return RequestBuilder::new(Err(Error::InvalidPath(path.into())));
+        }
+
+        RequestBuilder::new(Ok(self.api_client.request(method, path)))
+    }
+
+    fn get_solution_request(&self, uuid: &str) -> ApiRequestBuilder {
+        self.api_client.get(format!("/solutions/{uuid}"))
+    }
+
+    fn get_latest_solution_request(&self, track: &str, exercise: &str) -> ApiRequestBuilder {
+        self.api_client
+            .get("/solutions/latest")
+            .query(("track_id", Some(track)))
+            .query(("exercise_id", Some(exercise)))
+    }
+
+    fn get_track_request(&self, track: &str) -> ApiRequestBuilder {
+        self.api_client.get(format!("/tracks/{track}"))
+    }
+
+    fn ping_request(&self) -> ApiRequestBuilder {
+        self.api_client.get("/ping")
     }
 }
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Response to a ping request to the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1PingResponse"))]
 pub struct Response {
     /// Information about the status of the [Exercism](https://exercism.org) services.
     pub status: ServiceStatus,
@@ -11,6 +13,7 @@ pub struct Response {
 
 /// Status of services, as returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ServiceStatus {
     /// Whether the [Exercism website](https://exercism.org) is up and running.
     pub website: bool,
@@ -2,10 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::id::{ExerciseSlug, SolutionUuid};
 use crate::api::v1::track::Track;
 
 /// Response to a query for a solution on the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1SolutionResponse"))]
 pub struct Response {
     /// Solution information.
     pub solution: Solution,
@@ -13,10 +16,12 @@ pub struct Response {
 
 /// A solution returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1Solution"))]
 pub struct Solution {
     /// Solution unique ID.
     #[serde(rename = "id")]
-    pub uuid: String,
+    pub uuid: SolutionUuid,
 
     /// Solution URL.
     ///
@@ -50,6 +55,9 @@ pub struct Solution {
     /// This includes files submitted by the user as well as files that are provided by the
     /// exercise project. Files can be fetched by pre-pending their path with
     /// [`file_download_base_url`](Self::file_download_base_url).
+    ///
+    /// To find out which files are solution files, test files, etc., see
+    /// [`ExerciseConfig`](crate::track_repo::exercise_config::ExerciseConfig).
     pub files: Vec<String>,
 
     /// Information about the submission of the solution.
@@ -61,6 +69,7 @@ pub struct Solution {
 
 /// User who created a solution, as returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct User {
     /// [Exercism](https://exercism.org) user handle.
     pub handle: String,
@@ -71,12 +80,14 @@ pub struct User {
 
 /// Exercise for a solution, as returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1SolutionExercise"))]
 pub struct Exercise {
     /// Exercise name.
     ///
     /// This is an internal name, like `forth`. Also called `slug`.
     #[serde(rename = "id")]
-    pub name: String,
+    pub name: ExerciseSlug,
 
     /// URL of the exercise's instructions (e.g., the public exercise URL).
     pub instructions_url: String,
@@ -87,6 +98,7 @@ pub struct Exercise {
 
 /// Submission of a solution, as returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Submission {
     /// Date/time when the solution has been submitted, in ISO-8601 format.
     pub submitted_at: String,
@@ -2,8 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::id::TrackSlug;
+
 /// Response to a track query on the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1TrackResponse"))]
 pub struct Response {
     /// Information about the language track.
     pub track: Track,
@@ -11,12 +15,14 @@ pub struct Response {
 
 /// A language track returned by the [Exercism website](https://exercism.org) v1 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "V1Track"))]
 pub struct Track {
     /// Name of the language track.
     ///
     /// This is an internal name, like `common-lisp`. Also called `slug`.
     #[serde(rename = "id")]
-    pub name: String,
+    pub name: TrackSlug,
 
     /// Language track title.
     ///
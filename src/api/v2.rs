@@ -3,27 +3,75 @@
 pub mod exercise;
 pub mod exercises;
 pub mod iteration;
+pub mod offline;
+pub mod pool;
 pub mod solution;
 pub mod solutions;
+pub mod stats;
 pub mod submission;
+pub mod tag;
 pub mod tests;
 pub mod track;
 pub mod tracks;
 pub mod user;
 
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt, stream};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
 use crate::Result;
+use crate::api::IntoQuery;
+use crate::api::detail::ApiRequestBuilder;
+use crate::api::id::{IterationUuid, SolutionUuid, SubmissionUuid, TrackSlug};
+use crate::api::link::Link;
+use crate::api::raw::{RawResponse, WithRaw};
+use crate::api::request::RequestBuilder;
+use crate::http::Method;
 
 /// Default base URL for the [Exercism website](https://exercism.org) v2 API.
 pub const DEFAULT_V2_API_BASE_URL: &str = "https://exercism.org/api/v2";
 
+/// Maximum number of concurrent requests performed by
+/// [`Client::fill_automated_feedback`].
+pub const AUTOMATED_FEEDBACK_MAX_CONCURRENCY: usize = 4;
+
 define_api_client! {
     /// Client for the [Exercism website](https://exercism.org) v2 API.
     ///
     /// This API is undocumented and is mostly used by the website itself to fetch information.
-    pub struct Client(DEFAULT_V2_API_BASE_URL);
+    ///
+    /// A client can also be configured to work in [offline mode](ClientBuilder::offline).
+    pub struct Client(DEFAULT_V2_API_BASE_URL) {
+        offline: Option<Arc<offline::Snapshot>>,
+    };
+}
+
+impl ClientBuilder {
+    /// Configures the client to work in offline mode, answering queries from the given
+    /// [`Snapshot`](offline::Snapshot) instead of performing requests.
+    ///
+    /// Only [`get_tracks`](Client::get_tracks), [`get_exercises`](Client::get_exercises),
+    /// [`get_solutions`](Client::get_solutions), [`get_solution`](Client::get_solution) and
+    /// [`get_submission_files`](Client::get_submission_files) are supported in offline mode.
+    /// Querying data that is not in the snapshot results in a
+    /// [`NotSynced`](crate::Error::NotSynced) error, while calling other methods results in an
+    /// [`OfflineUnsupported`](crate::Error::OfflineUnsupported) error.
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, level = "trace"))]
+    pub fn offline(&mut self, snapshot: offline::Snapshot) -> &mut Self {
+        self.offline = Some(Arc::new(snapshot));
+        self
+    }
 }
 
 impl Client {
+    /// Returns `true` if the client is in [offline mode](ClientBuilder::offline).
+    pub fn is_offline(&self) -> bool {
+        self.offline.is_some()
+    }
+
     /// Returns a list of [Exercism tracks](https://exercism.org/tracks).
     ///
     /// - If the request is performed anonymously, will return a list of all tracks
@@ -37,16 +85,19 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching track information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the information was never synced
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use mini_exercism::api;
+    /// use mini_exercism::api::id::TrackSlug;
     /// use mini_exercism::api::v2::tracks::Filters;
     /// use mini_exercism::api::v2::tracks::StatusFilter::Joined;
     /// use mini_exercism::core::Credentials;
     ///
-    /// async fn get_joined_tracks(api_token: &str) -> anyhow::Result<Vec<String>> {
+    /// async fn get_joined_tracks(api_token: &str) -> anyhow::Result<Vec<TrackSlug>> {
     ///     let credentials = Credentials::from_api_token(api_token);
     ///     let client = api::v2::Client::builder()
     ///         .credentials(credentials)
@@ -60,15 +111,43 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
     pub async fn get_tracks(
         &self,
         filters: Option<tracks::Filters<'_>>,
     ) -> Result<tracks::Response> {
-        self.api_client
-            .get("/tracks")
-            .query(filters)
-            .execute()
+        if let Some(snapshot) = &self.offline {
+            return snapshot.get_tracks(filters);
+        }
+
+        self.get_tracks_request(filters).execute_model().await
+    }
+
+    /// Same as [`get_tracks`](Self::get_tracks), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching track information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_tracks_with_raw(
+        &self,
+        filters: Option<tracks::Filters<'_>>,
+    ) -> Result<WithRaw<tracks::Response>> {
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_tracks_with_raw"));
+        }
+
+        self.get_tracks_request(filters)
+            .execute_model_with_raw()
             .await
     }
 
@@ -97,18 +176,21 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching exercise information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the information was never synced
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use mini_exercism::api;
+    /// use mini_exercism::api::id::{SolutionUuid, TrackSlug};
     /// use mini_exercism::api::v2::exercises::Filters;
     /// use mini_exercism::core::Credentials;
     ///
     /// async fn get_published_solution_uuids(
     ///     api_token: &str,
-    ///     track: &str,
-    /// ) -> anyhow::Result<Vec<String>> {
+    ///     track: &TrackSlug,
+    /// ) -> anyhow::Result<Vec<SolutionUuid>> {
     ///     let credentials = Credentials::from_api_token(api_token);
     ///     let client = api::v2::Client::builder()
     ///         .credentials(credentials)
@@ -126,16 +208,55 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_exercises(
+    pub async fn get_exercises<T>(
         &self,
-        track: &str,
+        track: T,
         filters: Option<exercises::Filters<'_>>,
-    ) -> Result<exercises::Response> {
-        self.api_client
-            .get(format!("/tracks/{track}/exercises"))
-            .query(filters)
-            .execute()
+    ) -> Result<exercises::Response>
+    where
+        T: Into<TrackSlug> + Debug,
+    {
+        let track = track.into();
+        if let Some(snapshot) = &self.offline {
+            return snapshot.get_exercises(&track, filters);
+        }
+
+        self.get_exercises_request(&track, filters)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_exercises`](Self::get_exercises), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching exercise information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_exercises_with_raw<T>(
+        &self,
+        track: T,
+        filters: Option<exercises::Filters<'_>>,
+    ) -> Result<WithRaw<exercises::Response>>
+    where
+        T: Into<TrackSlug> + Debug,
+    {
+        let track = track.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_exercises_with_raw"));
+        }
+
+        self.get_exercises_request(&track, filters)
+            .execute_model_with_raw()
             .await
     }
 
@@ -153,6 +274,8 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching solutions information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the information was never synced
     ///
     /// # Examples
     ///
@@ -193,6 +316,8 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
     pub async fn get_solutions(
         &self,
@@ -200,12 +325,40 @@ impl Client {
         paging: Option<solutions::Paging>,
         sort_order: Option<solutions::SortOrder>,
     ) -> Result<solutions::Response> {
-        self.api_client
-            .get("/solutions")
-            .query(filters)
-            .query(paging)
-            .query(("order", sort_order))
-            .execute()
+        if let Some(snapshot) = &self.offline {
+            return snapshot.get_solutions(filters, paging, sort_order);
+        }
+
+        self.get_solutions_request(filters, paging, sort_order)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_solutions`](Self::get_solutions), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching solutions information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_solutions_with_raw(
+        &self,
+        filters: Option<solutions::Filters<'_>>,
+        paging: Option<solutions::Paging>,
+        sort_order: Option<solutions::SortOrder>,
+    ) -> Result<WithRaw<solutions::Response>> {
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_solutions_with_raw"));
+        }
+
+        self.get_solutions_request(filters, paging, sort_order)
+            .execute_model_with_raw()
             .await
     }
 
@@ -218,6 +371,8 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the information was never synced
     ///
     /// # Examples
     ///
@@ -240,16 +395,55 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_solution(
+    pub async fn get_solution<S>(
         &self,
-        uuid: &str,
+        uuid: S,
         include_iterations: bool,
-    ) -> Result<solution::Response> {
-        self.api_client
-            .get(format!("/solutions/{uuid}"))
-            .query(("sideload", include_iterations.then_some("iterations")))
-            .execute()
+    ) -> Result<solution::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let uuid = uuid.into();
+        if let Some(snapshot) = &self.offline {
+            return snapshot.get_solution(&uuid, include_iterations);
+        }
+
+        self.get_solution_request(&uuid, include_iterations)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_solution`](Self::get_solution), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching solution information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_solution_with_raw<S>(
+        &self,
+        uuid: S,
+        include_iterations: bool,
+    ) -> Result<WithRaw<solution::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let uuid = uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_solution_with_raw"));
+        }
+
+        self.get_solution_request(&uuid, include_iterations)
+            .execute_model_with_raw()
             .await
     }
 
@@ -276,6 +470,8 @@ impl Client {
     /// # Errors
     ///
     /// - [`ApiError`]: Error while fetching submitted files information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the information was never synced
     ///
     /// # Examples
     ///
@@ -320,15 +516,923 @@ impl Client {
     /// ```
     ///
     /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
+    pub async fn get_submission_files<S, U>(
+        &self,
+        solution_uuid: S,
+        submission_uuid: U,
+    ) -> Result<submission::files::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+        U: Into<SubmissionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let submission_uuid = submission_uuid.into();
+        if let Some(snapshot) = &self.offline {
+            return snapshot.get_submission_files(&submission_uuid);
+        }
+
+        self.get_submission_files_request(&solution_uuid, &submission_uuid)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_submission_files`](Self::get_submission_files), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching submitted files information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_submission_files_with_raw<S, U>(
+        &self,
+        solution_uuid: S,
+        submission_uuid: U,
+    ) -> Result<WithRaw<submission::files::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+        U: Into<SubmissionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let submission_uuid = submission_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_submission_files_with_raw"));
+        }
+
+        self.get_submission_files_request(&solution_uuid, &submission_uuid)
+            .execute_model_with_raw()
+            .await
+    }
+
+    /// Returns the test run of a submission for a solution iteration.
+    ///
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// The test run contains the detailed results reported by the track's [test runner](https://exercism.org/docs/building/tooling/test-runners).
+    /// If the submission has not been tested yet, the response's [`test_run`](tests::run::Response::test_run)
+    /// will be `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching test run information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    /// use mini_exercism::tooling::test_runner::TestResult;
+    ///
+    /// async fn get_latest_failing_tests(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<Vec<TestResult>> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let submission_uuid = client
+    ///         .get_solution(solution_uuid, true)
+    ///         .await?
+    ///         .iterations
+    ///         .into_iter()
+    ///         .find(|iteration| iteration.is_latest)
+    ///         .and_then(|iteration| iteration.submission_uuid)
+    ///         .ok_or_else(|| anyhow::anyhow!("could not find submission uuid"))?;
+    ///
+    ///     let test_run = client
+    ///         .get_test_run(solution_uuid, &submission_uuid)
+    ///         .await?
+    ///         .test_run;
+    ///
+    ///     Ok(test_run
+    ///         .map(|test_run| test_run.to_results().failing_tests().cloned().collect())
+    ///         .unwrap_or_default())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
+    pub async fn get_test_run<S, U>(
+        &self,
+        solution_uuid: S,
+        submission_uuid: U,
+    ) -> Result<tests::run::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+        U: Into<SubmissionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let submission_uuid = submission_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_test_run"));
+        }
+
+        self.get_test_run_request(&solution_uuid, &submission_uuid)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_test_run`](Self::get_test_run), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching test run information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_test_run_with_raw<S, U>(
+        &self,
+        solution_uuid: S,
+        submission_uuid: U,
+    ) -> Result<WithRaw<tests::run::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+        U: Into<SubmissionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let submission_uuid = submission_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_test_run_with_raw"));
+        }
+
+        self.get_test_run_request(&solution_uuid, &submission_uuid)
+            .execute_model_with_raw()
+            .await
+    }
+
+    /// Returns the automated feedback provided for a solution iteration.
+    ///
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// The response's [`iteration`](iteration::Response::iteration) contains the feedback
+    /// provided by the track's [representer](iteration::Iteration::representer_feedback) and
+    /// [analyzer](iteration::Iteration::analyzer_feedback). To fill the feedback of many
+    /// iterations at once, see [`fill_automated_feedback`](Self::fill_automated_feedback).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching automated feedback from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::api::v2::submission::analysis::AnalyzerComment;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn get_latest_analyzer_comments(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<Vec<AnalyzerComment>> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let iteration_uuid = client
+    ///         .get_solution(solution_uuid, true)
+    ///         .await?
+    ///         .iterations
+    ///         .into_iter()
+    ///         .find(|iteration| iteration.is_latest)
+    ///         .map(|iteration| iteration.uuid)
+    ///         .ok_or_else(|| anyhow::anyhow!("could not find latest iteration"))?;
+    ///
+    ///     let iteration = client
+    ///         .get_automated_feedback(solution_uuid, &iteration_uuid)
+    ///         .await?
+    ///         .iteration;
+    ///
+    ///     Ok(iteration
+    ///         .analyzer_feedback
+    ///         .map(|feedback| feedback.comments)
+    ///         .unwrap_or_default())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
+    pub async fn get_automated_feedback<S, I>(
+        &self,
+        solution_uuid: S,
+        iteration_uuid: I,
+    ) -> Result<iteration::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+        I: Into<IterationUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let iteration_uuid = iteration_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_automated_feedback"));
+        }
+
+        self.get_automated_feedback_request(&solution_uuid, &iteration_uuid)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_automated_feedback`](Self::get_automated_feedback), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching automated feedback from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_automated_feedback_with_raw<S, I>(
+        &self,
+        solution_uuid: S,
+        iteration_uuid: I,
+    ) -> Result<WithRaw<iteration::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+        I: Into<IterationUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let iteration_uuid = iteration_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_automated_feedback_with_raw"));
+        }
+
+        self.get_automated_feedback_request(&solution_uuid, &iteration_uuid)
+            .execute_model_with_raw()
+            .await
+    }
+
+    /// Fills the [representer](iteration::Iteration::representer_feedback) and
+    /// [analyzer](iteration::Iteration::analyzer_feedback) feedback of the given iterations.
+    ///
+    /// The feedback of each iteration is fetched by following its
+    /// [`automated_feedback`](iteration::Links::automated_feedback) link; iterations without
+    /// such a link (like deleted iterations) are left untouched. Up to
+    /// [`AUTOMATED_FEEDBACK_MAX_CONCURRENCY`] requests are performed concurrently.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching automated feedback from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// If an error occurs, the feedback of the iterations is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::api::v2::iteration::Iteration;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn get_iterations_with_feedback(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<Vec<Iteration>> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let mut iterations = client.get_solution(solution_uuid, true).await?.iterations;
+    ///     client.fill_automated_feedback(&mut iterations).await?;
+    ///
+    ///     Ok(iterations)
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, fields(iterations = iterations.len()), err))]
+    pub async fn fill_automated_feedback(
+        &self,
+        iterations: &mut [iteration::Iteration],
+    ) -> Result<()> {
+        let links: Vec<_> = iterations
+            .iter()
+            .enumerate()
+            .filter_map(|(index, iteration)| {
+                Some((index, iteration.links.automated_feedback.clone()?))
+            })
+            .collect();
+        let feedback: Vec<_> = stream::iter(links)
+            .map(|(index, link)| async move {
+                Ok::<_, crate::Error>((index, self.follow(&link).await?.iteration))
+            })
+            .buffer_unordered(AUTOMATED_FEEDBACK_MAX_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        for (index, iteration) in feedback {
+            iterations[index].representer_feedback = iteration.representer_feedback;
+            iterations[index].analyzer_feedback = iteration.analyzer_feedback;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the differences between the files submitted for two iterations of a solution.
+    ///
+    /// If an iteration's [`files`](iteration::Iteration::files) were sideloaded, they are used
+    /// directly; otherwise, they are fetched using [`get_submission_files`](Self::get_submission_files).
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching submission files from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`NotSynced`]: Client is [offline](ClientBuilder::offline) and the files were never synced
+    /// - [`IterationDeleted`]: One of the iterations was deleted and has no files
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn print_latest_changes(api_token: &str, solution_uuid: &str) -> anyhow::Result<()> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let iterations = client.get_solution(solution_uuid, true).await?.iterations;
+    ///     if let [.., previous, latest] = iterations.as_slice() {
+    ///         let diff = client
+    ///             .diff_iterations(solution_uuid, previous, latest)
+    ///             .await?;
+    ///         println!("{diff}");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`NotSynced`]: crate::Error::NotSynced
+    /// [`IterationDeleted`]: crate::Error::IterationDeleted
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, fields(old = old.index, new = new.index), err))]
+    pub async fn diff_iterations<S>(
+        &self,
+        solution_uuid: S,
+        old: &iteration::Iteration,
+        new: &iteration::Iteration,
+    ) -> Result<iteration::diff::Diff>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        let old_files = self.iteration_files(&solution_uuid, old).await?;
+        let new_files = self.iteration_files(&solution_uuid, new).await?;
+
+        Ok(iteration::diff::Diff::between(&old_files, &new_files))
+    }
+
+    /// Returns the changes made to a solution's exercise since the solution was started.
+    ///
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// The changes usually only include the exercise's test files. They are only meaningful for
+    /// [out-of-date](solution::Solution::is_out_of_date) solutions; up-to-date solutions will
+    /// return an empty [`Diff`](solution::update::Diff).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching exercise changes from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn get_changed_test_files(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<Vec<String>> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let diff = client.get_exercise_diff(solution_uuid).await?.diff;
+    ///     Ok(diff
+    ///         .files
+    ///         .into_iter()
+    ///         .map(|file| file.relative_path)
+    ///         .collect())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
+    pub async fn get_exercise_diff<S>(
+        &self,
+        solution_uuid: S,
+    ) -> Result<solution::update::DiffResponse>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_exercise_diff"));
+        }
+
+        self.get_exercise_diff_request(&solution_uuid)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`get_exercise_diff`](Self::get_exercise_diff), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching exercise changes from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_exercise_diff_with_raw<S>(
+        &self,
+        solution_uuid: S,
+    ) -> Result<WithRaw<solution::update::DiffResponse>>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("get_exercise_diff_with_raw"));
+        }
+
+        self.get_exercise_diff_request(&solution_uuid)
+            .execute_model_with_raw()
+            .await
+    }
+
+    /// Updates an [out-of-date](solution::Solution::is_out_of_date) solution to the latest version
+    /// of its exercise, returning the updated solution.
+    ///
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// To see the changes that will be applied to the solution, use [`get_exercise_diff`](Self::get_exercise_diff).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while updating solution through API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn update_solution(api_token: &str, solution_uuid: &str) -> anyhow::Result<()> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let solution = client.update_solution(solution_uuid).await?.solution;
+    ///     assert!(!solution.is_out_of_date);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
+    pub async fn update_solution<S>(&self, solution_uuid: S) -> Result<solution::Response>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("update_solution"));
+        }
+
+        self.update_solution_request(&solution_uuid)
+            .execute_model()
+            .await
+    }
+
+    /// Same as [`update_solution`](Self::update_solution), but also returns the
+    /// [raw response](crate::api::raw) returned by the API.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while updating solution through API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn update_solution_with_raw<S>(
+        &self,
+        solution_uuid: S,
+    ) -> Result<WithRaw<solution::Response>>
+    where
+        S: Into<SolutionUuid> + Debug,
+    {
+        let solution_uuid = solution_uuid.into();
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("update_solution_with_raw"));
+        }
+
+        self.update_solution_request(&solution_uuid)
+            .execute_model_with_raw()
+            .await
+    }
+
+    /// Updates all of the user's [out-of-date](solution::Solution::is_out_of_date) solutions to
+    /// the latest version of their exercise, one by one.
+    ///
+    /// This request cannot be performed anonymously; doing so will result in an [`ApiError`].
+    ///
+    /// If `track` is specified, only solutions in that track are updated. For each solution,
+    /// the [changes](Self::get_exercise_diff) made to the exercise are fetched before the
+    /// solution is [updated](Self::update_solution). If `dry_run` is `true`, solutions are
+    /// not updated, but the changes that would be applied are still returned.
+    ///
+    /// If fetching the changes or updating a solution fails, the error is recorded in its
+    /// [`Update::error`](solution::update::Update::error) and the remaining solutions are
+    /// still processed, so that the returned updates always reflect what changed.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching out-of-date solutions through API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn update_all_solutions(api_token: &str) -> anyhow::Result<usize> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let updates = client
+    ///         .update_out_of_date_solutions(None::<&str>, false)
+    ///         .await?;
+    ///     Ok(updates.len())
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn update_out_of_date_solutions<T>(
+        &self,
+        track: Option<T>,
+        dry_run: bool,
+    ) -> Result<Vec<solution::update::Update>>
+    where
+        T: Into<TrackSlug> + Debug,
+    {
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported("update_out_of_date_solutions"));
+        }
+
+        let mut query = solutions::query::Query::builder();
+        query.out_of_date(true);
+        if let Some(track) = track {
+            query.track(track);
+        }
+        let out_of_date_solutions = query.build().fetch(self, None).await?;
+
+        let mut updates = Vec::with_capacity(out_of_date_solutions.len());
+        for solution in out_of_date_solutions {
+            updates.push(self.update_out_of_date_solution(solution, dry_run).await);
+        }
+
+        Ok(updates)
+    }
+
+    async fn update_out_of_date_solution(
+        &self,
+        solution: solution::Solution,
+        dry_run: bool,
+    ) -> solution::update::Update {
+        let diff = match self.get_exercise_diff(&solution.uuid).await {
+            Ok(response) => response.diff,
+            Err(error) => {
+                return solution::update::Update::failed(solution, Default::default(), error);
+            },
+        };
+        if dry_run {
+            return solution::update::Update { solution, diff, updated: false, error: None };
+        }
+
+        match self.update_solution(&solution.uuid).await {
+            Ok(response) => solution::update::Update {
+                solution: response.solution,
+                diff,
+                updated: true,
+                error: None,
+            },
+            Err(error) => solution::update::Update::failed(solution, diff, error),
+        }
+    }
+
+    /// Performs a `GET` request to an arbitrary endpoint of the API and returns the
+    /// [raw response](crate::api::raw::RawResponse).
+    ///
+    /// This can be used to query endpoints that are not modeled by this client. The `path` is
+    /// relative to the [API base URL](ClientBuilder::api_base_url) and must start with a `/`;
+    /// `query` can be any [`IntoQuery`], or `()` to pass no query
+    /// parameters. Requests are performed with the client's [credentials](ClientBuilder::credentials)
+    /// and retry policy.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while performing request to API
+    /// - [`InvalidPath`]: `path` does not start with a `/`
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn get_raw_solution(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<serde_json::Value> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let path = format!("/solutions/{solution_uuid}");
+    ///     let raw = client
+    ///         .get_raw(&path, ("sideload", Some("iterations")))
+    ///         .await?;
+    ///
+    ///     Ok(raw.json()?)
+    /// }
+    /// ```
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`InvalidPath`]: crate::Error::InvalidPath
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn get_raw<Q>(&self, path: &str, query: Q) -> Result<RawResponse>
+    where
+        Q: IntoQuery,
+    {
+        self.request(Method::GET, path)
+            .query(query)
+            .send_raw()
+            .await
+    }
+
+    /// Returns a [`RequestBuilder`] that can be used to perform a request to an arbitrary
+    /// endpoint of the API.
+    ///
+    /// This can be used to call endpoints that are not modeled by this client. The `path` is
+    /// relative to the [API base URL](ClientBuilder::api_base_url) and must start with a `/`.
+    /// The request is performed with the client's [credentials](ClientBuilder::credentials) and
+    /// retry policy.
+    ///
+    /// If `path` does not start with a `/`, sending the request will fail with an
+    /// [`InvalidPath`](crate::Error::InvalidPath) error.
+    ///
+    /// If the client is [offline](ClientBuilder::offline), sending the request will fail with an
+    /// [`OfflineUnsupported`](crate::Error::OfflineUnsupported) error.
+    ///
+    /// See [`request`](crate::api::request) for an example.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "debug"))]
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        if !path.starts_with('/') {
+            return RequestBuilder::new(Err(crate::Error::InvalidPath(path.into())));
+        }
+
+        if self.offline.is_some() {
+            return RequestBuilder::new(Err(crate::Error::OfflineUnsupported("request")));
+        }
+
+        RequestBuilder::new(Ok(self.api_client.request(method, path)))
+    }
+
+    /// Follows a [`Link`] found in a model (like [`iteration::Links::test_run`]), returning the
+    /// resource it points to.
+    ///
+    /// Links to the default [API base URL](DEFAULT_V2_API_BASE_URL) (or path-only links to it)
+    /// are resolved against the client's [API base URL](ClientBuilder::api_base_url). Other
+    /// path-only links are resolved against the root of the website. Links to other hosts are
+    /// fetched as-is, without sending the client's [credentials](ClientBuilder::credentials).
+    ///
+    /// Links to [web pages](crate::api::link::WebPage) cannot be followed using this method; see
+    /// [`follow_raw`](Self::follow_raw) instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching the linked resource
+    /// - [`ApiResponseFormatError`]: Linked resource could not be deserialized
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::api::v2::submission;
+    /// use mini_exercism::core::Credentials;
+    ///
+    /// async fn get_iteration_files(
+    ///     api_token: &str,
+    ///     solution_uuid: &str,
+    /// ) -> anyhow::Result<Vec<Vec<submission::files::File>>> {
+    ///     let credentials = Credentials::from_api_token(api_token);
+    ///     let client = api::v2::Client::builder()
+    ///         .credentials(credentials)
+    ///         .build()?;
+    ///
+    ///     let iterations = client.get_solution(solution_uuid, true).await?.iterations;
+    ///     let mut files = Vec::new();
+    ///     for link in iterations.iter().filter_map(|iteration| iteration.links.files.as_ref()) {
+    ///         files.push(client.follow(link).await?.files);
+    ///     }
+    ///
+    ///     Ok(files)
+    /// }
+    /// ```
+    ///
+    /// [`iteration::Links::test_run`]: iteration::Links::test_run
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
     #[cfg_attr(not(coverage), tracing::instrument(skip(self), ret, err))]
-    pub async fn get_submission_files(
+    pub async fn follow<T>(&self, link: &Link<T>) -> Result<T>
+    where
+        T: DeserializeOwned + Serialize + Debug,
+    {
+        self.follow_request(link, "follow")?.execute_model().await
+    }
+
+    /// Follows a [`Link`] found in a model, returning the [raw response](crate::api::raw).
+    ///
+    /// Unlike [`follow`](Self::follow), this can be used to fetch [web pages](crate::api::link::WebPage).
+    /// Links are resolved in the same way.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching the linked resource
+    /// - [`OfflineUnsupported`]: Client is [offline](ClientBuilder::offline)
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`OfflineUnsupported`]: crate::Error::OfflineUnsupported
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err))]
+    pub async fn follow_raw<T>(&self, link: &Link<T>) -> Result<RawResponse> {
+        self.follow_request(link, "follow_raw")?.send_raw().await
+    }
+
+    async fn iteration_files(
+        &self,
+        solution_uuid: &str,
+        iteration: &iteration::Iteration,
+    ) -> Result<Vec<submission::files::File>> {
+        if !iteration.files.is_empty() {
+            return Ok(iteration.files.clone());
+        }
+
+        let submission_uuid = iteration
+            .submission_uuid
+            .as_deref()
+            .ok_or(crate::Error::IterationDeleted(iteration.index))?;
+        Ok(self
+            .get_submission_files(solution_uuid, submission_uuid)
+            .await?
+            .files)
+    }
+
+    fn follow_request<T>(
+        &self,
+        link: &Link<T>,
+        operation: &'static str,
+    ) -> Result<ApiRequestBuilder> {
+        if self.offline.is_some() {
+            return Err(crate::Error::OfflineUnsupported(operation));
+        }
+
+        Ok(self.api_client.follow(DEFAULT_V2_API_BASE_URL, link.href()))
+    }
+
+    fn get_tracks_request(&self, filters: Option<tracks::Filters<'_>>) -> ApiRequestBuilder {
+        self.api_client.get("/tracks").query(filters)
+    }
+
+    fn get_exercises_request(
+        &self,
+        track: &str,
+        filters: Option<exercises::Filters<'_>>,
+    ) -> ApiRequestBuilder {
+        self.api_client
+            .get(format!("/tracks/{track}/exercises"))
+            .query(filters)
+    }
+
+    fn get_solutions_request(
+        &self,
+        filters: Option<solutions::Filters<'_>>,
+        paging: Option<solutions::Paging>,
+        sort_order: Option<solutions::SortOrder>,
+    ) -> ApiRequestBuilder {
+        self.api_client
+            .get("/solutions")
+            .query(filters)
+            .query(paging)
+            .query(("order", sort_order))
+    }
+
+    fn get_solution_request(&self, uuid: &str, include_iterations: bool) -> ApiRequestBuilder {
+        self.api_client
+            .get(format!("/solutions/{uuid}"))
+            .query(("sideload", include_iterations.then_some("iterations")))
+    }
+
+    fn get_submission_files_request(
         &self,
         solution_uuid: &str,
         submission_uuid: &str,
-    ) -> Result<submission::files::Response> {
+    ) -> ApiRequestBuilder {
         self.api_client
             .get(format!("/solutions/{solution_uuid}/submissions/{submission_uuid}/files"))
-            .execute()
-            .await
+    }
+
+    fn get_test_run_request(
+        &self,
+        solution_uuid: &str,
+        submission_uuid: &str,
+    ) -> ApiRequestBuilder {
+        self.api_client
+            .get(format!("/solutions/{solution_uuid}/submissions/{submission_uuid}/test_run"))
+    }
+
+    fn get_automated_feedback_request(
+        &self,
+        solution_uuid: &str,
+        iteration_uuid: &str,
+    ) -> ApiRequestBuilder {
+        self.api_client.get(format!(
+            "/solutions/{solution_uuid}/iterations/{iteration_uuid}/automated_feedback"
+        ))
+    }
+
+    fn get_exercise_diff_request(&self, solution_uuid: &str) -> ApiRequestBuilder {
+        self.api_client
+            .get(format!("/solutions/{solution_uuid}/diff"))
+    }
+
+    fn update_solution_request(&self, solution_uuid: &str) -> ApiRequestBuilder {
+        self.api_client
+            .patch(format!("/solutions/{solution_uuid}/sync"))
     }
 }
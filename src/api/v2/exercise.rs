@@ -1,16 +1,20 @@
 //! Types related to exercises returned by the [Exercism website](https://exercism.org) v2 API.
 
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, IntoStaticStr};
+use strum::{AsRefStr, Display};
+
+use crate::api::id::ExerciseSlug;
+use crate::api::link::{Link, WebPage};
 
 /// A single exercise returned by the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Exercise {
     /// Name of the exercise.
     ///
     /// This is an internal name, like `forth`. Also called `slug`.
     #[serde(rename = "slug")]
-    pub name: String,
+    pub name: ExerciseSlug,
 
     /// Type of exercise.
     #[serde(rename = "type")]
@@ -51,10 +55,10 @@ pub struct Exercise {
 }
 
 /// Possible type of exercise on the [Exercism website](https://exercism.org).
-#[derive(
-    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr, IntoStaticStr,
-)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "ExerciseType"))]
+#[serde(rename_all = "snake_case", expecting = "invalid exercise type: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Type {
     /// Tutorial exercise.
@@ -74,18 +78,20 @@ pub enum Type {
     ///
     /// Included so that if new exercise types are introduced in the website API later,
     /// this crate will not break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Type>")]
+    #[strum(transparent)]
+    Unknown(String),
 }
 
 /// Possible difficulty rating of an exercise on the [Exercism website](https://exercism.org).
 ///
 /// Internally, exercises have a difficulty rating between 1 and 10 (inclusive); however, on the
 /// website, this is only represented by specific, named difficulty ratings.
-#[derive(
-    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr, IntoStaticStr,
-)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", expecting = "invalid exercise difficulty: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Difficulty {
     /// Easy exercise.
@@ -107,14 +113,46 @@ pub enum Difficulty {
     ///
     /// Included so that if new exercise difficulty ratings are introduced in the website API later,
     /// this crate will not break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::api::detail::deserialize_unknown::<_, Difficulty>"
+    )]
+    #[strum(transparent)]
+    Unknown(String),
+}
+
+impl Difficulty {
+    /// Returns the named difficulty corresponding to an internal difficulty rating.
+    ///
+    /// Internal difficulty ratings are found in a language track's `config.json` file
+    /// (see [`track_repo::config`](crate::track_repo::config)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_exercism::api::v2::exercise::Difficulty;
+    ///
+    /// assert_eq!(Difficulty::Easy, Difficulty::from_rating(2));
+    /// assert_eq!(Difficulty::Medium, Difficulty::from_rating(4));
+    /// assert_eq!(Difficulty::Hard, Difficulty::from_rating(8));
+    /// ```
+    pub fn from_rating(rating: u8) -> Self {
+        match rating {
+            0..=3 => Self::Easy,
+            4..=7 => Self::Medium,
+            _ => Self::Hard,
+        }
+    }
 }
 
 /// Links pertaining to an [Exercism](https://exercism.org) exercise returned by the v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "ExerciseLinks"))]
 pub struct Links {
     /// Path of the exercise on the [Exercism website](https://exercism.org), without the domain name.
     #[serde(rename = "self")]
-    pub self_path: String,
+    pub self_path: Link<WebPage>,
 }
@@ -51,6 +51,8 @@ impl<'a> FiltersBuilder<'a> {
 
 /// Response to a query for exercises on the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "ExercisesResponse"))]
 pub struct Response {
     /// List of exercises for the requested track.
     ///
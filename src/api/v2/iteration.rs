@@ -3,19 +3,34 @@
 //! Solutions to exercises can have multiple iterations.
 
 pub(crate) mod detail;
+pub mod diff;
 
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, IntoStaticStr};
+use strum::{AsRefStr, Display};
 
+use crate::api::id::{IterationUuid, SubmissionUuid};
+use crate::api::link::{Link, WebPage};
 use crate::api::v2::submission::analysis::{AnalyzerFeedback, RepresenterFeedback};
 use crate::api::v2::{submission, tests};
 
+/// Response to a query for an iteration's automated feedback on the
+/// [Exercism website](https://exercism.org) v2 API (see [`Links::automated_feedback`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "IterationResponse"))]
+pub struct Response {
+    /// Iteration information, including its [representer](Iteration::representer_feedback)
+    /// and [analyzer](Iteration::analyzer_feedback) feedback.
+    pub iteration: Iteration,
+}
+
 /// Information about a specific iteration of a [`Solution`](crate::api::v2::solution::Solution)
 /// submitted to the [Exercism website](https://exercism.org).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Iteration {
     /// Iteration unique ID.
-    pub uuid: String,
+    pub uuid: IterationUuid,
 
     /// Unique ID of the iteration's submission.
     ///
@@ -23,7 +38,7 @@ pub struct Iteration {
     ///
     /// Will be `None` for deleted iterations.
     #[serde(default)]
-    pub submission_uuid: Option<String>,
+    pub submission_uuid: Option<SubmissionUuid>,
 
     /// 1-based index of the iteration.
     ///
@@ -80,8 +95,9 @@ pub struct Iteration {
     ///
     /// # Notes
     ///
-    /// This field is only filled if automated feedback is sideloaded, which is not currently possible with the
-    /// v2 API [`Client`](crate::api::v2::Client).
+    /// This field is only filled if automated feedback is sideloaded. To fetch it, see
+    /// [`get_automated_feedback`](crate::api::v2::Client::get_automated_feedback) or
+    /// [`fill_automated_feedback`](crate::api::v2::Client::fill_automated_feedback).
     #[serde(default, deserialize_with = "detail::deserialize_optional_feedback")]
     pub representer_feedback: Option<RepresenterFeedback>,
 
@@ -92,8 +108,9 @@ pub struct Iteration {
     ///
     /// # Notes
     ///
-    /// This field is only filled if automated feedback is sideloaded, which is not currently possible with the
-    /// v2 API [`Client`](crate::api::v2::Client).
+    /// This field is only filled if automated feedback is sideloaded. To fetch it, see
+    /// [`get_automated_feedback`](crate::api::v2::Client::get_automated_feedback) or
+    /// [`fill_automated_feedback`](crate::api::v2::Client::fill_automated_feedback).
     #[serde(default, deserialize_with = "detail::deserialize_optional_feedback")]
     pub analyzer_feedback: Option<AnalyzerFeedback>,
 
@@ -125,20 +142,10 @@ pub struct Iteration {
 }
 
 /// Possible status of a solution iteration submitted to the [Exercism website](https://exercism.org).
-#[derive(
-    Debug,
-    Default,
-    Copy,
-    Clone,
-    PartialEq,
-    Eq,
-    Serialize,
-    Deserialize,
-    Display,
-    AsRefStr,
-    IntoStaticStr,
-)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "IterationStatus"))]
+#[serde(rename_all = "snake_case", expecting = "invalid iteration status: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Status {
     /// Iteration has been submitted but has not been queued for testing yet.
@@ -192,24 +199,29 @@ pub enum Status {
     ///
     /// Included so that if new iteration statuses are introduced in the website API later,
     /// this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Status>")]
+    #[strum(transparent)]
+    Unknown(String),
 }
 
 /// Links pertaining to an [Exercism](https://exercism.org) iteration returned by the v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "IterationLinks"))]
 pub struct Links {
     /// URL of the iteration on the [Exercism website](https://exercism.org).
     ///
     /// This URL is only valid for the user that submitted the iteration.
     #[serde(rename = "self")]
-    pub self_path: String,
+    pub self_path: Link<WebPage>,
 
     /// API URL that can be used to fetch the iteration's submission's automated feedback.
     ///
     /// Will be `None` if the iteration has no automated feedback (if it is deleted, for example).
     #[serde(default)]
-    pub automated_feedback: Option<String>,
+    pub automated_feedback: Option<Link<Response>>,
 
     /// API URL of the iteration. Performing an HTTP `DELETE` on this URL will delete the iteration.
     ///
@@ -220,17 +232,17 @@ pub struct Links {
     /// URL of the exercise on the [Exercism website](https://exercism.org).
     ///
     /// For the user that submitted the iteration, this URL will point to their solution.
-    pub solution: String,
+    pub solution: Link<WebPage>,
 
     /// API URL of the iteration's submission's test run.
     ///
     /// Will be `None` if the iteration has no test run (if it is deleted, for example).
     #[serde(default)]
-    pub test_run: Option<String>,
+    pub test_run: Option<Link<tests::run::Response>>,
 
     /// API URL of the iteration's submission's files (with content).
     ///
     /// Will be `None` for deleted iterations.
     #[serde(default)]
-    pub files: Option<String>,
+    pub files: Option<Link<submission::files::Response>>,
 }
@@ -0,0 +1,272 @@
+//! Types used to compare the files submitted for two iterations of a solution.
+//!
+//! A [`Diff`] can be computed from the [files](crate::api::v2::submission::files::File) of two
+//! submissions using [`Diff::between`], or fetched directly for two [`Iteration`]s using
+//! [`Client::diff_iterations`](crate::api::v2::Client::diff_iterations).
+//!
+//! When [displayed](fmt::Display), a diff is written as a summary of changed files followed by a
+//! unified diff of each file. It can also be serialized to JSON.
+//!
+//! # Examples
+//!
+//! ```
+//! use mini_exercism::api::v2::iteration::diff::{Diff, FileStatus};
+//! use mini_exercism::api::v2::submission::files::File;
+//!
+//! fn file(filename: &str, content: &str) -> File {
+//!     File {
+//!         filename: filename.into(),
+//!         content: content.into(),
+//!         digest: File::compute_digest(content),
+//!     }
+//! }
+//!
+//! let old = [file("src/lib.rs", "fn answer() -> i32 {\n    41\n}\n")];
+//! let new = [
+//!     file("src/lib.rs", "fn answer() -> i32 {\n    42\n}\n"),
+//!     file("src/detail.rs", "pub struct Detail;\n"),
+//! ];
+//!
+//! let diff = Diff::between(&old, &new);
+//! assert_eq!(2, diff.files.len());
+//! assert_eq!(FileStatus::Added, diff.files[0].status);
+//! assert_eq!(FileStatus::Modified, diff.files[1].status);
+//! assert_eq!(2, diff.stats().insertions);
+//! assert_eq!(1, diff.stats().deletions);
+//! ```
+//!
+//! [`Iteration`]: crate::api::v2::iteration::Iteration
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
+use strum::{AsRefStr, Display, IntoStaticStr};
+
+use crate::api::v2::submission::files::File;
+
+/// Minimum [similarity ratio](TextDiff::ratio) between the content of a removed file and an added
+/// file for them to be considered a rename.
+pub const RENAME_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Number of unchanged lines shown around each change in unified diffs.
+pub const CONTEXT_LINES: usize = 3;
+
+/// Differences between the files submitted for two iterations.
+///
+/// Returned by [`Diff::between`]. Files that did not change are not included.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diff {
+    /// Changed files, sorted by [filename](FileDiff::filename).
+    pub files: Vec<FileDiff>,
+}
+
+impl Diff {
+    /// Computes the differences between two sets of submitted files.
+    ///
+    /// A file only present in `old` is considered renamed if a file only present in `new` has
+    /// similar content (see [`RENAME_SIMILARITY_THRESHOLD`]).
+    pub fn between(old: &[File], new: &[File]) -> Self {
+        let mut removed: Vec<_> = old
+            .iter()
+            .filter(|file| !new.iter().any(|other| other.filename == file.filename))
+            .collect();
+        let mut added: Vec<_> = new
+            .iter()
+            .filter(|file| !old.iter().any(|other| other.filename == file.filename))
+            .collect();
+
+        let mut files: Vec<_> = old
+            .iter()
+            .filter_map(|old_file| {
+                new.iter()
+                    .find(|new_file| new_file.filename == old_file.filename)
+                    .filter(|new_file| new_file.content != old_file.content)
+                    .map(|new_file| {
+                        FileDiff::new(FileStatus::Modified, Some(old_file), Some(new_file))
+                    })
+            })
+            .collect();
+
+        removed.retain(|old_file| {
+            let renamed_to = added
+                .iter()
+                .enumerate()
+                .map(|(i, new_file)| {
+                    let ratio = TextDiff::from_lines(&old_file.content, &new_file.content).ratio();
+                    (i, ratio)
+                })
+                .filter(|(_, ratio)| *ratio >= RENAME_SIMILARITY_THRESHOLD)
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(i, _)| added.remove(i));
+
+            match renamed_to {
+                Some(new_file) => {
+                    files.push(FileDiff::new(FileStatus::Renamed, Some(old_file), Some(new_file)));
+                    false
+                },
+                None => true,
+            }
+        });
+
+        files.extend(
+            removed
+                .into_iter()
+                .map(|old_file| FileDiff::new(FileStatus::Removed, Some(old_file), None)),
+        );
+        files.extend(
+            added
+                .into_iter()
+                .map(|new_file| FileDiff::new(FileStatus::Added, None, Some(new_file))),
+        );
+        files.sort_by(|a, b| a.filename().cmp(b.filename()));
+
+        Self { files }
+    }
+
+    /// Returns `true` if no file changed between the two iterations.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the total number of lines inserted and deleted across all files.
+    pub fn stats(&self) -> DiffStats {
+        self.files
+            .iter()
+            .fold(DiffStats::default(), |total, file| DiffStats {
+                insertions: total.insertions + file.stats.insertions,
+                deletions: total.deletions + file.stats.deletions,
+            })
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for file in &self.files {
+            let name = match (&file.old_filename, &file.new_filename) {
+                (Some(old), Some(new)) if old != new => format!("{old} => {new}"),
+                _ => file.filename().into(),
+            };
+            writeln!(f, "{} {name} ({})", file.status, file.stats)?;
+        }
+        writeln!(f, "{} file(s) changed ({})", self.files.len(), self.stats())?;
+
+        for file in self
+            .files
+            .iter()
+            .filter(|file| !file.unified_diff.is_empty())
+        {
+            write!(f, "\n{}", file.unified_diff)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Differences between two versions of a submitted file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDiff {
+    /// How the file changed.
+    pub status: FileStatus,
+
+    /// Name of the file in the old iteration.
+    ///
+    /// Will be `None` for [added](FileStatus::Added) files.
+    pub old_filename: Option<String>,
+
+    /// Name of the file in the new iteration.
+    ///
+    /// Will be `None` for [removed](FileStatus::Removed) files.
+    pub new_filename: Option<String>,
+
+    /// Number of lines inserted and deleted.
+    pub stats: DiffStats,
+
+    /// Line-based unified diff of the file's content, with `a/` and `b/` headers.
+    ///
+    /// Will be empty if the file's content did not change (for example, if it was
+    /// [renamed](FileStatus::Renamed) without modifications).
+    pub unified_diff: String,
+}
+
+impl FileDiff {
+    fn new(status: FileStatus, old: Option<&File>, new: Option<&File>) -> Self {
+        let old_filename = old.map(|file| file.filename.clone());
+        let new_filename = new.map(|file| file.filename.clone());
+        let old_content = old.map(|file| file.content.as_str()).unwrap_or_default();
+        let new_content = new.map(|file| file.content.as_str()).unwrap_or_default();
+
+        let text_diff = TextDiff::from_lines(old_content, new_content);
+        let stats = text_diff
+            .iter_all_changes()
+            .fold(DiffStats::default(), |stats, change| match change.tag() {
+                ChangeTag::Insert => DiffStats { insertions: stats.insertions + 1, ..stats },
+                ChangeTag::Delete => DiffStats { deletions: stats.deletions + 1, ..stats },
+                ChangeTag::Equal => stats,
+            });
+        let unified_diff = if old_content == new_content {
+            String::new()
+        } else {
+            let old_header = old_filename
+                .as_ref()
+                .map_or_else(|| "/dev/null".into(), |filename| format!("a/{filename}"));
+            let new_header = new_filename
+                .as_ref()
+                .map_or_else(|| "/dev/null".into(), |filename| format!("b/{filename}"));
+            text_diff
+                .unified_diff()
+                .context_radius(CONTEXT_LINES)
+                .header(&old_header, &new_header)
+                .to_string()
+        };
+
+        Self { status, old_filename, new_filename, stats, unified_diff }
+    }
+
+    /// Returns the name of the file in the new iteration, or in the old one if it was
+    /// [removed](FileStatus::Removed).
+    pub fn filename(&self) -> &str {
+        self.new_filename
+            .as_deref()
+            .or(self.old_filename.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// How a submitted file changed between two iterations.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FileStatus {
+    /// File is only present in the new iteration.
+    Added,
+
+    /// File is only present in the old iteration.
+    Removed,
+
+    /// File was renamed (its content may also have been modified).
+    Renamed,
+
+    /// File's content was modified.
+    Modified,
+}
+
+/// Number of lines inserted and deleted in a [`FileDiff`] or [`Diff`].
+///
+/// When [displayed](fmt::Display), written as `+insertions -deletions`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffStats {
+    /// Number of lines inserted.
+    pub insertions: usize,
+
+    /// Number of lines deleted.
+    pub deletions: usize,
+}
+
+impl fmt::Display for DiffStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "+{} -{}", self.insertions, self.deletions)
+    }
+}
@@ -0,0 +1,416 @@
+//! Offline mode for the [Exercism website](https://exercism.org) v2 API [`Client`].
+//!
+//! A [`Snapshot`] of a user's tracks, exercises, solutions and iteration files can be
+//! [synced](Snapshot::sync) from the website and [saved](Snapshot::save) locally. A [`Client`]
+//! configured in [offline mode](crate::api::v2::ClientBuilder::offline) then answers
+//! queries from the snapshot instead of performing requests, applying the same filters,
+//! paging and sort order as the website. Querying data that was never synced results in a
+//! [`NotSynced`](crate::Error::NotSynced) error, while operations that cannot be answered from
+//! a snapshot result in an [`OfflineUnsupported`](crate::Error::OfflineUnsupported) error.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::api::v2::offline::{Snapshot, SyncOptions};
+//! use mini_exercism::core::Credentials;
+//!
+//! async fn sync_then_go_offline(api_token: &str) -> anyhow::Result<api::v2::Client> {
+//!     let credentials = Credentials::from_api_token(api_token);
+//!     let online_client = api::v2::Client::builder()
+//!         .credentials(credentials)
+//!         .build()?;
+//!
+//!     let options = SyncOptions { include_files: true };
+//!     let snapshot = Snapshot::sync(&online_client, &options).await?;
+//!     snapshot.save("exercism-snapshot.json")?;
+//!
+//!     let snapshot = Snapshot::load("exercism-snapshot.json")?;
+//!     Ok(api::v2::Client::builder().offline(snapshot).build()?)
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use futures::{StreamExt, TryStreamExt, stream};
+use serde::{Deserialize, Serialize};
+
+use crate::api::id::{SolutionUuid, SubmissionUuid, TrackSlug};
+use crate::api::v2::solution::Solution;
+use crate::api::v2::solutions::SortOrder;
+use crate::api::v2::track::Track;
+use crate::api::v2::tracks::StatusFilter;
+use crate::api::v2::{Client, exercises, solution, solutions, submission, tests, tracks};
+use crate::{Error, Result};
+
+/// Number of solutions returned per page by the website when no page size is specified.
+///
+/// See [here](https://github.com/exercism/website/blob/main/app/commands/solution/search_user_solutions.rb).
+pub const DEFAULT_SOLUTIONS_PER_PAGE: i64 = 25;
+
+/// Number of solutions fetched per request when [syncing](Snapshot::sync) a snapshot.
+const SYNC_SOLUTIONS_PER_PAGE: i64 = 100;
+
+/// Maximum number of concurrent requests performed when [syncing](Snapshot::sync) a snapshot.
+const SYNC_MAX_CONCURRENCY: usize = 4;
+
+/// Local snapshot of a user's data on the [Exercism website](https://exercism.org).
+///
+/// Used to answer queries performed by a v2 API [`Client`] in
+/// [offline mode](crate::api::v2::ClientBuilder::offline).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// All language tracks, as returned for the user.
+    ///
+    /// Will be `None` if tracks were never synced.
+    #[serde(default)]
+    pub tracks: Option<Vec<Track>>,
+
+    /// Exercises (and the user's solutions to them) for each synced track, keyed by track name.
+    #[serde(default)]
+    pub exercises: BTreeMap<TrackSlug, exercises::Response>,
+
+    /// All of the user's solutions, from oldest to newest.
+    ///
+    /// Will be `None` if solutions were never synced.
+    #[serde(default)]
+    pub solutions: Option<Vec<Solution>>,
+
+    /// Details of each synced solution (including its iterations), keyed by solution UUID.
+    #[serde(default)]
+    pub solution_details: BTreeMap<SolutionUuid, solution::Response>,
+
+    /// Files submitted for each synced submission, keyed by submission UUID.
+    #[serde(default)]
+    pub submission_files: BTreeMap<SubmissionUuid, submission::files::Response>,
+}
+
+/// Options controlling what is fetched when [syncing](Snapshot::sync) a [`Snapshot`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// Whether to also fetch the files submitted for every iteration.
+    ///
+    /// This requires one request per iteration, so it can take a while for active users.
+    pub include_files: bool,
+}
+
+impl Snapshot {
+    /// Syncs a new snapshot of the user's data using the given (online) [`Client`].
+    ///
+    /// The following data is fetched:
+    ///
+    /// - All tracks
+    /// - Exercises and solutions of every track joined by the user
+    /// - All of the user's solutions, along with their iterations
+    /// - If [`include_files`](SyncOptions::include_files) is set, the files of every iteration
+    ///
+    /// The client must have been created with [`credentials`](crate::api::v2::ClientBuilder::credentials).
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(client), err))]
+    pub async fn sync(client: &Client, options: &SyncOptions) -> Result<Self> {
+        let tracks = client.get_tracks(None).await?.tracks;
+
+        let exercise_filters = exercises::Filters::builder()
+            .include_solutions(true)
+            .build();
+        let exercises = stream::iter(tracks.iter().filter(|track| track.is_joined))
+            .map(|track| {
+                let filters = exercise_filters.clone();
+                async move {
+                    let response = client.get_exercises(&track.name, Some(filters)).await?;
+                    Ok::<_, Error>((track.name.clone(), response))
+                }
+            })
+            .buffered(SYNC_MAX_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut solutions = Vec::new();
+        let mut page = 1;
+        loop {
+            let paging = solutions::Paging::for_page(page).and_per_page(SYNC_SOLUTIONS_PER_PAGE);
+            let response = client
+                .get_solutions(None, Some(paging), Some(SortOrder::OldestFirst))
+                .await?;
+            let done = response.results.is_empty() || page >= response.meta.total_pages;
+            solutions.extend(response.results);
+            if done {
+                break;
+            }
+            page += 1;
+        }
+
+        let solution_details: BTreeMap<_, _> = stream::iter(solutions.iter())
+            .map(|solution| async move {
+                let response = client.get_solution(&solution.uuid, true).await?;
+                Ok::<_, Error>((solution.uuid.clone(), response))
+            })
+            .buffered(SYNC_MAX_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let submission_files = if options.include_files {
+            let submissions = solution_details
+                .iter()
+                .flat_map(|(solution_uuid, details)| {
+                    details.iterations.iter().filter_map(move |iteration| {
+                        Some((solution_uuid, iteration.submission_uuid.as_ref()?))
+                    })
+                });
+            stream::iter(submissions)
+                .map(|(solution_uuid, submission_uuid)| async move {
+                    let response = client
+                        .get_submission_files(solution_uuid, submission_uuid)
+                        .await?;
+                    Ok::<_, Error>((submission_uuid.clone(), response))
+                })
+                .buffered(SYNC_MAX_CONCURRENCY)
+                .try_collect()
+                .await?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            tracks: Some(tracks),
+            exercises,
+            solutions: Some(solutions),
+            solution_details,
+            submission_files,
+        })
+    }
+
+    /// Loads a snapshot previously [saved](Self::save) to a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotIoError`]: Error while reading the file
+    /// - [`SnapshotFormatError`]: File does not contain a valid snapshot
+    ///
+    /// [`SnapshotIoError`]: crate::Error::SnapshotIoError
+    /// [`SnapshotFormatError`]: crate::Error::SnapshotFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|source| Error::SnapshotIoError { path: path.into(), source })?;
+        serde_json::from_str(&contents).map_err(Error::SnapshotFormatError)
+    }
+
+    /// Saves the snapshot to a JSON file, overwriting it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotIoError`]: Error while writing the file
+    /// - [`SnapshotFormatError`]: Snapshot could not be serialized (for example if it contains
+    ///   values unknown to this crate)
+    ///
+    /// [`SnapshotIoError`]: crate::Error::SnapshotIoError
+    /// [`SnapshotFormatError`]: crate::Error::SnapshotFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents = serde_json::to_string(self).map_err(Error::SnapshotFormatError)?;
+        fs::write(path, contents)
+            .map_err(|source| Error::SnapshotIoError { path: path.into(), source })
+    }
+
+    pub(crate) fn get_tracks(
+        &self,
+        filters: Option<tracks::Filters<'_>>,
+    ) -> Result<tracks::Response> {
+        let tracks = self.tracks.as_ref().ok_or_else(|| not_synced("tracks"))?;
+        let filters = filters.unwrap_or_default();
+
+        let tracks = tracks
+            .iter()
+            .filter(|track| matches_criteria(filters.criteria, [&track.name, &track.title]))
+            .filter(|track| filters.tags.iter().all(|tag| track.has_tag(tag.clone())))
+            .filter(|track| match filters.status {
+                None | Some(StatusFilter::All) => true,
+                Some(StatusFilter::Joined) => track.is_joined,
+                Some(StatusFilter::Unjoined) => !track.is_joined,
+            })
+            .cloned()
+            .collect();
+
+        Ok(tracks::Response { tracks })
+    }
+
+    pub(crate) fn get_exercises(
+        &self,
+        track: &str,
+        filters: Option<exercises::Filters<'_>>,
+    ) -> Result<exercises::Response> {
+        let response = self
+            .exercises
+            .get(track)
+            .ok_or_else(|| not_synced(format!("exercises of track {track}")))?;
+        let filters = filters.unwrap_or_default();
+
+        let exercises = response
+            .exercises
+            .iter()
+            .filter(|exercise| {
+                matches_criteria(filters.criteria, [&exercise.name, &exercise.title])
+            })
+            .cloned()
+            .collect();
+        let solutions =
+            if filters.include_solutions { response.solutions.clone() } else { Vec::new() };
+
+        Ok(exercises::Response { exercises, solutions })
+    }
+
+    pub(crate) fn get_solutions(
+        &self,
+        filters: Option<solutions::Filters<'_>>,
+        paging: Option<solutions::Paging>,
+        sort_order: Option<SortOrder>,
+    ) -> Result<solutions::Response> {
+        let solutions = self
+            .solutions
+            .as_ref()
+            .ok_or_else(|| not_synced("solutions"))?;
+        let filters = filters.unwrap_or_default();
+
+        let mut results: Vec<_> = solutions
+            .iter()
+            .filter(|solution| self.matches_solution_filters(solution, &filters))
+            .collect();
+
+        // Solutions are stored oldest-first, which matches the website's ordering by ID.
+        match sort_order.unwrap_or_default() {
+            SortOrder::OldestFirst => {},
+            SortOrder::NewestFirst => results.reverse(),
+            SortOrder::MostStarred => {
+                results.reverse();
+                results.sort_by_key(|solution| std::cmp::Reverse(solution.num_stars));
+            },
+        }
+
+        let paging = paging.unwrap_or(solutions::Paging::for_page(1));
+        let per_page = paging.per_page.unwrap_or(DEFAULT_SOLUTIONS_PER_PAGE).max(1);
+        let current_page = paging.page.max(1);
+        let total_count = results.len() as i64;
+        let total_pages = (total_count + per_page - 1) / per_page;
+
+        let results = results
+            .into_iter()
+            .skip(((current_page - 1) * per_page) as usize)
+            .take(per_page as usize)
+            .cloned()
+            .collect();
+
+        Ok(solutions::Response {
+            results,
+            meta: solutions::ResponseMeta { current_page, total_count, total_pages },
+        })
+    }
+
+    pub(crate) fn get_solution(
+        &self,
+        uuid: &str,
+        include_iterations: bool,
+    ) -> Result<solution::Response> {
+        let mut response = self
+            .solution_details
+            .get(uuid)
+            .cloned()
+            .ok_or_else(|| not_synced(format!("solution {uuid}")))?;
+        if !include_iterations {
+            response.iterations.clear();
+        }
+
+        Ok(response)
+    }
+
+    pub(crate) fn get_submission_files(
+        &self,
+        submission_uuid: &str,
+    ) -> Result<submission::files::Response> {
+        self.submission_files
+            .get(submission_uuid)
+            .cloned()
+            .ok_or_else(|| not_synced(format!("files of submission {submission_uuid}")))
+    }
+
+    fn matches_solution_filters(
+        &self,
+        solution: &Solution,
+        filters: &solutions::Filters<'_>,
+    ) -> bool {
+        matches_criteria(filters.criteria, [&solution.exercise.title, &solution.track.title])
+            && filters
+                .track
+                .is_none_or(|track| solution.track.name == track)
+            && filters
+                .status
+                .as_ref()
+                .is_none_or(|status| solution.status == *status)
+            && filters
+                .mentoring_status
+                .as_ref()
+                .is_none_or(|status| solution.mentoring_status == *status)
+            && filters
+                .is_out_of_date
+                .is_none_or(|out_of_date| solution.is_out_of_date == out_of_date)
+            && matches_any(
+                &filters.published_iteration_tests_statuses,
+                &self.published_iteration_tests_status(solution),
+            )
+            && matches_any(
+                &filters.published_iteration_head_tests_statuses,
+                &solution.published_iteration_head_tests_status,
+            )
+    }
+
+    fn published_iteration_tests_status(&self, solution: &Solution) -> tests::Status {
+        // The solutions list only includes the head tests status; use the published
+        // iteration's own status if its details were synced.
+        self.solution_details
+            .get(&solution.uuid)
+            .and_then(|details| {
+                details
+                    .iterations
+                    .iter()
+                    .rev()
+                    .find(|iteration| iteration.is_published)
+            })
+            .map_or(solution.published_iteration_head_tests_status.clone(), |iteration| {
+                iteration.tests_status.clone()
+            })
+    }
+}
+
+fn not_synced<S: Into<String>>(what: S) -> Error {
+    Error::NotSynced(what.into())
+}
+
+fn matches_criteria<const N: usize>(criteria: Option<&str>, values: [&str; N]) -> bool {
+    criteria.is_none_or(|criteria| {
+        let criteria = criteria.to_lowercase();
+        values
+            .iter()
+            .any(|value| value.to_lowercase().contains(&criteria))
+    })
+}
+
+fn matches_any(statuses: &[tests::Status], status: &tests::Status) -> bool {
+    statuses.is_empty() || statuses.contains(status)
+}
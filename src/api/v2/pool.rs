@@ -0,0 +1,210 @@
+//! Pool of v2 API [`Client`]s used to perform requests on behalf of multiple
+//! [Exercism](https://exercism.org) accounts.
+//!
+//! All clients in a [`ClientPool`] share the same HTTP client (see [`Client::with_credentials`]),
+//! so they use a single connection pool and retry policy. Operations can be
+//! [fanned out](ClientPool::fan_out) across all accounts with bounded concurrency.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::join_all;
+use tokio::sync::Semaphore;
+
+use crate::Result;
+use crate::api::v2::Client;
+use crate::core::Credentials;
+
+/// Default maximum number of concurrent requests performed by [`ClientPool::fan_out`].
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Pool of v2 API [`Client`]s, one per [Exercism](https://exercism.org) account.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mini_exercism::api::v2::pool::ClientPool;
+/// use mini_exercism::core::Credentials;
+///
+/// async fn print_num_solutions(accounts: &[(&str, &str)]) -> anyhow::Result<()> {
+///     let mut builder = ClientPool::builder();
+///     for (name, api_token) in accounts {
+///         builder.account(*name, Credentials::from_api_token(*api_token));
+///     }
+///     let pool = builder.max_concurrency(8).build()?;
+///
+///     let results = pool
+///         .fan_out(|client| async move {
+///             Ok(client
+///                 .get_solutions(None, None, None)
+///                 .await?
+///                 .meta
+///                 .total_count)
+///         })
+///         .await;
+///     for result in results {
+///         match result.result {
+///             Ok(count) => println!("{}: {count} solution(s)", result.account),
+///             Err(err) => println!("{}: error: {err}", result.account),
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientPool {
+    clients: Vec<(String, Client)>,
+    max_concurrency: usize,
+    limiter: Arc<Semaphore>,
+}
+
+impl ClientPool {
+    /// Returns a [`ClientPoolBuilder`] that can be used to create a pool.
+    #[cfg_attr(not(coverage), tracing::instrument(level = "trace"))]
+    pub fn builder() -> ClientPoolBuilder {
+        ClientPoolBuilder::default()
+    }
+
+    /// Returns the names of the accounts in the pool, in the order they were added.
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        self.clients.iter().map(|(account, _)| account.as_str())
+    }
+
+    /// Returns the [`Client`] performing requests for the given account, if it is in the pool.
+    pub fn client(&self, account: &str) -> Option<&Client> {
+        self.clients
+            .iter()
+            .find(|(name, _)| name == account)
+            .map(|(_, client)| client)
+    }
+
+    /// Returns the number of accounts in the pool.
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns `true` if the pool contains no accounts.
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Returns the maximum number of operations run concurrently by [`fan_out`](Self::fan_out).
+    ///
+    /// The limit is shared by all calls to [`fan_out`](Self::fan_out) made on this pool
+    /// (or on its clones), even if they run at the same time.
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Runs an operation for every account in the pool and returns the result for each account.
+    ///
+    /// The operation is called with the [`Client`] of each account; at most
+    /// [`max_concurrency`](Self::max_concurrency) operations run at the same time across the
+    /// whole pool.
+    /// An error for one account does not prevent the operation from running for the others.
+    ///
+    /// Results are returned in the same order as the [`accounts`](Self::accounts).
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, fields(accounts = self.len())))]
+    pub async fn fan_out<F, Fut, T>(&self, op: F) -> Vec<AccountResult<T>>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let op = &op;
+        join_all(self.clients.iter().map(|(account, client)| async move {
+            let _permit = self
+                .limiter
+                .acquire()
+                .await
+                .expect("pool limiter should never be closed");
+            AccountResult { account: account.clone(), result: op(client.clone()).await }
+        }))
+        .await
+    }
+}
+
+/// Result of an operation run for one account by [`ClientPool::fan_out`].
+#[derive(Debug)]
+pub struct AccountResult<T> {
+    /// Name of the account.
+    pub account: String,
+
+    /// Result of the operation for the account.
+    pub result: Result<T>,
+}
+
+/// Builder for the [`ClientPool`] type.
+///
+/// To create a builder instance, call [`ClientPool::builder`].
+#[derive(Debug)]
+pub struct ClientPoolBuilder {
+    client: Option<Client>,
+    accounts: Vec<(String, Credentials)>,
+    max_concurrency: usize,
+}
+
+impl ClientPoolBuilder {
+    /// Sets the [`Client`] used as a template for the clients in the pool.
+    ///
+    /// All clients in the pool will share this client's HTTP client, retry policy and
+    /// base URL. Any credentials it has are ignored.
+    ///
+    /// If not specified, a default client will be created.
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, level = "trace"))]
+    pub fn client(&mut self, client: Client) -> &mut Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Adds an account to the pool.
+    ///
+    /// If an account with the same name was already added, its credentials are replaced.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn account<N>(&mut self, name: N, credentials: Credentials) -> &mut Self
+    where
+        N: Into<String> + std::fmt::Debug,
+    {
+        let name = name.into();
+        match self.accounts.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = credentials,
+            None => self.accounts.push((name, credentials)),
+        }
+        self
+    }
+
+    /// Sets the maximum number of operations run concurrently by [`ClientPool::fan_out`].
+    ///
+    /// If not specified, defaults to [`DEFAULT_MAX_CONCURRENCY`]. A value of `0` is treated as `1`.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn max_concurrency(&mut self, value: usize) -> &mut Self {
+        self.max_concurrency = value.max(1);
+        self
+    }
+
+    /// Builds a new [`ClientPool`] using the parameters of this builder.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "trace"))]
+    pub fn build(&mut self) -> Result<ClientPool> {
+        let client = match self.client.clone() {
+            Some(client) => client,
+            None => Client::new()?,
+        };
+        let clients = self
+            .accounts
+            .iter()
+            .map(|(name, credentials)| (name.clone(), client.with_credentials(credentials.clone())))
+            .collect();
+
+        Ok(ClientPool {
+            clients,
+            max_concurrency: self.max_concurrency,
+            limiter: Arc::new(Semaphore::new(self.max_concurrency)),
+        })
+    }
+}
+
+impl Default for ClientPoolBuilder {
+    fn default() -> Self {
+        Self { client: None, accounts: Vec::new(), max_concurrency: DEFAULT_MAX_CONCURRENCY }
+    }
+}
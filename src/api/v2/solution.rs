@@ -1,13 +1,18 @@
 //! Types related to solutions returned by the [Exercism website](https://exercism.org) v2 API.
 
+pub mod update;
+
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantNames};
+use strum::{AsRefStr, Display, EnumString, VariantNames};
 
+use crate::api::id::{ExerciseSlug, SolutionUuid, TrackSlug};
 use crate::api::v2::iteration::Iteration;
 use crate::api::v2::tests;
 
 /// Response to a query for a solution on the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SolutionResponse"))]
 pub struct Response {
     /// Solution information.
     pub solution: Solution,
@@ -21,9 +26,10 @@ pub struct Response {
 
 /// A solution to an exercise submitted to the [Exercism website](https://exercism.org).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Solution {
     /// Solution unique ID.
-    pub uuid: String,
+    pub uuid: SolutionUuid,
 
     /// Private solution URL.
     ///
@@ -125,7 +131,6 @@ pub struct Solution {
 #[derive(
     Debug,
     Default,
-    Copy,
     Clone,
     PartialEq,
     Eq,
@@ -134,10 +139,11 @@ pub struct Solution {
     AsRefStr,
     Display,
     EnumString,
-    IntoStaticStr,
     VariantNames,
 )]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SolutionStatus"))]
+#[serde(rename_all = "snake_case", expecting = "invalid solution status: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Status {
     /// Exercise has been started, but no iteration has been submitted yet.
@@ -158,15 +164,17 @@ pub enum Status {
     ///
     /// Included so that if new solution statuses are introduced in the website API later,
     /// this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Status>")]
+    #[strum(default, transparent)]
+    Unknown(String),
 }
 
 /// Possible mentoring status of a solution on the [Exercism website](https://exercism.org).
 #[derive(
     Debug,
     Default,
-    Copy,
     Clone,
     PartialEq,
     Eq,
@@ -175,10 +183,10 @@ pub enum Status {
     AsRefStr,
     Display,
     EnumString,
-    IntoStaticStr,
     VariantNames,
 )]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", expecting = "invalid mentoring status: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum MentoringStatus {
     /// No mentoring has been required for this exercise.
@@ -199,18 +207,26 @@ pub enum MentoringStatus {
     ///
     /// Included so that if new mentoring statuses are introduced in the website API later,
     /// this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::api::detail::deserialize_unknown::<_, MentoringStatus>"
+    )]
+    #[strum(default, transparent)]
+    Unknown(String),
 }
 
 /// Exercise for which a solution was submitted on the [Exercism website](https://exercism.org).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SolutionExercise"))]
 pub struct Exercise {
     /// Name of the exercise.
     ///
     /// This is an internal name, like `forth`. Also called `slug`.
     #[serde(rename = "slug")]
-    pub name: String,
+    pub name: ExerciseSlug,
 
     /// Exercise title.
     ///
@@ -223,12 +239,14 @@ pub struct Exercise {
 
 /// Language track of a solution submitted on the [Exercism website](https://exercism.org).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SolutionTrack"))]
 pub struct Track {
     /// Name of the language track.
     ///
     /// This is an internal name, like `common-lisp`. Also called `slug`.
     #[serde(rename = "slug")]
-    pub name: String,
+    pub name: TrackSlug,
 
     /// Language track title.
     ///
@@ -0,0 +1,112 @@
+//! Types used to update [out-of-date](Solution::is_out_of_date) solutions to the latest version
+//! of their exercise.
+//!
+//! When an exercise changes upstream (for example, when tests are added), existing solutions are
+//! marked as out-of-date. The changes made to the exercise's files can be fetched using
+//! [`Client::get_exercise_diff`], and a solution can be updated using [`Client::update_solution`].
+//! To update all out-of-date solutions at once, use [`Client::update_out_of_date_solutions`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::core::Credentials;
+//!
+//! async fn preview_rust_updates(api_token: &str) -> anyhow::Result<()> {
+//!     let credentials = Credentials::from_api_token(api_token);
+//!     let client = api::v2::Client::builder()
+//!         .credentials(credentials)
+//!         .build()?;
+//!
+//!     for update in client.update_out_of_date_solutions(Some("rust"), true).await? {
+//!         println!("{}:", update.solution.exercise.name);
+//!         for file in &update.diff.files {
+//!             println!("{}", file.diff);
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Client::get_exercise_diff`]: crate::api::v2::Client::get_exercise_diff
+//! [`Client::update_solution`]: crate::api::v2::Client::update_solution
+//! [`Client::update_out_of_date_solutions`]: crate::api::v2::Client::update_out_of_date_solutions
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::v2::solution::Solution;
+
+/// Response to a query for the changes made to a solution's exercise on the
+/// [Exercism website](https://exercism.org) v2 API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiffResponse {
+    /// Changes made to the exercise since the solution was started.
+    pub diff: Diff,
+}
+
+/// Changes made to an exercise between the version used by a solution and its latest version.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Diff {
+    /// Exercise files that changed (usually test files).
+    pub files: Vec<ChangedFile>,
+
+    /// Links pertaining to the changes.
+    #[serde(default)]
+    pub links: DiffLinks,
+}
+
+/// Exercise file that changed between two versions of an exercise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChangedFile {
+    /// Path of the file, relative to the exercise directory.
+    pub relative_path: String,
+
+    /// Unified diff of the file's content.
+    pub diff: String,
+}
+
+/// Links pertaining to the changes made to an exercise.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiffLinks {
+    /// URL of the API endpoint used to update the solution to the latest version of the exercise.
+    #[serde(default)]
+    pub update: Option<String>,
+}
+
+/// Result of updating an out-of-date solution, returned by
+/// [`Client::update_out_of_date_solutions`](crate::api::v2::Client::update_out_of_date_solutions).
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Update {
+    /// Solution information.
+    ///
+    /// If the solution was [updated](Self::updated), contains the updated solution; otherwise,
+    /// contains the solution as it was before the update.
+    pub solution: Solution,
+
+    /// Changes made to the exercise that were (or would be) applied to the solution.
+    pub diff: Diff,
+
+    /// Whether the solution was actually updated.
+    ///
+    /// Will be `false` in dry-run mode, or if an [error](Self::error) occurred.
+    pub updated: bool,
+
+    /// Error that occurred while fetching the changes or updating the solution, if any.
+    ///
+    /// If the changes could not be fetched, [`diff`](Self::diff) will be empty.
+    #[serde(skip)]
+    pub error: Option<crate::Error>,
+}
+
+impl Update {
+    pub(crate) fn failed(solution: Solution, diff: Diff, error: crate::Error) -> Self {
+        tracing::warn!(solution_uuid = %solution.uuid, %error, "failed to update solution");
+        Self { solution, diff, updated: false, error: Some(error) }
+    }
+}
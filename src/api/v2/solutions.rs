@@ -1,6 +1,7 @@
 //! Types related to list of solutions returned by the [Exercism website](https://exercism.org) v2 API.
 
 pub(crate) mod detail;
+pub mod query;
 
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
@@ -150,6 +151,7 @@ impl Paging {
     IntoStaticStr,
     VariantNames,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum SortOrder {
@@ -169,6 +171,8 @@ pub enum SortOrder {
 /// Response to a query for solutions on the [Exercism website](https://exercism.org) v2 API.
 /// Responses are paginated, so this only returns one page of results.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SolutionsResponse"))]
 pub struct Response {
     /// List of solutions in the current page.
     pub results: Vec<Solution>,
@@ -180,6 +184,7 @@ pub struct Response {
 /// Metadata attached to a response to a query for solutions on the [Exercism website](https://exercism.org) v2 API.
 /// Contains paging information.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ResponseMeta {
     /// Current page number (1-based).
     pub current_page: i64,
@@ -0,0 +1,692 @@
+//! Client-side queries over solutions returned by the [Exercism website](https://exercism.org) v2 API.
+//!
+//! The website only supports a few [`Filters`] when querying for solutions. A [`Query`] can express
+//! richer conditions using [`Predicate`]s: when it is [fetched](Query::fetch), the parts of the query
+//! that the website understands are pushed down into [`Filters`] and the rest is evaluated locally
+//! over the paginated results.
+//!
+//! Queries can also be [parsed](std::str::FromStr) from a small string syntax, which is useful for
+//! command-line tools. A query is a list of whitespace-separated terms that must all match:
+//!
+//! | Term                      | Matches solutions...                                                |
+//! |---------------------------|---------------------------------------------------------------------|
+//! | `word` or `"some words"`  | whose exercise or track title contains the text (case-insensitive)  |
+//! | `track:rust,go`           | in one of the given tracks                                          |
+//! | `exercise:forth`          | for one of the given exercises                                      |
+//! | `status:published`        | with one of the given [statuses](Status)                            |
+//! | `mentoring:in_progress`   | with one of the given [mentoring statuses](MentoringStatus)         |
+//! | `head_tests:passed`       | with one of the given published iteration head [tests statuses](tests::Status) |
+//! | `out_of_date:true`        | that are out-of-date (`true`) or up-to-date (`false`)               |
+//! | `notifications:true`      | that have unread notifications (`true`) or not (`false`)            |
+//! | `iterations>3`            | with more than 3 iterations (also `stars`, `views`, `comments`, `loc`) |
+//! | `completed:2025`          | completed in 2025 (also `published`, `updated`, `iterated`)         |
+//!
+//! Counts and dates support the `:` (or `=`), `!=`, `<`, `<=`, `>` and `>=` operators. Dates
+//! are compared using the precision of the given value, so `completed:2025-03` matches solutions
+//! completed in March 2025 and `completed<2025` matches solutions completed before 2025.
+//!
+//! Prefixing a term with `-` negates it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::api::v2::solution::Solution;
+//! use mini_exercism::api::v2::solutions::query::Query;
+//!
+//! async fn get_report_solutions(client: &api::v2::Client) -> anyhow::Result<Vec<Solution>> {
+//!     let query: Query =
+//!         "status:published iterations>3 stars>=1 completed:2025 track:rust,go".parse()?;
+//!
+//!     Ok(query.fetch(client, None).await?)
+//! }
+//! ```
+
+use std::str::FromStr;
+use std::{fmt, ops};
+
+use crate::Result;
+use crate::api::id::{ExerciseSlug, TrackSlug};
+use crate::api::v2::solution::{MentoringStatus, Solution, Status};
+use crate::api::v2::solutions::{Filters, Paging, SortOrder};
+use crate::api::v2::{Client, tests};
+
+/// Condition that a [`Solution`] must satisfy to be returned by a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Predicate {
+    /// Solution's exercise title or track title contains the text (case-insensitive).
+    Criteria(String),
+
+    /// Solution is in one of the given tracks (by name, e.g. slug).
+    Track(Vec<TrackSlug>),
+
+    /// Solution is for one of the given exercises (by name, e.g. slug).
+    Exercise(Vec<ExerciseSlug>),
+
+    /// Solution has one of the given [statuses](Status).
+    Status(Vec<Status>),
+
+    /// Solution has one of the given [mentoring statuses](MentoringStatus).
+    MentoringStatus(Vec<MentoringStatus>),
+
+    /// Solution's [published iteration head tests status](Solution::published_iteration_head_tests_status)
+    /// is one of the given statuses.
+    HeadTestsStatus(Vec<tests::Status>),
+
+    /// Solution is [out-of-date](Solution::is_out_of_date) (`true`) or up-to-date (`false`).
+    OutOfDate(bool),
+
+    /// Solution [has notifications](Solution::has_notifications) (`true`) or not (`false`).
+    HasNotifications(bool),
+
+    /// Comparison on one of the solution's counts.
+    Count(CountField, Comparison<i64>),
+
+    /// Comparison on one of the solution's dates.
+    ///
+    /// The date is compared using the precision of the comparison's value; for example,
+    /// comparing against `2025` only considers the year. Solutions without the date never match.
+    Date(DateField, Comparison<String>),
+
+    /// Solution does not match the inner predicate.
+    Not(Box<Predicate>),
+
+    /// Solution matches all of the inner predicates.
+    All(Vec<Predicate>),
+
+    /// Solution matches at least one of the inner predicates.
+    Any(Vec<Predicate>),
+}
+
+impl Predicate {
+    /// Returns `true` if the given solution satisfies this predicate.
+    pub fn matches(&self, solution: &Solution) -> bool {
+        match self {
+            Self::Criteria(criteria) => {
+                let criteria = criteria.to_lowercase();
+                [&solution.exercise.title, &solution.track.title]
+                    .iter()
+                    .any(|title| title.to_lowercase().contains(&criteria))
+            },
+            Self::Track(tracks) => tracks.contains(&solution.track.name),
+            Self::Exercise(exercises) => exercises.contains(&solution.exercise.name),
+            Self::Status(statuses) => statuses.contains(&solution.status),
+            Self::MentoringStatus(statuses) => statuses.contains(&solution.mentoring_status),
+            Self::HeadTestsStatus(statuses) => {
+                statuses.contains(&solution.published_iteration_head_tests_status)
+            },
+            Self::OutOfDate(out_of_date) => solution.is_out_of_date == *out_of_date,
+            Self::HasNotifications(has_notifications) => {
+                solution.has_notifications == *has_notifications
+            },
+            Self::Count(field, comparison) => field
+                .value(solution)
+                .is_some_and(|value| comparison.matches(&value)),
+            Self::Date(field, comparison) => field.value(solution).is_some_and(|value| {
+                let precision = comparison.value.len().min(value.len());
+                comparison.matches(&value[..precision].to_string())
+            }),
+            Self::Not(predicate) => !predicate.matches(solution),
+            Self::All(predicates) => predicates.iter().all(|p| p.matches(solution)),
+            Self::Any(predicates) => predicates.iter().any(|p| p.matches(solution)),
+        }
+    }
+
+    /// Returns a predicate matching solutions that match both this predicate and `other`.
+    pub fn and(self, other: Predicate) -> Self {
+        match self {
+            Self::All(mut predicates) => {
+                predicates.push(other);
+                Self::All(predicates)
+            },
+            predicate => Self::All(vec![predicate, other]),
+        }
+    }
+
+    /// Returns a predicate matching solutions that match either this predicate or `other`.
+    pub fn or(self, other: Predicate) -> Self {
+        match self {
+            Self::Any(mut predicates) => {
+                predicates.push(other);
+                Self::Any(predicates)
+            },
+            predicate => Self::Any(vec![predicate, other]),
+        }
+    }
+}
+
+impl ops::Not for Predicate {
+    type Output = Self;
+
+    /// Returns a predicate matching solutions that do not match this predicate.
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(predicate) => *predicate,
+            predicate => Self::Not(Box::new(predicate)),
+        }
+    }
+}
+
+/// Count of a [`Solution`] that can be compared in a [`Predicate`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CountField {
+    /// [`Solution::num_iterations`].
+    Iterations,
+
+    /// [`Solution::num_stars`].
+    Stars,
+
+    /// [`Solution::num_views`].
+    Views,
+
+    /// [`Solution::num_comments`].
+    Comments,
+
+    /// [`Solution::num_loc`].
+    Loc,
+}
+
+impl CountField {
+    fn value(self, solution: &Solution) -> Option<i64> {
+        match self {
+            Self::Iterations => Some(solution.num_iterations),
+            Self::Stars => Some(solution.num_stars),
+            Self::Views => Some(solution.num_views),
+            Self::Comments => Some(solution.num_comments),
+            Self::Loc => solution.num_loc,
+        }
+        .map(i64::from)
+    }
+}
+
+/// Date of a [`Solution`] that can be compared in a [`Predicate`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateField {
+    /// [`Solution::completed_at`].
+    Completed,
+
+    /// [`Solution::published_at`].
+    Published,
+
+    /// [`Solution::updated_at`].
+    Updated,
+
+    /// [`Solution::last_iterated_at`].
+    LastIterated,
+}
+
+impl DateField {
+    fn value(self, solution: &Solution) -> Option<&str> {
+        match self {
+            Self::Completed => solution.completed_at.as_deref(),
+            Self::Published => solution.published_at.as_deref(),
+            Self::Updated => Some(solution.updated_at.as_str()),
+            Self::LastIterated => solution.last_iterated_at.as_deref(),
+        }
+    }
+}
+
+/// Comparison operator used in a [`Comparison`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    /// Equal to (`:` or `=`).
+    Eq,
+
+    /// Not equal to (`!=`).
+    Ne,
+
+    /// Less than (`<`).
+    Lt,
+
+    /// Less than or equal to (`<=`).
+    Le,
+
+    /// Greater than (`>`).
+    Gt,
+
+    /// Greater than or equal to (`>=`).
+    Ge,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Eq => ":",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        })
+    }
+}
+
+/// Comparison of a value against a reference value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison<T> {
+    /// Operator used for the comparison.
+    pub operator: Operator,
+
+    /// Reference value; appears on the right-hand side of the comparison.
+    pub value: T,
+}
+
+impl<T: Ord> Comparison<T> {
+    /// Creates a new comparison.
+    pub fn new(operator: Operator, value: T) -> Self {
+        Self { operator, value }
+    }
+
+    /// Returns `true` if `value` satisfies the comparison.
+    pub fn matches(&self, value: &T) -> bool {
+        match self.operator {
+            Operator::Eq => *value == self.value,
+            Operator::Ne => *value != self.value,
+            Operator::Lt => *value < self.value,
+            Operator::Le => *value <= self.value,
+            Operator::Gt => *value > self.value,
+            Operator::Ge => *value >= self.value,
+        }
+    }
+}
+
+/// Query over the user's solutions on the [Exercism website](https://exercism.org).
+///
+/// A query matches solutions that satisfy all of its [`Predicate`]s.
+///
+/// # Examples
+///
+/// ```
+/// use mini_exercism::api::v2::solution::Status;
+/// use mini_exercism::api::v2::solutions::query::{CountField, DateField, Operator, Query};
+///
+/// let query = Query::builder()
+///     .status(Status::Published)
+///     .count(CountField::Iterations, Operator::Gt, 3)
+///     .count(CountField::Stars, Operator::Ge, 1)
+///     .date(DateField::Completed, Operator::Eq, "2025")
+///     .tracks(["rust", "go"])
+///     .build();
+///
+/// let parsed = "status:published iterations>3 stars>=1 completed:2025 track:rust,go".parse();
+/// assert_eq!(Ok(query.clone()), parsed);
+///
+/// // Only the status can be handled by the website.
+/// let filters = query.filters();
+/// assert_eq!(Some(Status::Published), filters.status);
+/// assert_eq!(None, filters.track);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    predicates: Vec<Predicate>,
+}
+
+impl Query {
+    /// Returns a builder for the [`Query`] type.
+    #[cfg_attr(not(coverage), tracing::instrument(level = "trace"))]
+    pub fn builder() -> QueryBuilder {
+        QueryBuilder::default()
+    }
+
+    /// Returns the query's predicates.
+    pub fn predicates(&self) -> &[Predicate] {
+        &self.predicates
+    }
+
+    /// Returns `true` if the given solution satisfies all of the query's predicates.
+    pub fn matches(&self, solution: &Solution) -> bool {
+        self.predicates.iter().all(|p| p.matches(solution))
+    }
+
+    /// Returns the [`Filters`] that can be sent to the website for this query.
+    ///
+    /// Solutions returned by the website for these filters must still be checked against
+    /// the query's other predicates (see [`local_predicates`](Self::local_predicates)).
+    pub fn filters(&self) -> Filters<'_> {
+        self.split().0
+    }
+
+    /// Returns the query's predicates that cannot be pushed down into [`filters`](Self::filters)
+    /// and must be evaluated locally.
+    pub fn local_predicates(&self) -> Vec<&Predicate> {
+        self.split().1
+    }
+
+    /// Fetches all solutions matching this query using the given [`Client`].
+    ///
+    /// All pages of solutions matching the query's [`filters`](Self::filters) are fetched, then
+    /// the query's [`local_predicates`](Self::local_predicates) are evaluated over them.
+    ///
+    /// # Errors
+    ///
+    /// - [`ApiError`]: Error while fetching solutions information from API
+    /// - [`ApiResponseFormatError`]: Response body could not be deserialized
+    ///
+    /// [`ApiError`]: crate::Error::ApiError
+    /// [`ApiResponseFormatError`]: crate::Error::ApiResponseFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip(client), err))]
+    pub async fn fetch(
+        &self,
+        client: &Client,
+        sort_order: Option<SortOrder>,
+    ) -> Result<Vec<Solution>> {
+        let (filters, local_predicates) = self.split();
+
+        let mut solutions = Vec::new();
+        let mut page = 1;
+        loop {
+            let response = client
+                .get_solutions(Some(filters.clone()), Some(Paging::for_page(page)), sort_order)
+                .await?;
+            let done = response.results.is_empty() || page >= response.meta.total_pages;
+            solutions.extend(
+                response
+                    .results
+                    .into_iter()
+                    .filter(|solution| local_predicates.iter().all(|p| p.matches(solution))),
+            );
+            if done {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(solutions)
+    }
+
+    fn split(&self) -> (Filters<'_>, Vec<&Predicate>) {
+        let mut filters = Filters::default();
+        let mut local_predicates = Vec::new();
+
+        for predicate in &self.predicates {
+            let pushed_down = match predicate {
+                Predicate::Criteria(criteria) if filters.criteria.is_none() => {
+                    filters.criteria = Some(criteria);
+                    true
+                },
+                Predicate::Track(tracks) if tracks.len() == 1 && filters.track.is_none() => {
+                    filters.track = Some(tracks[0].as_str());
+                    true
+                },
+                Predicate::Status(statuses) if statuses.len() == 1 && filters.status.is_none() => {
+                    filters.status = Some(statuses[0].clone());
+                    true
+                },
+                Predicate::MentoringStatus(statuses)
+                    if statuses.len() == 1 && filters.mentoring_status.is_none() =>
+                {
+                    filters.mentoring_status = Some(statuses[0].clone());
+                    true
+                },
+                Predicate::HeadTestsStatus(statuses)
+                    if !statuses.is_empty()
+                        && filters.published_iteration_head_tests_statuses.is_empty() =>
+                {
+                    filters.published_iteration_head_tests_statuses = statuses.clone();
+                    true
+                },
+                Predicate::OutOfDate(out_of_date) if filters.is_out_of_date.is_none() => {
+                    filters.is_out_of_date = Some(*out_of_date);
+                    true
+                },
+                _ => false,
+            };
+            if !pushed_down {
+                local_predicates.push(predicate);
+            }
+        }
+
+        (filters, local_predicates)
+    }
+}
+
+impl FromStr for Query {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let predicates = tokenize(s)?
+            .into_iter()
+            .map(|term| parse_term(&term))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Self { predicates })
+    }
+}
+
+/// Builder for the [`Query`] type.
+///
+/// To create a builder instance, call [`Query::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    predicates: Vec<Predicate>,
+}
+
+impl QueryBuilder {
+    /// Adds a [`Predicate`] to the query.
+    #[cfg_attr(not(coverage), tracing::instrument(level = "trace"))]
+    pub fn predicate(&mut self, predicate: Predicate) -> &mut Self {
+        self.predicates.push(predicate);
+        self
+    }
+
+    /// Adds a [`Criteria`](Predicate::Criteria) predicate to the query.
+    pub fn criteria<S: Into<String>>(&mut self, criteria: S) -> &mut Self {
+        self.predicate(Predicate::Criteria(criteria.into()))
+    }
+
+    /// Adds a predicate to the query matching solutions in the given track.
+    pub fn track<S: Into<TrackSlug>>(&mut self, track: S) -> &mut Self {
+        self.predicate(Predicate::Track(vec![track.into()]))
+    }
+
+    /// Adds a predicate to the query matching solutions in any of the given tracks.
+    pub fn tracks<I, S>(&mut self, tracks: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<TrackSlug>,
+    {
+        self.predicate(Predicate::Track(tracks.into_iter().map(Into::into).collect()))
+    }
+
+    /// Adds a predicate to the query matching solutions for the given exercise.
+    pub fn exercise<S: Into<ExerciseSlug>>(&mut self, exercise: S) -> &mut Self {
+        self.predicate(Predicate::Exercise(vec![exercise.into()]))
+    }
+
+    /// Adds a predicate to the query matching solutions with the given [status](Status).
+    pub fn status(&mut self, status: Status) -> &mut Self {
+        self.predicate(Predicate::Status(vec![status]))
+    }
+
+    /// Adds a predicate to the query matching solutions with the given [mentoring status](MentoringStatus).
+    pub fn mentoring_status(&mut self, mentoring_status: MentoringStatus) -> &mut Self {
+        self.predicate(Predicate::MentoringStatus(vec![mentoring_status]))
+    }
+
+    /// Adds an [`OutOfDate`](Predicate::OutOfDate) predicate to the query.
+    pub fn out_of_date(&mut self, out_of_date: bool) -> &mut Self {
+        self.predicate(Predicate::OutOfDate(out_of_date))
+    }
+
+    /// Adds a predicate to the query comparing one of the solution's counts.
+    pub fn count(&mut self, field: CountField, operator: Operator, value: i64) -> &mut Self {
+        self.predicate(Predicate::Count(field, Comparison::new(operator, value)))
+    }
+
+    /// Adds a predicate to the query comparing one of the solution's dates.
+    ///
+    /// See [`Predicate::Date`] for details on how dates are compared.
+    pub fn date<S: Into<String>>(
+        &mut self,
+        field: DateField,
+        operator: Operator,
+        value: S,
+    ) -> &mut Self {
+        self.predicate(Predicate::Date(field, Comparison::new(operator, value.into())))
+    }
+
+    /// Builds a new [`Query`].
+    #[cfg_attr(not(coverage), tracing::instrument(ret, level = "trace"))]
+    pub fn build(&self) -> Query {
+        Query { predicates: self.predicates.clone() }
+    }
+}
+
+/// Error returned when parsing a [`Query`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// A term used an unknown key.
+    #[error("unknown query key: {0}")]
+    UnknownKey(String),
+
+    /// A term used an operator that is not supported for its key.
+    #[error("operator {operator} is not supported for query key {key}")]
+    UnsupportedOperator {
+        /// Key of the term.
+        key: String,
+
+        /// Unsupported operator.
+        operator: Operator,
+    },
+
+    /// A term's value could not be parsed.
+    #[error("invalid value for query key {key}: {value}")]
+    InvalidValue {
+        /// Key of the term.
+        key: String,
+
+        /// Invalid value.
+        value: String,
+    },
+
+    /// A quoted string was not terminated.
+    #[error("unterminated quote in query")]
+    UnterminatedQuote,
+}
+
+fn tokenize(s: &str) -> std::result::Result<Vec<String>, ParseError> {
+    let mut terms = Vec::new();
+    let mut term = String::new();
+    let mut in_quotes = false;
+
+    for c in s.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            },
+            c => term.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ParseError::UnterminatedQuote);
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+
+    Ok(terms)
+}
+
+fn parse_term(term: &str) -> std::result::Result<Predicate, ParseError> {
+    if let Some(term) = term.strip_prefix('-').filter(|t| !t.is_empty()) {
+        return parse_term(term).map(|predicate| !predicate);
+    }
+
+    let Some((key, operator, value)) = split_term(term) else {
+        return Ok(Predicate::Criteria(term.into()));
+    };
+
+    let unsupported = || ParseError::UnsupportedOperator { key: key.into(), operator };
+    let invalid = || ParseError::InvalidValue { key: key.into(), value: value.into() };
+    let list = || value.split(',').filter(|v| !v.is_empty());
+    let parse_list = || -> std::result::Result<Vec<&str>, ParseError> {
+        let values: Vec<&str> = list().collect();
+        if values.is_empty() { Err(invalid()) } else { Ok(values) }
+    };
+
+    let count_field = match key {
+        "iterations" => Some(CountField::Iterations),
+        "stars" => Some(CountField::Stars),
+        "views" => Some(CountField::Views),
+        "comments" => Some(CountField::Comments),
+        "loc" => Some(CountField::Loc),
+        _ => None,
+    };
+    if let Some(field) = count_field {
+        let value = value.parse().map_err(|_| invalid())?;
+        return Ok(Predicate::Count(field, Comparison::new(operator, value)));
+    }
+
+    let date_field = match key {
+        "completed" => Some(DateField::Completed),
+        "published" => Some(DateField::Published),
+        "updated" => Some(DateField::Updated),
+        "iterated" => Some(DateField::LastIterated),
+        _ => None,
+    };
+    if let Some(field) = date_field {
+        if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            return Err(invalid());
+        }
+        return Ok(Predicate::Date(field, Comparison::new(operator, value.into())));
+    }
+
+    if operator != Operator::Eq {
+        return Err(unsupported());
+    }
+    match key {
+        "criteria" => Ok(Predicate::Criteria(value.into())),
+        "track" => parse_list().map(|tracks| Predicate::Track(into_all(tracks))),
+        "exercise" => parse_list().map(|exercises| Predicate::Exercise(into_all(exercises))),
+        "status" => parse_enum_list(list(), invalid).map(Predicate::Status),
+        "mentoring" => parse_enum_list(list(), invalid).map(Predicate::MentoringStatus),
+        "head_tests" => parse_enum_list(list(), invalid).map(Predicate::HeadTestsStatus),
+        "out_of_date" => value
+            .parse()
+            .map(Predicate::OutOfDate)
+            .map_err(|_| invalid()),
+        "notifications" => value
+            .parse()
+            .map(Predicate::HasNotifications)
+            .map_err(|_| invalid()),
+        _ => Err(ParseError::UnknownKey(key.into())),
+    }
+}
+
+fn into_all<T>(values: Vec<&str>) -> Vec<T>
+where
+    T: for<'a> From<&'a str>,
+{
+    values.into_iter().map(Into::into).collect()
+}
+
+fn split_term(term: &str) -> Option<(&str, Operator, &str)> {
+    let index = term.find([':', '=', '!', '<', '>'])?;
+    let (key, rest) = term.split_at(index);
+    let (operator, len) = match rest.as_bytes() {
+        [b'!', b'=', ..] => (Operator::Ne, 2),
+        [b'<', b'=', ..] => (Operator::Le, 2),
+        [b'>', b'=', ..] => (Operator::Ge, 2),
+        [b'<', ..] => (Operator::Lt, 1),
+        [b'>', ..] => (Operator::Gt, 1),
+        [b':' | b'=', ..] => (Operator::Eq, 1),
+        _ => return None,
+    };
+
+    Some((key, operator, &rest[len..]))
+}
+
+fn parse_enum_list<'a, T, I, E>(values: I, invalid: E) -> std::result::Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    I: Iterator<Item = &'a str>,
+    E: Fn() -> ParseError,
+{
+    let values = values
+        .map(|value| value.parse().map_err(|_| invalid()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    if values.is_empty() { Err(invalid()) } else { Ok(values) }
+}
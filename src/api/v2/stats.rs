@@ -0,0 +1,335 @@
+//! Progress statistics computed from data returned by the [Exercism website](https://exercism.org) v2 API.
+//!
+//! A [`Report`] aggregates the data returned by [`get_tracks`], [`get_exercises`] (with
+//! [`include_solutions`]) and [`get_solutions`]. Each aggregate can also be computed on its own
+//! using the functions of this module. All report types can be serialized.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::api::v2::exercises;
+//! use mini_exercism::api::v2::stats::Report;
+//! use mini_exercism::core::Credentials;
+//!
+//! async fn print_report(api_token: &str) -> anyhow::Result<()> {
+//!     let credentials = Credentials::from_api_token(api_token);
+//!     let client = api::v2::Client::builder()
+//!         .credentials(credentials)
+//!         .build()?;
+//!
+//!     let tracks = client.get_tracks(None).await?.tracks;
+//!     let filters = exercises::Filters::builder().include_solutions(true).build();
+//!     let exercises = vec![client.get_exercises("rust", Some(filters)).await?];
+//!     let solutions = client.get_solutions(None, None, None).await?.results;
+//!
+//!     let report = Report::compute(&tracks, &exercises, &solutions);
+//!     println!("{}", serde_json::to_string_pretty(&report)?);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`get_tracks`]: crate::api::v2::Client::get_tracks
+//! [`get_exercises`]: crate::api::v2::Client::get_exercises
+//! [`include_solutions`]: crate::api::v2::exercises::Filters::include_solutions
+//! [`get_solutions`]: crate::api::v2::Client::get_solutions
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, IntoStaticStr};
+
+use crate::api::id::TrackSlug;
+use crate::api::v2::exercise::Difficulty;
+use crate::api::v2::exercises;
+use crate::api::v2::solution::{Solution, Status};
+use crate::api::v2::tests;
+use crate::api::v2::track::Track;
+
+/// Progress report computed from a user's tracks, exercises and solutions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// Completion of each joined track (see [`track_completion`]).
+    pub tracks: Vec<TrackCompletion>,
+
+    /// Statistics about the user's solutions (see [`SolutionStats::compute`]).
+    pub solutions: SolutionStats,
+
+    /// Exercises grouped by difficulty (see [`exercises_by_difficulty`]).
+    pub difficulties: Vec<DifficultyStats>,
+
+    /// Number of solutions completed each week (see [`completions`]).
+    pub weekly_completions: Vec<PeriodCount>,
+
+    /// Number of solutions completed each month (see [`completions`]).
+    pub monthly_completions: Vec<PeriodCount>,
+}
+
+impl Report {
+    /// Computes a report from data returned by the API.
+    ///
+    /// - `tracks`: returned by [`get_tracks`](crate::api::v2::Client::get_tracks)
+    /// - `exercises`: returned by [`get_exercises`](crate::api::v2::Client::get_exercises)
+    ///   (one response per track, with [`include_solutions`](exercises::Filters::include_solutions))
+    /// - `solutions`: returned by [`get_solutions`](crate::api::v2::Client::get_solutions)
+    ///   (possibly accumulated over multiple pages)
+    pub fn compute(
+        tracks: &[Track],
+        exercises: &[exercises::Response],
+        solutions: &[Solution],
+    ) -> Self {
+        Self {
+            tracks: track_completion(tracks),
+            solutions: SolutionStats::compute(solutions),
+            difficulties: exercises_by_difficulty(exercises),
+            weekly_completions: completions(solutions, Period::Week),
+            monthly_completions: completions(solutions, Period::Month),
+        }
+    }
+}
+
+/// Completion of a language track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackCompletion {
+    /// Name of the track, like `rust`.
+    pub name: TrackSlug,
+
+    /// Title of the track, like `Rust`.
+    pub title: String,
+
+    /// Number of exercises in the track.
+    pub num_exercises: usize,
+
+    /// Number of exercises completed by the user.
+    pub num_completed_exercises: usize,
+
+    /// Ratio of completed exercises, between `0.0` and `1.0`.
+    pub completion: f64,
+}
+
+/// Returns the completion of each [joined](Track::is_joined) track, in the given order.
+pub fn track_completion(tracks: &[Track]) -> Vec<TrackCompletion> {
+    tracks
+        .iter()
+        .filter(|track| track.is_joined)
+        .map(|track| TrackCompletion {
+            name: track.name.clone(),
+            title: track.title.clone(),
+            num_exercises: track.num_exercises,
+            num_completed_exercises: track.num_completed_exercises,
+            completion: ratio(track.num_completed_exercises, track.num_exercises),
+        })
+        .collect()
+}
+
+/// Statistics about a set of solutions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SolutionStats {
+    /// Total number of solutions.
+    pub num_solutions: usize,
+
+    /// Number of [completed](Status::Completed) solutions, including [published](Status::Published) ones.
+    pub num_completed: usize,
+
+    /// Number of [published](Status::Published) solutions.
+    pub num_published: usize,
+
+    /// Ratio of completed solutions that were published, between `0.0` and `1.0`.
+    pub published_ratio: f64,
+
+    /// Average number of iterations submitted per solution.
+    pub average_iterations: f64,
+
+    /// Number of solutions whose [published iteration's head tests](Solution::published_iteration_head_tests_status)
+    /// are failing (see [`is_failing`]).
+    pub num_failing_head_tests: usize,
+
+    /// Ratio of solutions whose published iteration's head tests are failing, between `0.0` and `1.0`.
+    ///
+    /// Only solutions whose head tests have run (see [`has_run`]) are considered.
+    pub failing_head_tests_ratio: f64,
+}
+
+impl SolutionStats {
+    /// Computes statistics about the given solutions.
+    pub fn compute(solutions: &[Solution]) -> Self {
+        let num_completed = solutions
+            .iter()
+            .filter(|solution| matches!(solution.status, Status::Completed | Status::Published))
+            .count();
+        let num_published = solutions
+            .iter()
+            .filter(|solution| solution.status == Status::Published)
+            .count();
+        let num_iterations: usize = solutions
+            .iter()
+            .map(|solution| usize::try_from(solution.num_iterations).unwrap_or_default())
+            .sum();
+        let num_tested = solutions
+            .iter()
+            .filter(|solution| has_run(&solution.published_iteration_head_tests_status))
+            .count();
+        let num_failing_head_tests = solutions
+            .iter()
+            .filter(|solution| is_failing(&solution.published_iteration_head_tests_status))
+            .count();
+
+        Self {
+            num_solutions: solutions.len(),
+            num_completed,
+            num_published,
+            published_ratio: ratio(num_published, num_completed),
+            average_iterations: ratio(num_iterations, solutions.len()),
+            num_failing_head_tests,
+            failing_head_tests_ratio: ratio(num_failing_head_tests, num_tested),
+        }
+    }
+}
+
+/// Returns `true` if the given tests status indicates that tests are failing.
+///
+/// Tests are considered failing if they [failed](tests::Status::Failed), [errored](tests::Status::Errored)
+/// or [raised an exception](tests::Status::Exceptioned).
+pub fn is_failing(status: &tests::Status) -> bool {
+    matches!(status, tests::Status::Failed | tests::Status::Errored | tests::Status::Exceptioned)
+}
+
+/// Returns `true` if the given tests status indicates that tests have run (whether they
+/// [passed](tests::Status::Passed) or are [failing](is_failing)).
+pub fn has_run(status: &tests::Status) -> bool {
+    *status == tests::Status::Passed || is_failing(status)
+}
+
+/// Exercises of a given [`Difficulty`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyStats {
+    /// Difficulty of the exercises.
+    pub difficulty: Difficulty,
+
+    /// Number of exercises with this difficulty.
+    pub num_exercises: usize,
+
+    /// Number of exercises with this difficulty that the user has [completed](Status::Completed).
+    pub num_completed: usize,
+}
+
+/// Groups exercises by [`Difficulty`], from easiest to hardest.
+///
+/// Exercises are matched with the solutions included in the same response to find out if they
+/// were completed. Difficulties without exercises are omitted; [unknown](Difficulty::Unknown)
+/// difficulties come last, in the order they were encountered.
+pub fn exercises_by_difficulty(exercises: &[exercises::Response]) -> Vec<DifficultyStats> {
+    let mut stats: Vec<_> = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+        .into_iter()
+        .map(|difficulty| DifficultyStats { difficulty, num_exercises: 0, num_completed: 0 })
+        .collect();
+
+    for response in exercises {
+        for exercise in &response.exercises {
+            let completed = response.solutions.iter().any(|solution| {
+                solution.exercise.name == exercise.name
+                    && matches!(solution.status, Status::Completed | Status::Published)
+            });
+
+            let index = match stats
+                .iter()
+                .position(|stats| stats.difficulty == exercise.difficulty)
+            {
+                Some(index) => index,
+                None => {
+                    stats.push(DifficultyStats {
+                        difficulty: exercise.difficulty.clone(),
+                        num_exercises: 0,
+                        num_completed: 0,
+                    });
+                    stats.len() - 1
+                },
+            };
+            stats[index].num_exercises += 1;
+            stats[index].num_completed += usize::from(completed);
+        }
+    }
+
+    stats.retain(|stats| stats.num_exercises > 0);
+    stats
+}
+
+/// Period used to bucket [`completions`].
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Period {
+    /// Week, starting on Monday.
+    Week,
+
+    /// Calendar month.
+    Month,
+}
+
+impl Period {
+    /// Returns the first day of the period containing `date`.
+    pub fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Week => date - Days::new(date.weekday().num_days_from_monday().into()),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    fn next(self, start: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Week => start.checked_add_days(Days::new(7)),
+            Self::Month => start.checked_add_months(Months::new(1)),
+        }
+    }
+}
+
+/// Number of events that occurred during a period.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeriodCount {
+    /// First day of the period.
+    pub start: NaiveDate,
+
+    /// Number of events.
+    pub count: usize,
+}
+
+/// Returns the number of solutions [completed](Solution::completed_at) during each period.
+///
+/// Periods are sorted chronologically; periods without completions between the first and the last
+/// completion are included with a count of `0`. Solutions that were not completed (or whose
+/// completion date cannot be parsed) are ignored.
+pub fn completions(solutions: &[Solution], period: Period) -> Vec<PeriodCount> {
+    let mut starts: Vec<_> = solutions
+        .iter()
+        .filter_map(|solution| solution.completed_at.as_deref())
+        .filter_map(parse_date)
+        .map(|date| period.start_of(date))
+        .collect();
+    starts.sort_unstable();
+
+    let (Some(&first), Some(&last)) = (starts.first(), starts.last()) else {
+        return Vec::new();
+    };
+    let mut counts = Vec::new();
+    let mut current = Some(first);
+    while let Some(start) = current.filter(|start| *start <= last) {
+        let count = starts.iter().filter(|other| **other == start).count();
+        counts.push(PeriodCount { start, count });
+        current = period.next(start);
+    }
+
+    counts
+}
+
+/// Parses the date part of an ISO-8601 date/time, like `2023-03-26T05:22:23Z`.
+fn parse_date(date_time: &str) -> Option<NaiveDate> {
+    date_time
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { count as f64 / total as f64 }
+}
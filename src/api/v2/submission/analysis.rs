@@ -2,14 +2,18 @@
 //!
 //! A submission analysis contains information about the output of the [analyzer](https://exercism.org/docs/building/tooling/analyzers)
 //! and/or [representer](https://exercism.org/docs/building/tooling/representers) for the track.
+//! The HTML it contains can be [rendered](render) as text.
+
+pub mod render;
 
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, IntoStaticStr};
+use strum::{AsRefStr, Display};
 
 use crate::api::v2::user::Flair;
 
 /// Feedback returned by the track [analyzer](https://exercism.org/docs/building/tooling/analyzers) for a submission.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalyzerFeedback {
     /// Analysis summary. Not all analyzers provide this.
     #[serde(default)]
@@ -22,6 +26,7 @@ pub struct AnalyzerFeedback {
 
 /// A specific comment returned by the track [analyzer](https://exercism.org/docs/building/tooling/analyzers).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AnalyzerComment {
     /// Type of comment.
     ///
@@ -37,21 +42,10 @@ pub struct AnalyzerComment {
 ///
 /// For more information about analyzer comment types, see [this document](https://github.com/exercism/docs/blob/main/building/tooling/analyzers/interface.md#type-optional).
 #[derive(
-    Debug,
-    Default,
-    Copy,
-    Clone,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Serialize,
-    Deserialize,
-    Display,
-    AsRefStr,
-    IntoStaticStr,
+    Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Display, AsRefStr,
 )]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", expecting = "invalid analyzer comment type: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum AnalyzerCommentType {
     /// Feedback about a fix that is absolutely essential.
@@ -73,12 +67,21 @@ pub enum AnalyzerCommentType {
     /// Unknown comment type.
     ///
     /// Included so that if new comment types are introduced in the website API later, this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::api::detail::deserialize_unknown::<_, AnalyzerCommentType>"
+    )]
+    #[strum(transparent)]
+    Unknown(String),
 }
 
 /// Feedback returned by the track [representer](https://exercism.org/docs/building/tooling/representers) for a submission.
+///
+/// For the representer's own output files, see [`tooling::representer`](crate::tooling::representer).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RepresenterFeedback {
     /// HTML rendering of the feedback.
     pub html: String,
@@ -95,6 +98,7 @@ pub struct RepresenterFeedback {
 
 /// Information about the author of some [`RepresenterFeedback`].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FeedbackAuthor {
     /// Author's full name.
     pub name: String,
@@ -0,0 +1,407 @@
+//! Rendering of the HTML returned for automated feedback as text.
+//!
+//! [`AnalyzerComment::html`], [`AnalyzerFeedback::summary`] and [`RepresenterFeedback::html`] are
+//! HTML fragments meant to be displayed on the [Exercism website](https://exercism.org). This module
+//! can [render](render_html) them as plain text, Markdown or text styled using ANSI escape codes
+//! (for display in a terminal). Paragraphs, headings, emphasis, inline code, code blocks, links,
+//! lists and block quotes are supported; other elements are rendered as their content.
+//!
+//! # Examples
+//!
+//! ```
+//! use mini_exercism::api::v2::submission::analysis::render::{Format, render_html};
+//!
+//! let html = "<p>Consider using <a href=\"https://doc.rust-lang.org/std/iter/\">iterators</a>:</p>\n\
+//!             <ul>\n<li><code>map</code></li>\n<li><code>sum</code></li>\n</ul>\n";
+//!
+//! assert_eq!(
+//!     "Consider using iterators (https://doc.rust-lang.org/std/iter/):\n\n- map\n- sum",
+//!     render_html(html, Format::PlainText),
+//! );
+//! assert_eq!(
+//!     "Consider using [iterators](https://doc.rust-lang.org/std/iter/):\n\n- `map`\n- `sum`",
+//!     render_html(html, Format::Markdown),
+//! );
+//! ```
+
+mod detail;
+
+use std::collections::BTreeMap;
+
+use strum::{AsRefStr, Display, IntoStaticStr};
+
+use crate::api::v2::submission::analysis::render::detail::Node;
+use crate::api::v2::submission::analysis::{
+    AnalyzerComment, AnalyzerCommentType, AnalyzerFeedback, RepresenterFeedback,
+};
+
+/// Possible formats in which feedback HTML can be [rendered](render_html).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Display, AsRefStr, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Format {
+    /// Plain text, without any markup.
+    ///
+    /// Code blocks are indented and link URLs are written in parentheses after the link text.
+    #[default]
+    PlainText,
+
+    /// [CommonMark](https://commonmark.org/) Markdown.
+    Markdown,
+
+    /// Text styled using ANSI escape codes, for display in a terminal.
+    ///
+    /// Uses bold for headings and strong emphasis, italics for emphasis, underline for links
+    /// and cyan for code.
+    Ansi,
+}
+
+/// Renders an HTML fragment in the given [`Format`].
+///
+/// The returned text has no leading or trailing blank lines.
+pub fn render_html(html: &str, format: Format) -> String {
+    Renderer { format }.blocks(&detail::parse(html), "\n\n")
+}
+
+impl AnalyzerFeedback {
+    /// Returns the feedback's [`comments`](Self::comments) grouped by [type](AnalyzerCommentType).
+    ///
+    /// Groups are sorted by importance ([essential](AnalyzerCommentType::Essential) comments first);
+    /// within a group, comments keep their original order.
+    pub fn comments_by_type(&self) -> BTreeMap<AnalyzerCommentType, Vec<&AnalyzerComment>> {
+        self.comments
+            .iter()
+            .fold(BTreeMap::new(), |mut groups, comment| {
+                groups
+                    .entry(comment.comment_type.clone())
+                    .or_insert_with(Vec::new)
+                    .push(comment);
+                groups
+            })
+    }
+
+    /// Renders the feedback's [`summary`](Self::summary) in the given [`Format`], if any.
+    pub fn render_summary(&self, format: Format) -> Option<String> {
+        self.summary
+            .as_deref()
+            .map(|summary| render_html(summary, format))
+    }
+
+    /// Renders the whole feedback in the given [`Format`].
+    ///
+    /// The [`summary`](Self::summary) is rendered first, followed by the comments
+    /// [grouped by type](Self::comments_by_type), each group under a heading.
+    pub fn render(&self, format: Format) -> String {
+        let renderer = Renderer { format };
+        let groups = self
+            .comments_by_type()
+            .into_iter()
+            .map(|(comment_type, comments)| {
+                let heading = renderer.heading(comment_type_title(&comment_type), 2);
+                let comments = comments.into_iter().map(|comment| comment.render(format));
+                [heading]
+                    .into_iter()
+                    .chain(comments)
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            });
+
+        self.render_summary(format)
+            .into_iter()
+            .chain(groups)
+            .filter(|section| !section.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+impl AnalyzerComment {
+    /// Renders the comment's [`html`](Self::html) in the given [`Format`].
+    pub fn render(&self, format: Format) -> String {
+        render_html(&self.html, format)
+    }
+}
+
+impl RepresenterFeedback {
+    /// Renders the feedback's [`html`](Self::html) in the given [`Format`].
+    pub fn render(&self, format: Format) -> String {
+        render_html(&self.html, format)
+    }
+}
+
+fn comment_type_title(comment_type: &AnalyzerCommentType) -> &'static str {
+    match comment_type {
+        AnalyzerCommentType::Essential => "Essential",
+        AnalyzerCommentType::Actionable => "Actionable",
+        AnalyzerCommentType::Informative => "Informative",
+        AnalyzerCommentType::Celebratory => "Celebratory",
+        AnalyzerCommentType::Unknown(_) => "Other",
+    }
+}
+
+const BLOCK_TAGS: [&str; 16] = [
+    "p",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "ul",
+    "ol",
+    "pre",
+    "blockquote",
+    "hr",
+    "table",
+    "tr",
+    "details",
+];
+
+struct Renderer {
+    format: Format,
+}
+
+impl Renderer {
+    /// Renders a list of nodes as blocks separated by `separator`.
+    ///
+    /// Consecutive inline nodes are grouped in a paragraph.
+    fn blocks(&self, nodes: &[Node], separator: &str) -> String {
+        let mut blocks = Vec::new();
+        let mut inline_nodes = Vec::new();
+
+        for node in nodes {
+            if is_block(node) {
+                blocks.push(self.paragraph(&inline_nodes));
+                inline_nodes.clear();
+                blocks.push(self.block(node));
+            } else {
+                inline_nodes.push(node.clone());
+            }
+        }
+        blocks.push(self.paragraph(&inline_nodes));
+
+        blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn block(&self, node: &Node) -> String {
+        let Node::Element { tag, children, .. } = node else {
+            return self.paragraph(std::slice::from_ref(node));
+        };
+
+        match tag.as_str() {
+            "p" => self.paragraph(children),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = usize::from(tag.as_bytes()[1] - b'0');
+                self.heading(&self.paragraph(children), level)
+            },
+            "ul" | "ol" => self.list(node),
+            "pre" => self.code_block(node),
+            "blockquote" => prefix_lines(&self.blocks(children, "\n\n"), "> ", ">"),
+            "hr" => "---".into(),
+            _ => self.blocks(children, "\n\n"),
+        }
+    }
+
+    fn paragraph(&self, nodes: &[Node]) -> String {
+        let text: String = nodes.iter().map(|node| self.inline(node)).collect();
+        text.lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .into()
+    }
+
+    fn heading(&self, text: &str, level: usize) -> String {
+        match self.format {
+            Format::PlainText => text.into(),
+            Format::Markdown => format!("{} {text}", "#".repeat(level)),
+            Format::Ansi => style(text, "1", "22"),
+        }
+    }
+
+    fn list(&self, node: &Node) -> String {
+        let Node::Element { tag, children, .. } = node else {
+            return String::new();
+        };
+        let start: usize = node
+            .attr("start")
+            .and_then(|start| start.parse().ok())
+            .unwrap_or(1);
+
+        children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Element { tag, children, .. } if tag == "li" => Some(children),
+                _ => None,
+            })
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = match tag.as_str() {
+                    "ol" => format!("{}. ", start.saturating_add(i)),
+                    _ => "- ".into(),
+                };
+                let indent = " ".repeat(marker.len());
+                let content = prefix_lines(&self.blocks(item, "\n"), &indent, "");
+                format!("{marker}{}", &content[indent.len().min(content.len())..])
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn code_block(&self, node: &Node) -> String {
+        let code = strip_controls(&node.raw_text());
+        let code = code.strip_suffix('\n').unwrap_or(&code);
+
+        match self.format {
+            Format::PlainText => prefix_lines(code, "    ", ""),
+            Format::Markdown => {
+                let language = code_language(node).unwrap_or_default();
+                let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+                format!("{fence}{language}\n{code}\n{fence}")
+            },
+            Format::Ansi => code
+                .lines()
+                .map(|line| format!("    {}", style(line, "36", "39")))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    fn inline(&self, node: &Node) -> String {
+        let (tag, children) = match node {
+            Node::Text(text) => return self.text(text),
+            Node::Element { tag, children, .. } => (tag.as_str(), children),
+        };
+        let content = || {
+            children
+                .iter()
+                .map(|child| self.inline(child))
+                .collect::<String>()
+        };
+
+        match (tag, self.format) {
+            ("br", Format::Markdown) => "\\\n".into(),
+            ("br", _) => "\n".into(),
+            ("img", _) => self.text(node.attr("alt").unwrap_or_default()),
+            ("code", _) => self.inline_code(&node.raw_text()),
+            ("a", _) => self.link(&content(), node.attr("href")),
+            ("strong" | "b", Format::Markdown) => format!("**{}**", content()),
+            ("strong" | "b", Format::Ansi) => style(&content(), "1", "22"),
+            ("em" | "i", Format::Markdown) => format!("_{}_", content()),
+            ("em" | "i", Format::Ansi) => style(&content(), "3", "23"),
+            ("td" | "th", _) => format!("{} ", content()),
+            _ if is_block(node) => format!("\n{}\n", self.block(node)),
+            _ => content(),
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        let mut collapsed = String::with_capacity(text.len());
+        let mut last_was_space = false;
+        for c in strip_controls(text).chars() {
+            let is_space = c.is_ascii_whitespace();
+            if !is_space || !last_was_space {
+                collapsed.push(if is_space { ' ' } else { c });
+            }
+            last_was_space = is_space;
+        }
+
+        match self.format {
+            Format::Markdown => escape_markdown(&collapsed),
+            Format::PlainText | Format::Ansi => collapsed,
+        }
+    }
+
+    fn inline_code(&self, code: &str) -> String {
+        let code = &strip_controls(code);
+        match self.format {
+            Format::PlainText => code.into(),
+            Format::Markdown => {
+                let fence = "`".repeat(longest_run(code, '`') + 1);
+                let padding = if code.starts_with('`') || code.ends_with('`') { " " } else { "" };
+                format!("{fence}{padding}{code}{padding}{fence}")
+            },
+            Format::Ansi => style(code, "36", "39"),
+        }
+    }
+
+    fn link(&self, text: &str, href: Option<&str>) -> String {
+        let Some(href) = href.filter(|href| !href.is_empty()) else {
+            return text.into();
+        };
+        let href = &strip_controls(href);
+
+        match self.format {
+            Format::Markdown => format!("[{text}]({href})"),
+            Format::PlainText if text.is_empty() || text == href => href.into(),
+            Format::PlainText => format!("{text} ({href})"),
+            Format::Ansi if text.is_empty() || text == href => style(href, "4", "24"),
+            Format::Ansi => format!("{} ({href})", style(text, "4", "24")),
+        }
+    }
+}
+
+fn is_block(node: &Node) -> bool {
+    matches!(node, Node::Element { tag, .. } if BLOCK_TAGS.contains(&tag.as_str()))
+}
+
+fn code_language(pre: &Node) -> Option<String> {
+    let Node::Element { children, .. } = pre else {
+        return None;
+    };
+
+    [pre]
+        .into_iter()
+        .chain(children)
+        .filter_map(|node| node.attr("class"))
+        .flat_map(str::split_ascii_whitespace)
+        .find_map(|class| class.strip_prefix("language-"))
+        .map(Into::into)
+}
+
+// Control characters (other than newlines and tabs) are removed from the HTML's content, so
+// that it cannot inject escape sequences in the terminal displaying the rendered text.
+fn strip_controls(text: &str) -> String {
+    text.chars()
+        .filter(|&c| !c.is_control() || c == '\n' || c == '\t')
+        .collect()
+}
+
+fn style(text: &str, start: &str, end: &str) -> String {
+    format!("\x1b[{start}m{text}\x1b[{end}m")
+}
+
+fn prefix_lines(text: &str, prefix: &str, empty_line_prefix: &str) -> String {
+    text.lines()
+        .map(
+            |line| {
+                if line.is_empty() { empty_line_prefix.into() } else { format!("{prefix}{line}") }
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|other| other != c)
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
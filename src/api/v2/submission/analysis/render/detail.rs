@@ -0,0 +1,346 @@
+/// Node of a (very) simplified HTML document tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element { tag: String, attrs: Vec<(String, String)>, children: Vec<Node> },
+    Text(String),
+}
+
+impl Node {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(attr_name, _)| attr_name == name)
+                .map(|(_, value)| value.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    /// Returns the text of the node and its descendants, without any whitespace processing.
+    pub fn raw_text(&self) -> String {
+        match self {
+            Node::Element { children, .. } => children.iter().map(Node::raw_text).collect(),
+            Node::Text(text) => text.clone(),
+        }
+    }
+}
+
+const VOID_TAGS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements that are implicitly closed when a sibling element of the same type starts.
+const IMPLICITLY_CLOSED_TAGS: [&str; 2] = ["li", "p"];
+
+/// Parses an HTML fragment into a list of nodes.
+///
+/// This parser is lenient and only supports what is needed to render the HTML produced for
+/// automated feedback: comments, doctypes and processing instructions are skipped, unclosed
+/// elements are closed implicitly (at the end of their parent, or when a sibling list item or
+/// paragraph starts) and stray closing tags are ignored.
+pub fn parse(html: &str) -> Vec<Node> {
+    let mut stack: Vec<OpenElement> = vec![(String::new(), Vec::new(), Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some((tag, len)) = parse_tag(rest) {
+            rest = &rest[len..];
+            match tag {
+                Tag::Start { name, attrs, self_closing } => {
+                    if IMPLICITLY_CLOSED_TAGS.contains(&name.as_str())
+                        && stack.last().is_some_and(|(tag, ..)| *tag == name)
+                    {
+                        close_element(&mut stack);
+                    }
+                    if self_closing || VOID_TAGS.contains(&name.as_str()) {
+                        push_node(
+                            &mut stack,
+                            Node::Element { tag: name, attrs, children: Vec::new() },
+                        );
+                    } else {
+                        stack.push((name, attrs, Vec::new()));
+                    }
+                },
+                Tag::End { name } => {
+                    if let Some(index) = stack.iter().skip(1).rposition(|(tag, ..)| *tag == name) {
+                        while stack.len() > index + 1 {
+                            close_element(&mut stack);
+                        }
+                    }
+                },
+            }
+        } else {
+            let start = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[start..]
+                .find('<')
+                .map_or(rest.len(), |end| end + start);
+            push_node(&mut stack, Node::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close_element(&mut stack);
+    }
+    stack
+        .pop()
+        .map(|(.., children)| children)
+        .unwrap_or_default()
+}
+
+/// Element being parsed: its tag, attributes and children parsed so far.
+type OpenElement = (String, Vec<(String, String)>, Vec<Node>);
+
+enum Tag {
+    Start { name: String, attrs: Vec<(String, String)>, self_closing: bool },
+    End { name: String },
+}
+
+/// Parses a tag at the start of `input`, returning it along with its length.
+fn parse_tag(input: &str) -> Option<(Tag, usize)> {
+    let (is_end, start) = if input.starts_with("</") { (true, 2) } else { (false, 1) };
+    if !input.starts_with('<') || !input[start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let name_len = input[start..]
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(input.len() - start);
+    let name = input[start..start + name_len].to_ascii_lowercase();
+    let mut pos = start + name_len;
+
+    let mut attrs = Vec::new();
+    let mut self_closing = false;
+    loop {
+        pos += input[pos..].len() - input[pos..].trim_start().len();
+        let rest = &input[pos..];
+        if let Some(after) = rest.strip_prefix('>') {
+            pos = input.len() - after.len();
+            break;
+        } else if let Some(after) = rest.strip_prefix("/>") {
+            self_closing = true;
+            pos = input.len() - after.len();
+            break;
+        } else if rest.is_empty() {
+            return None;
+        }
+
+        let attr_len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len())
+            .max(1);
+        let attr_name = rest[..attr_len].to_ascii_lowercase();
+        pos += attr_len;
+
+        let rest = &input[pos..];
+        let value = match rest.trim_start().strip_prefix('=') {
+            Some(after_eq) => {
+                let value_start = after_eq.trim_start();
+                let (value, consumed) = match value_start.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value_end = value_start[1..].find(quote)?;
+                        (&value_start[1..value_end + 1], value_end + 2)
+                    },
+                    _ => {
+                        let value_end = value_start
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(value_start.len());
+                        (&value_start[..value_end], value_end)
+                    },
+                };
+                pos = input.len() - value_start.len() + consumed;
+                decode_entities(value)
+            },
+            None => String::new(),
+        };
+        if attr_name != "/" {
+            attrs.push((attr_name, value));
+        }
+    }
+
+    let tag = if is_end { Tag::End { name } } else { Tag::Start { name, attrs, self_closing } };
+    Some((tag, pos))
+}
+
+fn push_node(stack: &mut [OpenElement], node: Node) {
+    if let Some((.., children)) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+fn close_element(stack: &mut Vec<OpenElement>) {
+    if let Some((tag, attrs, children)) = stack.pop() {
+        push_node(stack, Node::Element { tag, attrs, children });
+    }
+}
+
+/// Decodes HTML character references in `text`.
+///
+/// Only numeric references and the most common named ones are supported; others are left as-is.
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| {
+                let reference = &rest[1..end + 1];
+                let c = match reference {
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some('\u{a0}'),
+                    _ => reference
+                        .strip_prefix("#x")
+                        .or_else(|| reference.strip_prefix("#X"))
+                        .map(|hex| u32::from_str_radix(hex, 16))
+                        .or_else(|| reference.strip_prefix('#').map(str::parse))
+                        .and_then(Result::ok)
+                        .and_then(char::from_u32),
+                };
+                c.map(|c| (c, end + 2))
+            });
+
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &rest[len..];
+            },
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn element(tag: &str, attrs: &[(&str, &str)], children: Vec<Node>) -> Node {
+        Node::Element {
+            tag: tag.into(),
+            attrs: attrs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            children,
+        }
+    }
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.into())
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_elements() {
+            let html =
+                r#"<p>Use <a href="https://doc.rust-lang.org/std/iter/">iterators</a>!<br/></p>"#;
+
+            let expected = vec![element(
+                "p",
+                &[],
+                vec![
+                    text("Use "),
+                    element(
+                        "a",
+                        &[("href", "https://doc.rust-lang.org/std/iter/")],
+                        vec![text("iterators")],
+                    ),
+                    text("!"),
+                    element("br", &[], vec![]),
+                ],
+            )];
+            assert_eq!(expected, parse(html));
+        }
+
+        #[test]
+        fn test_lenient() {
+            let html = "<!-- comment --><UL class=list><li>one<li>two</span></ul>a < b";
+
+            let expected = vec![
+                element(
+                    "ul",
+                    &[("class", "list")],
+                    vec![
+                        element("li", &[], vec![text("one")]),
+                        element("li", &[], vec![text("two")]),
+                    ],
+                ),
+                text("a "),
+                text("< b"),
+            ];
+            assert_eq!(expected, parse(html));
+        }
+
+        #[test]
+        fn test_void_and_unclosed() {
+            let html = "<p>line<br>next<hr><pre><code>x";
+
+            let expected = vec![element(
+                "p",
+                &[],
+                vec![
+                    text("line"),
+                    element("br", &[], vec![]),
+                    text("next"),
+                    element("hr", &[], vec![]),
+                    element("pre", &[], vec![element("code", &[], vec![text("x")])]),
+                ],
+            )];
+            assert_eq!(expected, parse(html));
+        }
+
+        #[test]
+        fn test_non_ascii_text() {
+            let html = "é<b>x</b>→ b<i>ü</i>";
+
+            let expected = vec![
+                text("é"),
+                element("b", &[], vec![text("x")]),
+                text("→ b"),
+                element("i", &[], vec![text("ü")]),
+            ];
+            assert_eq!(expected, parse(html));
+        }
+    }
+
+    mod decode_entities {
+        use super::*;
+
+        #[test]
+        fn test_decode() {
+            assert_eq!(
+                "<Vec<&str>> \"'' \u{a0}é😀",
+                decode_entities(
+                    "&lt;Vec&lt;&amp;str&gt;&gt; &quot;&apos;&#39; &nbsp;&#xe9;&#128512;"
+                )
+            );
+        }
+
+        #[test]
+        fn test_unknown() {
+            assert_eq!("&foo; & bar &#xzz;", decode_entities("&foo; & bar &#xzz;"));
+        }
+    }
+}
@@ -1,16 +1,146 @@
 //! Types related to files submitted to the [Exercism website](https://exercism.org) for a solution.
+//!
+//! Each submitted [`File`] includes a [`digest`](File::digest) of its content, which can be used to
+//! [verify](Response::verify) that the content was not corrupted. Submitted files can also be
+//! [compared](Response::diff_with_dir) with the files of a local exercise directory, for example
+//! before overwriting them.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//!
+//! async fn print_local_changes(
+//!     client: &api::v2::Client,
+//!     solution_uuid: &str,
+//!     submission_uuid: &str,
+//!     exercise_dir: &str,
+//! ) -> anyhow::Result<()> {
+//!     let files = client
+//!         .get_submission_files(solution_uuid, submission_uuid)
+//!         .await?;
+//!     files.verify()?;
+//!
+//!     let diff = files.diff_with_dir(exercise_dir)?;
+//!     for path in &diff.modified {
+//!         println!("modified locally: {}", path.display());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::track_repo::exercise_config::ExerciseConfig;
+use crate::{Error, Result};
+
 /// Response to a query for files submitted for a solution on the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "SubmissionFilesResponse"))]
 pub struct Response {
     /// Files that were part of the submission, including their content.
     pub files: Vec<File>,
 }
 
+impl Response {
+    /// Verifies that the content of every file matches its [`digest`](File::digest).
+    ///
+    /// # Errors
+    ///
+    /// - [`DigestMismatch`]: The content of a file does not match its digest (the first such
+    ///   file is reported; use [`corrupted_files`](Self::corrupted_files) to list them all)
+    ///
+    /// [`DigestMismatch`]: crate::Error::DigestMismatch
+    pub fn verify(&self) -> Result<()> {
+        self.files.iter().try_for_each(File::verify)
+    }
+
+    /// Returns the files whose content does not match their [`digest`](File::digest).
+    pub fn corrupted_files(&self) -> impl Iterator<Item = &File> {
+        self.files.iter().filter(|file| !file.has_valid_digest())
+    }
+
+    /// Compares the submitted files with the files of a local exercise directory.
+    ///
+    /// The local files considered are the solution files listed in the exercise's
+    /// [config](ExerciseConfig::read_from), plus any submitted file that exists locally.
+    /// See [`diff_with_files`](Self::diff_with_files) for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while reading the exercise config or a local file
+    /// - [`TrackRepoFormatError`]: The exercise config is not valid
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    /// [`TrackRepoFormatError`]: crate::Error::TrackRepoFormatError
+    pub fn diff_with_dir<P>(&self, exercise_dir: P) -> Result<FilesDiff>
+    where
+        P: AsRef<Path>,
+    {
+        let exercise_dir = exercise_dir.as_ref();
+        let config = ExerciseConfig::read_from(exercise_dir)?;
+        let local_files = config
+            .files
+            .solution
+            .iter()
+            .map(PathBuf::from)
+            .filter(|path| exercise_dir.join(path).is_file());
+
+        self.diff_with_files(exercise_dir, local_files)
+    }
+
+    /// Compares the submitted files with the given files of a local exercise directory.
+    ///
+    /// `local_files` must be relative to `exercise_dir`. Submitted files that exist locally are
+    /// always compared, even if they are not part of `local_files`. Local files are compared with
+    /// the submitted [`content`](File::content), byte for byte.
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while reading a local file
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    pub fn diff_with_files<P, I, F>(&self, exercise_dir: P, local_files: I) -> Result<FilesDiff>
+    where
+        P: AsRef<Path>,
+        I: IntoIterator<Item = F>,
+        F: Into<PathBuf>,
+    {
+        let exercise_dir = exercise_dir.as_ref();
+        let mut local_files: BTreeSet<PathBuf> = local_files.into_iter().map(Into::into).collect();
+
+        let mut diff = FilesDiff::default();
+        for file in &self.files {
+            let path = PathBuf::from(&file.filename);
+            let full_path = exercise_dir.join(&path);
+            if !local_files.remove(&path) && !full_path.is_file() {
+                diff.removed.push(path);
+                continue;
+            }
+
+            let local_content = fs::read(&full_path)
+                .map_err(|source| Error::TrackRepoIoError { path: full_path, source })?;
+            if local_content != file.content.as_bytes() {
+                diff.modified.push(path);
+            }
+        }
+        diff.added = local_files.into_iter().collect();
+        diff.removed.sort();
+        diff.modified.sort();
+
+        Ok(diff)
+    }
+}
+
 /// Information about a file that is part of a submission, including its content.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct File {
     /// Name of the file, including its path from the exercise directory's root.
     pub filename: String,
@@ -18,6 +148,74 @@ pub struct File {
     /// File content.
     pub content: String,
 
-    /// File digest (a hash of some kind).
+    /// File digest (a SHA-1 hash of [`content`](Self::content), as a lowercase hex string).
+    ///
+    /// See [`compute_digest`](Self::compute_digest).
     pub digest: String,
 }
+
+impl File {
+    /// Computes the digest of the given file content, the same way the [Exercism website](https://exercism.org) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_exercism::api::v2::submission::files::File;
+    ///
+    /// assert_eq!("a9993e364706816aba3e25717850c26c9cd0d89d", File::compute_digest("abc"));
+    /// ```
+    pub fn compute_digest<C>(content: C) -> String
+    where
+        C: AsRef<[u8]>,
+    {
+        sha1_smol::Sha1::from(content).digest().to_string()
+    }
+
+    /// Returns `true` if the file's [`content`](Self::content) matches its [`digest`](Self::digest).
+    pub fn has_valid_digest(&self) -> bool {
+        Self::compute_digest(&self.content).eq_ignore_ascii_case(&self.digest)
+    }
+
+    /// Verifies that the file's [`content`](Self::content) matches its [`digest`](Self::digest).
+    ///
+    /// # Errors
+    ///
+    /// - [`DigestMismatch`]: The content of the file does not match its digest
+    ///
+    /// [`DigestMismatch`]: crate::Error::DigestMismatch
+    pub fn verify(&self) -> Result<()> {
+        let actual = Self::compute_digest(&self.content);
+        if actual.eq_ignore_ascii_case(&self.digest) {
+            Ok(())
+        } else {
+            Err(Error::DigestMismatch {
+                filename: self.filename.clone(),
+                expected: self.digest.clone(),
+                actual,
+            })
+        }
+    }
+}
+
+/// Differences between the files of a submission and those of a local exercise directory.
+///
+/// Returned by [`Response::diff_with_dir`]. All paths are relative to the exercise directory
+/// and sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilesDiff {
+    /// Local files that are not part of the submission.
+    pub added: Vec<PathBuf>,
+
+    /// Submitted files that do not exist locally.
+    pub removed: Vec<PathBuf>,
+
+    /// Submitted files whose local content differs.
+    pub modified: Vec<PathBuf>,
+}
+
+impl FilesDiff {
+    /// Returns `true` if the local exercise directory matches the submission.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
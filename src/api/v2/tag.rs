@@ -0,0 +1,364 @@
+//! Types related to language track tags returned by the [Exercism website](https://exercism.org) v2 API.
+//!
+//! Tags are documented [here](https://exercism.org/docs/building/tracks/config-json#h-tags). They are
+//! grouped in [categories](TagCategory) and identified by a `category/value` slug, like
+//! `paradigm/functional`. The website displays them using a title instead, like `Functional`;
+//! this is the format used in [`Track::tags`](crate::api::v2::track::Track::tags).
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantArray, VariantNames};
+
+/// A tag attached to an [Exercism](https://exercism.org) language track.
+///
+/// A tag can be parsed from either its `category/value` slug or its title (case-insensitive).
+/// Tags that cannot be recognized are stored as [`Unknown`](Tag::Unknown), so parsing never fails.
+///
+/// Tags are [displayed](fmt::Display) using their slug.
+///
+/// # Examples
+///
+/// ```
+/// use mini_exercism::api::v2::tag::{Paradigm, Tag, TagCategory};
+///
+/// let tag: Tag = "paradigm/functional".into();
+/// assert_eq!(Tag::Paradigm(Paradigm::Functional), tag);
+/// assert_eq!(Tag::Paradigm(Paradigm::Functional), Tag::from("Functional"));
+/// assert_eq!(Some(TagCategory::Paradigm), tag.category());
+/// assert_eq!("Functional", tag.title());
+/// assert_eq!("paradigm/functional", tag.to_string());
+///
+/// assert_eq!(Tag::Unknown("paradigm/funcional".into()), Tag::from("paradigm/funcional"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "String", into = "String")]
+pub enum Tag {
+    /// Programming paradigm supported by the language.
+    Paradigm(Paradigm),
+
+    /// Typing discipline of the language.
+    Typing(Typing),
+
+    /// How programs written in the language are executed.
+    ExecutionMode(ExecutionMode),
+
+    /// Platform on which programs written in the language can run.
+    Platform(Platform),
+
+    /// Runtime used to run programs written in the language.
+    Runtime(Runtime),
+
+    /// What the language is typically used for.
+    UsedFor(UsedFor),
+
+    /// Unknown tag.
+    ///
+    /// Contains the tag as it was parsed. Included so that if new tags are introduced
+    /// on the website later, this crate won't break (hopefully).
+    Unknown(String),
+}
+
+impl Tag {
+    /// Returns the category of this tag, or `None` if the tag is [`Unknown`](Tag::Unknown).
+    pub fn category(&self) -> Option<TagCategory> {
+        match self {
+            Self::Paradigm(_) => Some(TagCategory::Paradigm),
+            Self::Typing(_) => Some(TagCategory::Typing),
+            Self::ExecutionMode(_) => Some(TagCategory::ExecutionMode),
+            Self::Platform(_) => Some(TagCategory::Platform),
+            Self::Runtime(_) => Some(TagCategory::Runtime),
+            Self::UsedFor(_) => Some(TagCategory::UsedFor),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the value of this tag within its category, like `functional`.
+    ///
+    /// For [`Unknown`](Tag::Unknown) tags, returns the tag as it was parsed.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Paradigm(value) => value.as_ref(),
+            Self::Typing(value) => value.as_ref(),
+            Self::ExecutionMode(value) => value.as_ref(),
+            Self::Platform(value) => value.as_ref(),
+            Self::Runtime(value) => value.as_ref(),
+            Self::UsedFor(value) => value.as_ref(),
+            Self::Unknown(tag) => tag,
+        }
+    }
+
+    /// Returns the title of this tag, as displayed on the [Exercism website](https://exercism.org).
+    ///
+    /// For [`Unknown`](Tag::Unknown) tags, returns the tag as it was parsed.
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Paradigm(value) => value.title(),
+            Self::Typing(value) => value.title(),
+            Self::ExecutionMode(value) => value.title(),
+            Self::Platform(value) => value.title(),
+            Self::Runtime(value) => value.title(),
+            Self::UsedFor(value) => value.title(),
+            Self::Unknown(tag) => tag,
+        }
+    }
+
+    fn from_slug(slug: &str) -> Option<Self> {
+        let (category, value) = slug.split_once('/')?;
+        match category.parse().ok()? {
+            TagCategory::Paradigm => value.parse().ok().map(Self::Paradigm),
+            TagCategory::Typing => value.parse().ok().map(Self::Typing),
+            TagCategory::ExecutionMode => value.parse().ok().map(Self::ExecutionMode),
+            TagCategory::Platform => value.parse().ok().map(Self::Platform),
+            TagCategory::Runtime => value.parse().ok().map(Self::Runtime),
+            TagCategory::UsedFor => value.parse().ok().map(Self::UsedFor),
+        }
+    }
+
+    fn from_title(title: &str) -> Option<Self> {
+        Paradigm::from_title(title)
+            .map(Self::Paradigm)
+            .or_else(|| Typing::from_title(title).map(Self::Typing))
+            .or_else(|| ExecutionMode::from_title(title).map(Self::ExecutionMode))
+            .or_else(|| Platform::from_title(title).map(Self::Platform))
+            .or_else(|| Runtime::from_title(title).map(Self::Runtime))
+            .or_else(|| UsedFor::from_title(title).map(Self::UsedFor))
+    }
+}
+
+impl FromStr for Tag {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_slug(s)
+            .or_else(|| Self::from_title(s))
+            .unwrap_or_else(|| Self::Unknown(s.into())))
+    }
+}
+
+impl From<&str> for Tag {
+    fn from(value: &str) -> Self {
+        let Ok(tag) = value.parse();
+        tag
+    }
+}
+
+impl From<String> for Tag {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<Tag> for String {
+    fn from(value: Tag) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.category() {
+            Some(category) => write!(f, "{category}/{}", self.value()),
+            None => f.write_str(self.value()),
+        }
+    }
+}
+
+/// Possible categories of [Exercism](https://exercism.org) language track [tags](Tag).
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    IntoStaticStr,
+    VariantNames,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum TagCategory {
+    /// [`Paradigm`] tags.
+    Paradigm,
+
+    /// [`Typing`] tags.
+    Typing,
+
+    /// [`ExecutionMode`] tags.
+    ExecutionMode,
+
+    /// [`Platform`] tags.
+    Platform,
+
+    /// [`Runtime`] tags.
+    Runtime,
+
+    /// [`UsedFor`] tags.
+    UsedFor,
+}
+
+macro_rules! define_tag_values {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $title:literal,)+
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(
+            Debug,
+            Copy,
+            Clone,
+            PartialEq,
+            Eq,
+            Hash,
+            AsRefStr,
+            Display,
+            EnumString,
+            IntoStaticStr,
+            VariantNames,
+            VariantArray,
+        )]
+        #[strum(serialize_all = "snake_case")]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)+
+        }
+
+        impl $name {
+            /// Returns the title of this tag value, as displayed on the [Exercism website](https://exercism.org).
+            pub fn title(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $title,)+
+                }
+            }
+
+            fn from_title(title: &str) -> Option<Self> {
+                <Self as VariantArray>::VARIANTS
+                    .iter()
+                    .copied()
+                    .find(|value| value.title().eq_ignore_ascii_case(title))
+            }
+        }
+
+        impl From<$name> for Tag {
+            fn from(value: $name) -> Self {
+                Self::$name(value)
+            }
+        }
+    };
+}
+
+define_tag_values! {
+    /// Programming paradigm supported by a language (`paradigm/*` tags).
+    pub enum Paradigm {
+        /// Declarative programming.
+        Declarative => "Declarative",
+        /// Functional programming.
+        Functional => "Functional",
+        /// Imperative programming.
+        Imperative => "Imperative",
+        /// Logic programming.
+        Logic => "Logic",
+        /// Object-oriented programming.
+        ObjectOriented => "Object-oriented",
+        /// Procedural programming.
+        Procedural => "Procedural",
+    }
+}
+
+define_tag_values! {
+    /// Typing discipline of a language (`typing/*` tags).
+    pub enum Typing {
+        /// Static typing.
+        Static => "Static",
+        /// Dynamic typing.
+        Dynamic => "Dynamic",
+        /// Strong typing.
+        Strong => "Strong",
+        /// Weak typing.
+        Weak => "Weak",
+    }
+}
+
+define_tag_values! {
+    /// How programs written in a language are executed (`execution_mode/*` tags).
+    pub enum ExecutionMode {
+        /// Programs are compiled.
+        Compiled => "Compiled",
+        /// Programs are interpreted.
+        Interpreted => "Interpreted",
+    }
+}
+
+define_tag_values! {
+    /// Platform on which programs written in a language can run (`platform/*` tags).
+    pub enum Platform {
+        /// Windows.
+        Windows => "Windows",
+        /// macOS.
+        Mac => "Mac OSX",
+        /// Linux.
+        Linux => "Linux",
+        /// iOS.
+        Ios => "iOS",
+        /// Android.
+        Android => "Android",
+        /// Web browsers.
+        Web => "Web",
+    }
+}
+
+define_tag_values! {
+    /// Runtime used to run programs written in a language (`runtime/*` tags).
+    pub enum Runtime {
+        /// Programs are compiled to a standalone executable.
+        StandaloneExecutable => "Standalone executable",
+        /// Programs run on a runtime specific to the language.
+        LanguageSpecific => "Language-specific runtime",
+        /// Programs run on the .NET Common Language Runtime.
+        Clr => "CLR (.NET)",
+        /// Programs run on the Java Virtual Machine.
+        Jvm => "JVM (Java)",
+        /// Programs run on the Erlang virtual machine.
+        Beam => "BEAM (Erlang)",
+        /// Programs run on the Wasmtime WebAssembly runtime.
+        Wasmtime => "WebAssembly",
+    }
+}
+
+define_tag_values! {
+    /// What a language is typically used for (`used_for/*` tags).
+    pub enum UsedFor {
+        /// Artificial intelligence.
+        ArtificialIntelligence => "Artificial Intelligence",
+        /// Backends.
+        Backends => "Backends",
+        /// Cross-platform development.
+        CrossPlatformDevelopment => "Cross-platform development",
+        /// Embedded systems.
+        EmbeddedSystems => "Embedded systems",
+        /// Financial systems.
+        FinancialSystems => "Financial systems",
+        /// Frontends.
+        Frontends => "Frontends",
+        /// Games.
+        Games => "Games",
+        /// Graphical user interfaces.
+        Guis => "GUIs",
+        /// Mobile applications.
+        Mobile => "Mobile",
+        /// Robotics.
+        Robotics => "Robotics",
+        /// Scientific calculations.
+        ScientificCalculations => "Scientific calculations",
+        /// Scripts.
+        Scripts => "Scripts",
+        /// Web development.
+        WebDevelopment => "Web development",
+    }
+}
@@ -1,7 +1,9 @@
 //! Types related to test runs submitted to the [Exercism website](https://exercism.org) v2 API.
 
+pub mod run;
+
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantNames};
+use strum::{AsRefStr, Display, EnumString, VariantNames};
 
 /// Possible status of a test run on the [Exercism website](https://exercism.org).
 ///
@@ -13,7 +15,6 @@ use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantNames};
 #[derive(
     Debug,
     Default,
-    Copy,
     Clone,
     PartialEq,
     Eq,
@@ -22,10 +23,11 @@ use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantNames};
     AsRefStr,
     Display,
     EnumString,
-    IntoStaticStr,
     VariantNames,
 )]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TestsStatus"))]
+#[serde(rename_all = "snake_case", expecting = "invalid tests status: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Status {
     /// Tests have not been queued yet.
@@ -56,6 +58,9 @@ pub enum Status {
     ///
     /// Included so that if new tests statuses are introduced in the website API later,
     /// this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Status>")]
+    #[strum(default, transparent)]
+    Unknown(String),
 }
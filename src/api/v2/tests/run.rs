@@ -0,0 +1,189 @@
+//! Types related to test runs returned by the [Exercism website](https://exercism.org) v2 API.
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString, VariantNames};
+
+use crate::api::id::SubmissionUuid;
+use crate::api::v2::tests;
+use crate::tooling::test_runner;
+
+/// Response to a query for a submission's test run on the [Exercism website](https://exercism.org) v2 API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TestRunResponse"))]
+pub struct Response {
+    /// Information about the test run.
+    ///
+    /// Will be `None` if the submission has not been tested yet.
+    #[serde(default)]
+    pub test_run: Option<TestRun>,
+}
+
+/// A test run of a submission on the [Exercism website](https://exercism.org).
+///
+/// Most of the information comes from the track's [test runner](https://exercism.org/docs/building/tooling/test-runners)
+/// output; see [`to_results`](Self::to_results) to convert it to the test runner format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TestRun {
+    /// Unique ID of the submission that was tested.
+    pub submission_uuid: SubmissionUuid,
+
+    /// Version of the test runner output format (see [`Results::version`](test_runner::Results::version)).
+    #[serde(default = "default_version")]
+    pub version: u32,
+
+    /// Status of the test run.
+    pub status: Status,
+
+    /// Error message, when the test run's [`status`](Self::status) is [`Error`](Status::Error).
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// HTML rendering of the [`message`](Self::message).
+    #[serde(default)]
+    pub message_html: Option<String>,
+
+    /// Output of the test run.
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// HTML rendering of the [`output`](Self::output).
+    #[serde(default)]
+    pub output_html: Option<String>,
+
+    /// Results of individual tests.
+    #[serde(default)]
+    pub tests: Vec<Test>,
+
+    /// Status of the submission's tests.
+    #[serde(default)]
+    pub tests_status: tests::Status,
+
+    /// Name of the language used by [highlight.js](https://highlightjs.org/) to highlight the test code.
+    #[serde(default)]
+    pub highlightjs_language: Option<String>,
+}
+
+impl TestRun {
+    /// Converts this test run to the track [test runner](https://exercism.org/docs/building/tooling/test-runners)
+    /// output format.
+    ///
+    /// Statuses that do not exist in the test runner format (like [`Timeout`](Status::Timeout))
+    /// are converted to [`Error`](test_runner::Status::Error).
+    pub fn to_results(&self) -> test_runner::Results {
+        test_runner::Results {
+            version: self.version,
+            status: self.status.clone().into(),
+            message: self.message.clone(),
+            tests: self
+                .tests
+                .iter()
+                .map(|test| test_runner::TestResult {
+                    name: test.name.clone(),
+                    status: test.status.clone().into(),
+                    message: test.message.clone(),
+                    output: test.output.clone(),
+                    test_code: test.test_code.clone(),
+                    task_id: test.task_id,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Result of an individual test in a [`TestRun`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Test {
+    /// Name of the test.
+    pub name: String,
+
+    /// Status of the test.
+    pub status: Status,
+
+    /// Code of the test.
+    #[serde(default)]
+    pub test_code: Option<String>,
+
+    /// Message explaining why the test did not pass.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// HTML rendering of the [`message`](Self::message).
+    #[serde(default)]
+    pub message_html: Option<String>,
+
+    /// Expected value, if reported by the test runner.
+    #[serde(default)]
+    pub expected: Option<String>,
+
+    /// Output produced by the student's code while running the test.
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// HTML rendering of the [`output`](Self::output).
+    #[serde(default)]
+    pub output_html: Option<String>,
+
+    /// ID of the exercise task that this test is linked to.
+    #[serde(default)]
+    pub task_id: Option<u32>,
+}
+
+/// Possible status of a test run, or of an individual test, on the [Exercism website](https://exercism.org).
+#[derive(
+    Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AsRefStr, Display, EnumString, VariantNames,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TestRunStatus"))]
+#[serde(rename_all = "snake_case", expecting = "invalid test run status: expected a string")]
+#[strum(serialize_all = "snake_case")]
+pub enum Status {
+    /// All tests passed.
+    Pass,
+
+    /// At least one test failed.
+    Fail,
+
+    /// Tests could not be run (for example because of a compilation error).
+    Error,
+
+    /// Tests could not be run because of an error in the test runner infrastructure.
+    OpsError,
+
+    /// Test run took too long and was aborted.
+    Timeout,
+
+    /// Test run was cancelled.
+    Cancelled,
+
+    /// Test run is queued and has not completed yet.
+    Queued,
+
+    /// Unknown status.
+    ///
+    /// Included so that if new test run statuses are introduced in the website API later,
+    /// this crate won't break (hopefully).
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Status>")]
+    #[strum(default, transparent)]
+    Unknown(String),
+}
+
+impl From<Status> for test_runner::Status {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::Pass => Self::Pass,
+            Status::Fail => Self::Fail,
+            Status::Error | Status::OpsError | Status::Timeout | Status::Cancelled => Self::Error,
+            Status::Queued => Self::Unknown(value.to_string()),
+            Status::Unknown(value) => Self::Unknown(value),
+        }
+    }
+}
+
+fn default_version() -> u32 {
+    test_runner::DEFAULT_VERSION
+}
@@ -2,14 +2,19 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::id::TrackSlug;
+use crate::api::link::{Link, WebPage};
+use crate::api::v2::tag::{Tag, TagCategory};
+
 /// A single language track returned by the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Track {
     /// Name of the language track.
     ///
     /// This is an internal name, like `common-lisp`. Also called `slug`.
     #[serde(rename = "slug")]
-    pub name: String,
+    pub name: TrackSlug,
 
     /// Language track title.
     ///
@@ -31,6 +36,7 @@ pub struct Track {
     /// List of tags attached to this language track.
     ///
     /// Can contain many information, like `Object-oriented`, `Linux`, etc.
+    /// To work with typed [`Tag`]s instead, see [`typed_tags`](Self::typed_tags).
     pub tags: Vec<String>,
 
     /// Links pertaining to the language track.
@@ -55,18 +61,59 @@ pub struct Track {
     pub num_completed_exercises: usize,
 }
 
+impl Track {
+    /// Returns the language track's [`tags`](Self::tags), parsed as [`Tag`]s.
+    pub fn typed_tags(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.tags.iter().map(|tag| Tag::from(tag.as_str()))
+    }
+
+    /// Returns the language track's [`tags`](Self::tags) that belong to the given category.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mini_exercism::api;
+    /// use mini_exercism::api::v2::tag::{Tag, TagCategory};
+    ///
+    /// async fn get_rust_paradigms() -> anyhow::Result<Vec<Tag>> {
+    ///     let client = api::v2::Client::new()?;
+    ///     let tracks = client.get_tracks(None).await?.tracks;
+    ///     let rust = tracks.into_iter().find(|track| track.name == "rust");
+    ///
+    ///     Ok(rust
+    ///         .map(|track| track.tags_in(TagCategory::Paradigm).collect())
+    ///         .unwrap_or_default())
+    /// }
+    /// ```
+    pub fn tags_in(&self, category: TagCategory) -> impl Iterator<Item = Tag> + '_ {
+        self.typed_tags()
+            .filter(move |tag| tag.category() == Some(category))
+    }
+
+    /// Returns `true` if the given tag is attached to the language track.
+    pub fn has_tag<T>(&self, tag: T) -> bool
+    where
+        T: Into<Tag>,
+    {
+        let tag = tag.into();
+        self.typed_tags().any(|t| t == tag)
+    }
+}
+
 /// Links pertaining to an [Exercism](https://exercism.org) language track returned by the v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TrackLinks"))]
 pub struct Links {
     /// URL of the language track on the [Exercism website](https://exercism.org).
     ///
     /// Corresponds to the track's [`web_url`](Track::web_url).
     #[serde(rename = "self")]
-    pub self_url: String,
+    pub self_url: Link<WebPage>,
 
     /// URL of the language track's exercises on the [Exercism website](https://exercism.org).
-    pub exercises: String,
+    pub exercises: Link<WebPage>,
 
     /// URL of the language track's concepts on the [Exercism website](https://exercism.org).
-    pub concepts: String,
+    pub concepts: Link<WebPage>,
 }
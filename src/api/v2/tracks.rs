@@ -6,6 +6,7 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, Display, EnumString, IntoStaticStr, VariantNames};
 
+use crate::api::v2::tag::Tag;
 use crate::api::v2::track::Track;
 
 /// Filters that can be applied when fetching language tracks from the
@@ -26,12 +27,15 @@ pub struct Filters<'a> {
 
     /// List of [`tags`](Track::tags) that must be attached to the language track.
     ///
+    /// Tags can be added using their `category/value` slug or their title (see [`Tag`]);
+    /// they are sent to the API using their slug.
+    ///
     /// # Note
     ///
     /// This filter does not currently seem to work; whether this is the result of
     /// a bug in the Exercism v2 API or in this library remains to be determined.
-    #[builder(setter(into, each(name = "tag")))]
-    pub tags: Vec<&'a str>,
+    #[builder(setter(into, each(name = "tag", into)))]
+    pub tags: Vec<Tag>,
 
     /// Language track's [status filter](StatusFilter).
     ///
@@ -91,6 +95,8 @@ pub enum StatusFilter {
 
 /// Response to a query for language tracks on the [Exercism website](https://exercism.org) v2 API.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TracksResponse"))]
 pub struct Response {
     /// List of [Exercism](https://exercism.org) language tracks.
     ///
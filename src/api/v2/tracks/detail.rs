@@ -10,7 +10,13 @@ impl IntoQuery for Filters<'_> {
     fn into_query(self, request: RequestBuilder) -> RequestBuilder {
         request
             .build_query(("criteria", self.criteria))
-            .build_query(("tags[]", self.tags))
+            .build_query((
+                "tags[]",
+                self.tags
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            ))
             .build_query(("status", self.status))
     }
 }
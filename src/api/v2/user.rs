@@ -1,16 +1,15 @@
 //! Types related to users returned by the [Exercism website](https://exercism.org) v2 API.
 
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, IntoStaticStr};
+use strum::{AsRefStr, Display};
 
 /// Possible values for a user's "flair", which is a kind of special status.
 ///
 /// A user's "flair" is represented next to their name when displayed on the website, for example
 /// in mentoring sessions, on their public profile, etc. Not all users have a "flair".
-#[derive(
-    Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr, IntoStaticStr,
-)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", expecting = "invalid user flair: expected a string")]
 #[strum(serialize_all = "snake_case")]
 pub enum Flair {
     /// Reserved for [Exercism's leadership team](https://exercism.org/about/team#:~:text=help%20to%20others.-,Leadership%20Team,-Our%20leadership%20team).
@@ -41,6 +40,9 @@ pub enum Flair {
     /// Unknown flair.
     ///
     /// Included so that if new "flairs" are introduced in the website API later, this crate won't break (hopefully).
-    #[serde(skip_serializing, other)]
-    Unknown,
+    ///
+    /// Contains the value returned by the website API, which is serialized back unchanged.
+    #[serde(untagged, deserialize_with = "crate::api::detail::deserialize_unknown::<_, Flair>")]
+    #[strum(transparent)]
+    Unknown(String),
 }
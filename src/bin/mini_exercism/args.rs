@@ -0,0 +1,169 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use mini_exercism::api::v2::solution::{MentoringStatus, Status};
+use mini_exercism::api::v2::solutions::SortOrder;
+use mini_exercism::api::v2::tag::Tag;
+use mini_exercism::api::v2::tests;
+use mini_exercism::api::v2::tracks::StatusFilter;
+
+/// Command-line interface to the Exercism website APIs.
+///
+/// Credentials are read from the Exercism CLI config file, if present.
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Cli {
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+
+    /// Perform queries anonymously, even if CLI credentials are available
+    #[arg(long, global = true)]
+    pub anonymous: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Possible output formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable table
+    Table,
+
+    /// JSON, as returned by the API
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List language tracks
+    Tracks(TracksArgs),
+
+    /// List exercises of a language track
+    Exercises(ExercisesArgs),
+
+    /// List solutions submitted by the user (requires credentials)
+    Solutions(SolutionsArgs),
+
+    /// Show a solution (requires credentials)
+    Solution(SolutionArgs),
+
+    /// Show the files submitted for a solution (requires credentials)
+    Files(FilesArgs),
+
+    /// Check whether the Exercism website is up
+    Ping,
+
+    /// Check whether the CLI credentials are valid
+    #[command(alias = "whoami")]
+    ValidateToken,
+}
+
+#[derive(Debug, Args)]
+pub struct TracksArgs {
+    /// Only list tracks whose name or title contains this text
+    #[arg(long)]
+    pub criteria: Option<String>,
+
+    /// Only list tracks with this tag (slug like `paradigm/functional`, or title); can be repeated
+    #[arg(long = "tag")]
+    pub tags: Vec<Tag>,
+
+    /// Only list tracks with this status (`all`, `joined` or `unjoined`; requires credentials)
+    #[arg(long)]
+    pub status: Option<StatusFilter>,
+}
+
+#[derive(Debug, Args)]
+pub struct ExercisesArgs {
+    /// Name of the track, like `rust`
+    pub track: String,
+
+    /// Only list exercises whose title contains this text
+    #[arg(long)]
+    pub criteria: Option<String>,
+
+    /// Also list the user's solutions to the exercises (requires credentials)
+    #[arg(long)]
+    pub include_solutions: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct SolutionsArgs {
+    /// Only list solutions whose exercise or track title contains this text
+    #[arg(long)]
+    pub criteria: Option<String>,
+
+    /// Only list solutions in this track
+    #[arg(long)]
+    pub track: Option<String>,
+
+    /// Only list solutions with this status
+    #[arg(long, value_parser = non_empty::<Status>)]
+    pub status: Option<Status>,
+
+    /// Only list solutions with this mentoring status
+    #[arg(long, value_parser = non_empty::<MentoringStatus>)]
+    pub mentoring_status: Option<MentoringStatus>,
+
+    /// Only list out-of-date solutions
+    #[arg(long, conflicts_with = "up_to_date")]
+    pub out_of_date: bool,
+
+    /// Only list up-to-date solutions
+    #[arg(long)]
+    pub up_to_date: bool,
+
+    /// Only list solutions whose published iteration's tests have this status; can be repeated
+    #[arg(long = "tests-status", value_parser = non_empty::<tests::Status>)]
+    pub tests_statuses: Vec<tests::Status>,
+
+    /// Only list solutions whose published iteration's head tests have this status; can be repeated
+    #[arg(long = "head-tests-status", value_parser = non_empty::<tests::Status>)]
+    pub head_tests_statuses: Vec<tests::Status>,
+
+    /// Page to return (1-based)
+    #[arg(long)]
+    pub page: Option<i64>,
+
+    /// Number of solutions per page
+    #[arg(long, requires = "page")]
+    pub per_page: Option<i64>,
+
+    /// Sort order of solutions
+    #[arg(long)]
+    pub sort_order: Option<SortOrder>,
+}
+
+#[derive(Debug, Args)]
+pub struct SolutionArgs {
+    /// UUID of the solution
+    pub uuid: String,
+
+    /// Also list the solution's iterations
+    #[arg(long)]
+    pub include_iterations: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct FilesArgs {
+    /// UUID of the solution
+    pub solution_uuid: String,
+
+    /// UUID of the submission
+    pub submission_uuid: String,
+}
+
+// Statuses parse unknown values as-is, so empty values must be rejected explicitly.
+fn non_empty<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if value.is_empty() {
+        return Err("value cannot be empty".into());
+    }
+    value.parse().map_err(|err: T::Err| err.to_string())
+}
@@ -0,0 +1,126 @@
+//! Command-line interface to the [Exercism website](https://exercism.org) APIs.
+//!
+//! Built when the `bin` feature is enabled. Run `mini_exercism --help` for a list of commands.
+
+mod args;
+mod output;
+
+use clap::Parser;
+use mini_exercism::Error;
+use mini_exercism::api::{v1, v2};
+use mini_exercism::cli::get_cli_credentials;
+use mini_exercism::core::Credentials;
+
+use crate::args::{Cli, Command, ExercisesArgs, SolutionsArgs, TracksArgs};
+use crate::output::TokenValidity;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let credentials = if cli.anonymous { None } else { credentials()? };
+
+    let mut v1_builder = v1::Client::builder();
+    let mut v2_builder = v2::Client::builder();
+    if let Some(credentials) = credentials {
+        v1_builder.credentials(credentials.clone());
+        v2_builder.credentials(credentials);
+    }
+    let v1_client = v1_builder.build()?;
+    let v2_client = v2_builder.build()?;
+
+    match cli.command {
+        Command::Tracks(args) => {
+            let response = v2_client.get_tracks(Some(tracks_filters(&args))).await?;
+            output::print(&response, cli.format)
+        },
+        Command::Exercises(args) => {
+            let response = v2_client
+                .get_exercises(&args.track, Some(exercises_filters(&args)))
+                .await?;
+            output::print(&response, cli.format)
+        },
+        Command::Solutions(args) => {
+            let paging = args.page.map(|page| {
+                let paging = v2::solutions::Paging::for_page(page);
+                match args.per_page {
+                    Some(per_page) => paging.and_per_page(per_page),
+                    None => paging,
+                }
+            });
+            let response = v2_client
+                .get_solutions(Some(solutions_filters(&args)), paging, args.sort_order)
+                .await?;
+            output::print(&response, cli.format)
+        },
+        Command::Solution(args) => {
+            let response = v2_client
+                .get_solution(&args.uuid, args.include_iterations)
+                .await?;
+            output::print(&response, cli.format)
+        },
+        Command::Files(args) => {
+            let response = v2_client
+                .get_submission_files(&args.solution_uuid, &args.submission_uuid)
+                .await?;
+            output::print(&response, cli.format)
+        },
+        Command::Ping => output::print(&v1_client.ping().await?, cli.format),
+        Command::ValidateToken => {
+            let valid = v1_client.validate_token().await?;
+            output::print(&TokenValidity { valid }, cli.format)
+        },
+    }
+}
+
+/// Reads the Exercism CLI credentials, if the CLI is installed.
+fn credentials() -> anyhow::Result<Option<Credentials>> {
+    match get_cli_credentials() {
+        Ok(credentials) => Ok(Some(credentials)),
+        Err(Error::ConfigNotFound) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn tracks_filters(args: &TracksArgs) -> v2::tracks::Filters<'_> {
+    let mut builder = v2::tracks::Filters::builder();
+    if let Some(criteria) = &args.criteria {
+        builder.criteria(criteria.as_str());
+    }
+    if let Some(status) = args.status {
+        builder.status(status);
+    }
+    builder.tags(args.tags.clone()).build()
+}
+
+fn exercises_filters(args: &ExercisesArgs) -> v2::exercises::Filters<'_> {
+    let mut builder = v2::exercises::Filters::builder();
+    if let Some(criteria) = &args.criteria {
+        builder.criteria(criteria.as_str());
+    }
+    builder.include_solutions(args.include_solutions).build()
+}
+
+fn solutions_filters(args: &SolutionsArgs) -> v2::solutions::Filters<'_> {
+    let mut builder = v2::solutions::Filters::builder();
+    if let Some(criteria) = &args.criteria {
+        builder.criteria(criteria.as_str());
+    }
+    if let Some(track) = &args.track {
+        builder.track(track.as_str());
+    }
+    if let Some(status) = &args.status {
+        builder.status(status.clone());
+    }
+    if let Some(mentoring_status) = &args.mentoring_status {
+        builder.mentoring_status(mentoring_status.clone());
+    }
+    if args.out_of_date {
+        builder.out_of_date();
+    } else if args.up_to_date {
+        builder.up_to_date();
+    }
+    builder
+        .published_iteration_tests_statuses(args.tests_statuses.clone())
+        .published_iteration_head_tests_statuses(args.head_tests_statuses.clone())
+        .build()
+}
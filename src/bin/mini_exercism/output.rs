@@ -0,0 +1,266 @@
+use std::fmt;
+
+use mini_exercism::api::{v1, v2};
+use serde::Serialize;
+
+use crate::args::Format;
+
+/// Prints a response using the given output format.
+pub fn print<T>(value: &T, format: Format) -> anyhow::Result<()>
+where
+    T: Serialize + ToTable,
+{
+    match format {
+        Format::Table => print!("{}", value.to_table()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(value)?),
+    }
+
+    Ok(())
+}
+
+/// Simple text table, displayed with aligned columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Self { headers, rows: Vec::new() }
+    }
+
+    pub fn push_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        let write_row = |f: &mut fmt::Formatter<'_>, cells: &mut dyn Iterator<Item = &str>| {
+            let line = cells
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &mut self.headers.iter().copied())?;
+        write_row(
+            f,
+            &mut widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>()
+                .iter()
+                .map(String::as_str),
+        )?;
+        for row in &self.rows {
+            write_row(f, &mut row.iter().map(String::as_str))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Trait implemented by responses that can be displayed as a [`Table`].
+pub trait ToTable {
+    fn to_table(&self) -> Table;
+}
+
+impl ToTable for v2::tracks::Response {
+    fn to_table(&self) -> Table {
+        let mut table = Table::new(vec!["NAME", "TITLE", "EXERCISES", "JOINED", "COMPLETED"]);
+        for track in &self.tracks {
+            table.push_row(vec![
+                track.name.to_string(),
+                track.title.clone(),
+                track.num_exercises.to_string(),
+                yes_no(track.is_joined),
+                track.num_completed_exercises.to_string(),
+            ]);
+        }
+        table
+    }
+}
+
+impl ToTable for v2::exercises::Response {
+    fn to_table(&self) -> Table {
+        let mut table = Table::new(vec!["NAME", "TITLE", "TYPE", "DIFFICULTY", "SOLUTION"]);
+        for exercise in &self.exercises {
+            let solution = self
+                .solutions
+                .iter()
+                .find(|solution| solution.exercise.name == exercise.name);
+            table.push_row(vec![
+                exercise.name.to_string(),
+                exercise.title.clone(),
+                exercise.exercise_type.to_string(),
+                exercise.difficulty.to_string(),
+                solution
+                    .map(|solution| solution.status.to_string())
+                    .unwrap_or_default(),
+            ]);
+        }
+        table
+    }
+}
+
+impl ToTable for v2::solutions::Response {
+    fn to_table(&self) -> Table {
+        let mut table = solutions_table();
+        for solution in &self.results {
+            push_solution_row(&mut table, solution);
+        }
+        table.push_row(vec![format!(
+            "page {}/{} ({} solutions)",
+            self.meta.current_page, self.meta.total_pages, self.meta.total_count
+        )]);
+        table
+    }
+}
+
+impl ToTable for v2::solution::Response {
+    fn to_table(&self) -> Table {
+        let mut table = solutions_table();
+        push_solution_row(&mut table, &self.solution);
+
+        if !self.iterations.is_empty() {
+            table.push_row(Vec::new());
+            table.push_row(vec![
+                "ITERATION".into(),
+                "STATUS".into(),
+                "TESTS".into(),
+                "SUBMITTED".into(),
+            ]);
+            for iteration in &self.iterations {
+                table.push_row(vec![
+                    format!("#{}", iteration.index),
+                    iteration.status.to_string(),
+                    iteration.tests_status.to_string(),
+                    iteration.created_at.clone(),
+                ]);
+            }
+        }
+        table
+    }
+}
+
+impl ToTable for v2::submission::files::Response {
+    fn to_table(&self) -> Table {
+        let mut table = Table::new(vec!["FILE", "LINES", "DIGEST"]);
+        for file in &self.files {
+            table.push_row(vec![
+                file.filename.clone(),
+                file.content.lines().count().to_string(),
+                file.digest.clone(),
+            ]);
+        }
+        table
+    }
+}
+
+impl ToTable for v1::ping::Response {
+    fn to_table(&self) -> Table {
+        let mut table = Table::new(vec!["SERVICE", "UP"]);
+        table.push_row(vec!["website".into(), yes_no(self.status.website)]);
+        table.push_row(vec!["database".into(), yes_no(self.status.database)]);
+        table
+    }
+}
+
+/// Result of the `validate-token` command.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenValidity {
+    pub valid: bool,
+}
+
+impl ToTable for TokenValidity {
+    fn to_table(&self) -> Table {
+        let mut table = Table::new(vec!["VALID TOKEN"]);
+        table.push_row(vec![yes_no(self.valid)]);
+        table
+    }
+}
+
+fn solutions_table() -> Table {
+    Table::new(vec![
+        "UUID",
+        "TRACK",
+        "EXERCISE",
+        "STATUS",
+        "MENTORING",
+        "ITERATIONS",
+        "OUT OF DATE",
+    ])
+}
+
+fn push_solution_row(table: &mut Table, solution: &v2::solution::Solution) {
+    table.push_row(vec![
+        solution.uuid.to_string(),
+        solution.track.name.to_string(),
+        solution.exercise.name.to_string(),
+        solution.status.to_string(),
+        solution.mentoring_status.to_string(),
+        solution.num_iterations.to_string(),
+        yes_no(solution.is_out_of_date),
+    ]);
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.into()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut table = Table::new(vec!["NAME", "TITLE"]);
+        table.push_row(vec!["common-lisp".into(), "Common Lisp".into()]);
+        table.push_row(vec!["go".into(), "Go".into()]);
+
+        let expected = "\
+NAME         TITLE
+-----------  -----------
+common-lisp  Common Lisp
+go           Go
+";
+        assert_eq!(expected, table.to_string());
+    }
+
+    #[test]
+    fn test_ping() {
+        let response = v1::ping::Response {
+            status: v1::ping::ServiceStatus { website: true, database: false },
+        };
+
+        let expected = "\
+SERVICE   UP
+--------  ---
+website   yes
+database  no
+";
+        assert_eq!(expected, response.to_table().to_string());
+    }
+}
@@ -1,6 +1,12 @@
 //! Core types used across the [mini_exercism](crate) library.
 
+pub mod credentials;
+
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+
 use derive_builder::UninitializedFieldError;
+use secrecy::{ExposeSecret, SecretString};
 use thiserror::Error;
 
 use crate::http;
@@ -17,23 +23,53 @@ use crate::http;
 ///
 /// assert_eq!(credentials.api_token(), api_token);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// # Notes
+///
+/// The API token is stored as a secret: it is redacted when the credentials are
+/// [debug-formatted](Debug) (for example when traced) and its memory is zeroed when
+/// the credentials are dropped.
+///
+/// ```
+/// use mini_exercism::core::Credentials;
+///
+/// let credentials = Credentials::from_api_token("some_token");
+///
+/// assert!(!format!("{credentials:?}").contains("some_token"));
+/// ```
+#[derive(Clone)]
 pub struct Credentials {
-    api_token: String,
+    api_token: SecretString,
 }
 
 impl Credentials {
     /// Creates a new [Exercism](https://exercism.org) credentials wrapper from the given API token.
     pub fn from_api_token<T: Into<String>>(api_token: T) -> Self {
-        Self { api_token: api_token.into() }
+        Self { api_token: api_token.into().into() }
     }
 
     /// Accesses the [Exercism](https://exercism.org) API token.
     pub fn api_token(&self) -> &str {
-        self.api_token.as_str()
+        self.api_token.expose_secret()
     }
 }
 
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_token", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl PartialEq for Credentials {
+    fn eq(&self, other: &Self) -> bool {
+        self.api_token() == other.api_token()
+    }
+}
+
+impl Eq for Credentials {}
+
 /// Result type used by the [mini_exercism](crate) library when an error can occur.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
@@ -63,6 +99,130 @@ pub enum Error {
     #[error("Exercism CLI config file did not contain an API token")]
     ApiTokenNotFoundInConfig,
 
+    /// None of the sources of a [`CredentialsChain`](credentials::CredentialsChain) provided credentials
+    #[error("no credentials found in any of the configured sources")]
+    CredentialsNotFound,
+
+    /// I/O error reading an API token file (see [`CredentialsProvider::File`](credentials::CredentialsProvider::File))
+    #[error("could not read API token file {path:?}: {source:?}")]
+    TokenFileReadError {
+        /// Path of the API token file.
+        path: PathBuf,
+
+        /// I/O error that occurred while reading the file.
+        source: std::io::Error,
+    },
+
+    /// Data requested from an [offline](crate::api::v2::ClientBuilder::offline) client was never
+    /// synced to its [`Snapshot`](crate::api::v2::offline::Snapshot)
+    #[error("{0} was never synced to the offline snapshot")]
+    NotSynced(String),
+
+    /// Operation is not supported by an [offline](crate::api::v2::ClientBuilder::offline) client,
+    /// because it cannot be answered from its [`Snapshot`](crate::api::v2::offline::Snapshot)
+    #[error("{0} is not supported in offline mode")]
+    OfflineUnsupported(&'static str),
+
+    /// I/O error reading or writing an offline [`Snapshot`](crate::api::v2::offline::Snapshot) file
+    #[error("could not access offline snapshot file {path:?}: {source:?}")]
+    SnapshotIoError {
+        /// Path of the snapshot file.
+        path: PathBuf,
+
+        /// I/O error that occurred while accessing the file.
+        source: std::io::Error,
+    },
+
+    /// JSON error reading or writing an offline [`Snapshot`](crate::api::v2::offline::Snapshot) file
+    #[error("failed to parse or serialize offline snapshot: {0:?}")]
+    SnapshotFormatError(serde_json::Error),
+
+    /// I/O error reading or writing a track tooling file (like a [representer](crate::tooling::representer) output file)
+    #[error("could not access tooling file {path:?}: {source:?}")]
+    ToolingIoError {
+        /// Path of the tooling file.
+        path: PathBuf,
+
+        /// I/O error that occurred while accessing the file.
+        source: std::io::Error,
+    },
+
+    /// JSON error reading or writing a track tooling file (like a [representer](crate::tooling::representer) output file)
+    #[error("failed to parse or serialize tooling file {path:?}: {source:?}")]
+    ToolingFormatError {
+        /// Path of the tooling file.
+        path: PathBuf,
+
+        /// JSON error that occurred while parsing or serializing the file.
+        source: serde_json::Error,
+    },
+
+    /// I/O error reading a file in a language track repository or downloaded exercise (like an [exercise config](crate::track_repo::exercise_config))
+    #[error("could not access track file {path:?}: {source:?}")]
+    TrackRepoIoError {
+        /// Path of the file.
+        path: PathBuf,
+
+        /// I/O error that occurred while accessing the file.
+        source: std::io::Error,
+    },
+
+    /// JSON error parsing a file in a language track repository or downloaded exercise (like an [exercise config](crate::track_repo::exercise_config))
+    #[error("failed to parse track file {path:?}: {source:?}")]
+    TrackRepoFormatError {
+        /// Path of the file.
+        path: PathBuf,
+
+        /// JSON error that occurred while parsing the file.
+        source: serde_json::Error,
+    },
+
+    /// TOML error parsing a file in a language track repository (like an exercise's [`tests.toml`](crate::track_repo::canonical_data::TestsToml))
+    #[error("failed to parse track file {path:?}: {source:?}")]
+    TrackRepoTomlError {
+        /// Path of the file.
+        path: PathBuf,
+
+        /// TOML error that occurred while parsing the file.
+        source: toml::de::Error,
+    },
+
+    /// The content of a [submission file](crate::api::v2::submission::files::File) does not match its digest
+    #[error(
+        "content of submission file {filename} does not match its digest (expected {expected}, got {actual})"
+    )]
+    DigestMismatch {
+        /// Name of the submission file.
+        filename: String,
+
+        /// Digest returned by the API.
+        expected: String,
+
+        /// Digest computed from the file's content.
+        actual: String,
+    },
+
+    /// Files of a deleted [`Iteration`](crate::api::v2::iteration::Iteration) were requested
+    /// (see [`Client::diff_iterations`](crate::api::v2::Client::diff_iterations))
+    #[error("iteration {0} was deleted and has no submitted files")]
+    IterationDeleted(i32),
+
+    /// An identifier (like a [`SolutionUuid`](crate::api::id::SolutionUuid)) is not in the
+    /// expected format
+    #[error("invalid {kind}: {value:?}")]
+    InvalidIdentifier {
+        /// Kind of identifier, like `solution UUID`.
+        kind: &'static str,
+
+        /// Invalid value.
+        value: String,
+    },
+
+    /// Path of a request to an arbitrary endpoint does not start with a `/` (see
+    /// [`v2::Client::request`](crate::api::v2::Client::request))
+    #[error("request path {0:?} does not start with `/`")]
+    InvalidPath(String),
+
     /// A call to a builder's `build` method failed
     #[error(transparent)]
     BuildFailed(#[from] BuildError),
@@ -71,6 +231,16 @@ pub enum Error {
     #[error("error while performing API request: {0:?}")]
     ApiError(#[from] http::Error),
 
+    /// Response returned by an [Exercism](https://exercism.org) API could not be deserialized
+    ///
+    /// # Notes
+    ///
+    /// Before this variant was introduced, such errors were reported as [`ApiError`](Self::ApiError).
+    /// Code matching on [`ApiError`](Self::ApiError) to detect invalid responses must now match
+    /// on this variant instead.
+    #[error("failed to parse API response: {0:?}")]
+    ApiResponseFormatError(serde_json::Error),
+
     /// Error encountered while performing a request to an [Exercism](https://exercism.org) API
     /// which persisted even after retried have been exhausted
     #[error("error while performing API request with retries: {0:?}")]
@@ -113,6 +283,25 @@ mod tests {
 
     use super::*;
 
+    mod credentials {
+        use super::*;
+
+        #[test]
+        fn test_debug_redacts_api_token() {
+            let credentials = Credentials::from_api_token("some_secret_token");
+
+            let debug = format!("{credentials:?}");
+            assert!(!debug.contains("some_secret_token"));
+            assert!(debug.contains("[REDACTED]"));
+        }
+
+        #[test]
+        fn test_eq() {
+            assert_eq!(Credentials::from_api_token("token"), Credentials::from_api_token("token"));
+            assert_ne!(Credentials::from_api_token("token"), Credentials::from_api_token("other"));
+        }
+    }
+
     mod error {
         use super::*;
 
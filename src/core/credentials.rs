@@ -0,0 +1,438 @@
+//! Sources of [`Credentials`] and a chain to resolve them.
+//!
+//! When the same program runs in different environments (for example, on CI and on developer
+//! machines), credentials are often found in different places. A [`CredentialsChain`] lists
+//! the possible sources of credentials in order; [resolving](CredentialsChain::resolve) it
+//! returns the credentials of the first source that provides some, along with that
+//! [source](CredentialsSource).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::core::credentials::CredentialsChain;
+//!
+//! fn get_api_client() -> anyhow::Result<api::v2::Client> {
+//!     let resolved = CredentialsChain::new()
+//!         .env_var("MY_EXERCISM_TOKEN")
+//!         .file("/etc/exercism/token")
+//!         .resolve()?;
+//!     println!("Using credentials from {}", resolved.source);
+//!
+//!     Ok(api::v2::Client::builder()
+//!         .credentials(resolved.credentials)
+//!         .build()?)
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+use crate::core::{Credentials, Error, Result};
+
+/// Name of the environment variable used to fetch an API token by [`CredentialsChain::standard`].
+pub const DEFAULT_API_TOKEN_ENV_VAR: &str = "EXERCISM_TOKEN";
+
+/// A source of [`Credentials`] that can be part of a [`CredentialsChain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CredentialsProvider {
+    /// Credentials passed explicitly.
+    Explicit(Credentials),
+
+    /// API token read from the environment variable with the given name.
+    ///
+    /// If the environment variable is not set or is blank, no credentials are provided.
+    Environment(String),
+
+    /// API token read from the file at the given path.
+    ///
+    /// Leading and trailing whitespace is ignored. If the file does not exist or is blank,
+    /// no credentials are provided.
+    File(PathBuf),
+
+    /// Credentials used by the [Exercism CLI application](https://exercism.org/docs/using/solving-exercises/working-locally)
+    /// (see [`get_cli_credentials`](crate::cli::get_cli_credentials)).
+    ///
+    /// If the CLI is not installed or configured, no credentials are provided.
+    #[cfg(feature = "cli")]
+    Cli,
+}
+
+impl CredentialsProvider {
+    /// Returns the [`CredentialsSource`] corresponding to this provider.
+    pub fn source(&self) -> CredentialsSource {
+        match self {
+            Self::Explicit(_) => CredentialsSource::Explicit,
+            Self::Environment(name) => CredentialsSource::Environment(name.clone()),
+            Self::File(path) => CredentialsSource::File(path.clone()),
+            #[cfg(feature = "cli")]
+            Self::Cli => CredentialsSource::Cli,
+        }
+    }
+
+    /// Fetches [`Credentials`] from this provider.
+    ///
+    /// Returns `None` if the provider's source does not contain any credentials.
+    ///
+    /// # Errors
+    ///
+    /// - [`TokenFileReadError`]: I/O error (other than "not found") reading an API token file
+    /// - Any error returned by [`get_cli_credentials`](crate::cli::get_cli_credentials), except
+    ///   [`ConfigNotFound`](Error::ConfigNotFound) and [`ApiTokenNotFoundInConfig`](Error::ApiTokenNotFoundInConfig)
+    ///
+    /// [`TokenFileReadError`]: Error::TokenFileReadError
+    #[cfg_attr(not(coverage), tracing::instrument(ret, err, level = "trace"))]
+    pub fn provide(&self) -> Result<Option<Credentials>> {
+        match self {
+            Self::Explicit(credentials) => Ok(Some(credentials.clone())),
+            Self::Environment(name) => Ok(env::var(name)
+                .ok()
+                .as_deref()
+                .and_then(Self::from_token_value)),
+            Self::File(path) => match fs::read_to_string(path) {
+                Ok(token) => Ok(Self::from_token_value(&token)),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(Error::TokenFileReadError { path: path.clone(), source: err }),
+            },
+            #[cfg(feature = "cli")]
+            Self::Cli => match crate::cli::get_cli_credentials() {
+                Ok(credentials) => Ok(Some(credentials)),
+                Err(Error::ConfigNotFound | Error::ApiTokenNotFoundInConfig) => Ok(None),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    fn from_token_value(value: &str) -> Option<Credentials> {
+        let token = value.trim();
+        (!token.is_empty()).then(|| Credentials::from_api_token(token))
+    }
+}
+
+/// Source from which [`Credentials`] were obtained by a [`CredentialsChain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CredentialsSource {
+    /// Credentials were passed explicitly.
+    Explicit,
+
+    /// Credentials were read from the environment variable with the given name.
+    Environment(String),
+
+    /// Credentials were read from the API token file at the given path.
+    File(PathBuf),
+
+    /// Credentials were read from the [Exercism CLI application](https://exercism.org/docs/using/solving-exercises/working-locally)'s
+    /// config file.
+    #[cfg(feature = "cli")]
+    Cli,
+}
+
+impl Display for CredentialsSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Explicit => write!(f, "explicit API token"),
+            Self::Environment(name) => write!(f, "environment variable {name}"),
+            Self::File(path) => write!(f, "API token file {}", path.display()),
+            #[cfg(feature = "cli")]
+            Self::Cli => write!(f, "Exercism CLI config"),
+        }
+    }
+}
+
+/// [`Credentials`] resolved by a [`CredentialsChain`], along with their source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedCredentials {
+    /// Credentials that were found.
+    pub credentials: Credentials,
+
+    /// Source that provided the [`credentials`](Self::credentials).
+    pub source: CredentialsSource,
+}
+
+/// Ordered list of [`CredentialsProvider`]s used to look for [`Credentials`].
+///
+/// # Examples
+///
+/// ```
+/// use mini_exercism::core::Credentials;
+/// use mini_exercism::core::credentials::{CredentialsChain, CredentialsSource};
+///
+/// let resolved = CredentialsChain::new()
+///     .env_var("SOME_ENV_VAR_THAT_IS_NOT_SET")
+///     .explicit(Credentials::from_api_token("some_token"))
+///     .resolve()
+///     .unwrap();
+///
+/// assert_eq!(resolved.credentials.api_token(), "some_token");
+/// assert_eq!(resolved.source, CredentialsSource::Explicit);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CredentialsChain {
+    providers: Vec<CredentialsProvider>,
+}
+
+impl CredentialsChain {
+    /// Creates a new, empty [`CredentialsChain`].
+    #[cfg_attr(not(coverage), tracing::instrument(level = "trace"))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`CredentialsChain`] looking for credentials in the standard places.
+    ///
+    /// The chain checks, in order:
+    ///
+    /// 1. The [`EXERCISM_TOKEN`](DEFAULT_API_TOKEN_ENV_VAR) environment variable
+    /// 2. The [Exercism CLI application](https://exercism.org/docs/using/solving-exercises/working-locally)'s
+    ///    config file (only if the `cli` feature is enabled)
+    #[cfg_attr(not(coverage), tracing::instrument(level = "trace"))]
+    pub fn standard() -> Self {
+        let mut chain = Self::new();
+        chain.env_var(DEFAULT_API_TOKEN_ENV_VAR);
+        #[cfg(feature = "cli")]
+        chain.cli();
+        chain
+    }
+
+    /// Adds a [`CredentialsProvider`] at the end of the chain.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn provider(&mut self, provider: CredentialsProvider) -> &mut Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Adds explicit [`Credentials`] at the end of the chain.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn explicit(&mut self, credentials: Credentials) -> &mut Self {
+        self.provider(CredentialsProvider::Explicit(credentials))
+    }
+
+    /// Adds an environment variable containing an API token at the end of the chain.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn env_var<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String> + std::fmt::Debug,
+    {
+        self.provider(CredentialsProvider::Environment(name.into()))
+    }
+
+    /// Adds a file containing an API token at the end of the chain.
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn file<P>(&mut self, path: P) -> &mut Self
+    where
+        P: Into<PathBuf> + std::fmt::Debug,
+    {
+        self.provider(CredentialsProvider::File(path.into()))
+    }
+
+    /// Adds the [Exercism CLI application](https://exercism.org/docs/using/solving-exercises/working-locally)'s
+    /// credentials at the end of the chain.
+    #[cfg(feature = "cli")]
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), level = "trace"))]
+    pub fn cli(&mut self) -> &mut Self {
+        self.provider(CredentialsProvider::Cli)
+    }
+
+    /// Returns the providers in this chain, in order.
+    pub fn providers(&self) -> &[CredentialsProvider] {
+        &self.providers
+    }
+
+    /// Looks for [`Credentials`] in each provider of the chain, in order, and returns
+    /// the first ones found.
+    ///
+    /// # Errors
+    ///
+    /// - [`CredentialsNotFound`]: none of the providers returned credentials
+    /// - Any error returned by [`CredentialsProvider::provide`]
+    ///
+    /// [`CredentialsNotFound`]: Error::CredentialsNotFound
+    #[cfg_attr(not(coverage), tracing::instrument(skip(self), err, level = "debug"))]
+    pub fn resolve(&self) -> Result<ResolvedCredentials> {
+        for provider in &self.providers {
+            if let Some(credentials) = provider.provide()? {
+                let source = provider.source();
+                tracing::debug!(%source, "resolved credentials");
+                return Ok(ResolvedCredentials { credentials, source });
+            }
+        }
+
+        Err(Error::CredentialsNotFound)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::io::Write;
+
+    use assert_matches::assert_matches;
+    use serial_test::serial;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    const TEST_ENV_VAR: &str = "MINI_EXERCISM_TEST_CREDENTIALS_TOKEN";
+
+    fn token_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    mod credentials_provider {
+        use super::*;
+
+        #[test]
+        fn test_explicit() {
+            let provider = CredentialsProvider::Explicit(Credentials::from_api_token("some_token"));
+
+            assert_matches!(provider.provide(), Ok(Some(creds)) if creds.api_token() == "some_token");
+            assert_eq!(provider.source(), CredentialsSource::Explicit);
+        }
+
+        #[test]
+        #[serial(credentials_env)]
+        fn test_environment() {
+            unsafe {
+                env::set_var(TEST_ENV_VAR, " some_token\n");
+            }
+            let provider = CredentialsProvider::Environment(TEST_ENV_VAR.into());
+
+            assert_matches!(provider.provide(), Ok(Some(creds)) if creds.api_token() == "some_token");
+            assert_eq!(provider.source(), CredentialsSource::Environment(TEST_ENV_VAR.into()));
+        }
+
+        #[test]
+        #[serial(credentials_env)]
+        fn test_environment_not_set() {
+            unsafe {
+                env::remove_var(TEST_ENV_VAR);
+            }
+            let provider = CredentialsProvider::Environment(TEST_ENV_VAR.into());
+
+            assert_matches!(provider.provide(), Ok(None));
+        }
+
+        #[test]
+        #[serial(credentials_env)]
+        fn test_environment_blank() {
+            unsafe {
+                env::set_var(TEST_ENV_VAR, "   ");
+            }
+            let provider = CredentialsProvider::Environment(TEST_ENV_VAR.into());
+
+            assert_matches!(provider.provide(), Ok(None));
+        }
+
+        #[test]
+        fn test_file() {
+            let file = token_file("some_token\n");
+            let provider = CredentialsProvider::File(file.path().into());
+
+            assert_matches!(provider.provide(), Ok(Some(creds)) if creds.api_token() == "some_token");
+            assert_eq!(provider.source(), CredentialsSource::File(file.path().into()));
+        }
+
+        #[test]
+        fn test_file_blank() {
+            let file = token_file("\n");
+            let provider = CredentialsProvider::File(file.path().into());
+
+            assert_matches!(provider.provide(), Ok(None));
+        }
+
+        #[test]
+        fn test_file_not_found() {
+            let dir = tempfile::tempdir().unwrap();
+            let provider = CredentialsProvider::File(dir.path().join("token"));
+
+            assert_matches!(provider.provide(), Ok(None));
+        }
+
+        #[test]
+        fn test_file_read_error() {
+            // Reading a directory as a file fails with an error other than "not found".
+            let dir = tempfile::tempdir().unwrap();
+            let provider = CredentialsProvider::File(dir.path().into());
+
+            assert_matches!(provider.provide(), Err(Error::TokenFileReadError { path, .. }) => {
+                assert_eq!(path, dir.path());
+            });
+        }
+    }
+
+    mod credentials_chain {
+        use super::*;
+
+        #[test]
+        #[serial(credentials_env)]
+        fn test_first_found() {
+            unsafe {
+                env::remove_var(TEST_ENV_VAR);
+            }
+            let file = token_file("file_token");
+
+            let resolved = CredentialsChain::new()
+                .env_var(TEST_ENV_VAR)
+                .file(file.path())
+                .explicit(Credentials::from_api_token("explicit_token"))
+                .resolve();
+
+            assert_matches!(resolved, Ok(ResolvedCredentials { credentials, source }) => {
+                assert_eq!(credentials.api_token(), "file_token");
+                assert_eq!(source, CredentialsSource::File(file.path().into()));
+            });
+        }
+
+        #[test]
+        fn test_error_stops_chain() {
+            let dir = tempfile::tempdir().unwrap();
+
+            let resolved = CredentialsChain::new()
+                .file(dir.path())
+                .explicit(Credentials::from_api_token("explicit_token"))
+                .resolve();
+
+            assert_matches!(resolved, Err(Error::TokenFileReadError { .. }));
+        }
+
+        #[test]
+        fn test_not_found() {
+            assert_matches!(CredentialsChain::new().resolve(), Err(Error::CredentialsNotFound));
+        }
+
+        #[test]
+        fn test_standard() {
+            let chain = CredentialsChain::standard();
+
+            assert_eq!(
+                chain.providers().first(),
+                Some(&CredentialsProvider::Environment(DEFAULT_API_TOKEN_ENV_VAR.into()))
+            );
+            #[cfg(feature = "cli")]
+            assert_eq!(chain.providers().last(), Some(&CredentialsProvider::Cli));
+        }
+    }
+
+    mod credentials_source {
+        use super::*;
+
+        #[test]
+        fn test_display() {
+            assert_eq!(CredentialsSource::Explicit.to_string(), "explicit API token");
+            assert_eq!(
+                CredentialsSource::Environment("EXERCISM_TOKEN".into()).to_string(),
+                "environment variable EXERCISM_TOKEN"
+            );
+            assert!(
+                CredentialsSource::File("token".into())
+                    .to_string()
+                    .starts_with("API token file")
+            );
+        }
+    }
+}
@@ -8,6 +8,7 @@
 //! - [`Async methods`](#async-methods)
 //! - [`Example`](#example)
 //! - [`Credentials`](#credentials)
+//! - [`Credentials chain`](#credentials-chain)
 //! - [`CLI credentials`](#cli-credentials)
 //! - [`Custom HTTP client`](#custom-http-client)
 //! - [`Crate status`](#crate-status)
@@ -144,6 +145,29 @@
 //! }
 //! ```
 //!
+//! ## Credentials chain
+//!
+//! When a program can run in different environments (CI, developer machines, etc.), credentials
+//! might be found in different places. A [`CredentialsChain`] can look for them in order, for
+//! example in an environment variable, in a token file or in the [Exercism CLI application](https://exercism.org/docs/using/solving-exercises/working-locally)'s
+//! config, and reports which source was used:
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::core::credentials::CredentialsChain;
+//!
+//! fn get_api_client() -> anyhow::Result<api::v2::Client> {
+//!     // Looks in the `EXERCISM_TOKEN` environment variable, then in the CLI config
+//!     // (if the `cli` feature is enabled).
+//!     let resolved = CredentialsChain::standard().resolve()?;
+//!     println!("Using credentials from {}", resolved.source);
+//!
+//!     Ok(api::v2::Client::builder()
+//!         .credentials(resolved.credentials)
+//!         .build()?)
+//! }
+//! ```
+//!
 //! ## CLI credentials
 //!
 //! This crate provides a helper function to fetch the [`Credentials`] used by the currently-installed
@@ -175,6 +199,10 @@
 //! }
 //! ```
 //!
+//! The `bin` feature (which implies `cli`) also builds a `mini_exercism` command-line binary that
+//! performs common queries using the CLI credentials. Run `mini_exercism --help` for a list of
+//! commands.
+//!
 //! ## Custom HTTP client
 //!
 //! Internally, [mini_exercism](crate) uses the [reqwest](https://crates.io/crates/reqwest)
@@ -241,6 +269,18 @@
 //! }
 //! ```
 //!
+//! ## JSON Schemas
+//!
+//! When the `schema` feature is enabled, all response models implement
+//! [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) and the
+//! `api::schema` module can generate an [OpenAPI](https://www.openapis.org) document describing
+//! the endpoints called by the API clients (for example, to generate TypeScript clients):
+//!
+//! ```toml
+//! [dependencies]
+//! mini_exercism = { version = "8.1.0", features = ["schema"] }
+//! ```
+//!
 //! ## Crate status
 //!
 //! Currently, this crate is a bit minimalistic and does not implement all the [Exercism](https://exercism.org)
@@ -252,6 +292,7 @@
 //! [mini_exercism](crate) currently builds on Rust 1.88 or newer.
 //!
 //! [`Credentials`]: core::Credentials
+//! [`CredentialsChain`]: core::credentials::CredentialsChain
 
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
@@ -282,6 +323,8 @@ pub mod api;
 #[cfg(feature = "cli")]
 pub mod cli;
 pub mod core;
+pub mod tooling;
+pub mod track_repo;
 
 pub use crate::core::Error;
 pub use crate::core::Result;
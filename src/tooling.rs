@@ -0,0 +1,8 @@
+//! Types used to work with the output of [Exercism](https://exercism.org) track tooling.
+//!
+//! Track tooling (test runners, analyzers, representers) runs on every submission and produces
+//! files following interfaces documented [here](https://exercism.org/docs/building/tooling).
+
+pub mod analyzer;
+pub mod representer;
+pub mod test_runner;
@@ -0,0 +1,286 @@
+//! Types used to parse and produce the output of an [Exercism](https://exercism.org) track [analyzer](https://exercism.org/docs/building/tooling/analyzers).
+//!
+//! Analyzers write their output to an `analysis.json` file, whose format is documented
+//! [here](https://exercism.org/docs/building/tooling/analyzers/interface). Comments in this file
+//! do not contain text directly: they refer to comment templates stored in the
+//! [website-copy](https://github.com/exercism/website-copy/tree/main/analyzer-comments) repository.
+//! By supplying these templates (see [`CommentTemplates`]), an [`Analysis`] can be converted to
+//! the [`AnalyzerFeedback`] that the website would display.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use mini_exercism::api::v2::submission::analysis::AnalyzerCommentType;
+//! use mini_exercism::tooling::analyzer::{Analysis, Comment};
+//!
+//! let mut analysis = Analysis::default();
+//! analysis.comments.push(
+//!     Comment::new("rust.general.use_iterators")
+//!         .with_param("name", "sum")
+//!         .with_type(AnalyzerCommentType::Actionable),
+//! );
+//! assert!(analysis.validate().is_ok());
+//!
+//! let templates = HashMap::from([(
+//!     "rust.general.use_iterators".to_string(),
+//!     "Consider using an iterator in `%{name}`.".to_string(),
+//! )]);
+//! let feedback = analysis.to_feedback(&templates).unwrap();
+//! assert_eq!(
+//!     "<p>Consider using an iterator in <code>sum</code>.</p>\n",
+//!     feedback.comments[0].html
+//! );
+//! ```
+
+pub(crate) mod detail;
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::v2::submission::analysis::{
+    AnalyzerComment, AnalyzerCommentType, AnalyzerFeedback,
+};
+
+/// Contents of an analyzer's `analysis.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Analysis {
+    /// Summary of the analysis, displayed above the comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Comments produced by the analyzer.
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+}
+
+impl Analysis {
+    /// Validates the analysis against the analyzer interface.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`ValidationError`] found in the analysis.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let errors: Vec<_> = self.comments.iter().flat_map(Comment::validate).collect();
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Converts the analysis to the [`AnalyzerFeedback`] displayed by the [Exercism website](https://exercism.org).
+    ///
+    /// Each comment's template is fetched from `templates`, its parameters are interpolated and
+    /// the resulting Markdown is rendered to HTML. Like on the website, comments are sorted by
+    /// [type](AnalyzerCommentType) (essential comments first); comments without a type are
+    /// considered [informative](AnalyzerCommentType::Informative).
+    ///
+    /// # Errors
+    ///
+    /// - [`MissingTemplate`](ValidationError::MissingTemplate): No template was found for a comment
+    /// - [`MissingParam`](ValidationError::MissingParam): A template refers to a parameter that
+    ///   the comment does not provide
+    pub fn to_feedback<T>(&self, templates: &T) -> Result<AnalyzerFeedback, ValidationError>
+    where
+        T: CommentTemplates + ?Sized,
+    {
+        let mut comments = self
+            .comments
+            .iter()
+            .map(|comment| comment.to_analyzer_comment(templates))
+            .collect::<Result<Vec<_>, _>>()?;
+        comments.sort_by(|a, b| a.comment_type.cmp(&b.comment_type));
+
+        Ok(AnalyzerFeedback { summary: self.summary.clone(), comments })
+    }
+}
+
+impl FromStr for Analysis {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// A comment in an analyzer's `analysis.json` file.
+///
+/// In the file, a comment can be written either as an object or, if it has no
+/// parameters or type, as a simple string containing its identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "detail::CommentRepr")]
+pub struct Comment {
+    /// Identifier of the comment, like `ruby.two-fer.string_interpolation`.
+    ///
+    /// Identifies the comment's template in the [website-copy](https://github.com/exercism/website-copy/tree/main/analyzer-comments)
+    /// repository.
+    pub comment: String,
+
+    /// Parameters interpolated in the comment's template.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, serde_json::Value>,
+
+    /// Type of comment.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub comment_type: Option<AnalyzerCommentType>,
+}
+
+impl Comment {
+    /// Creates a new comment with the given identifier, without parameters or type.
+    pub fn new<S: Into<String>>(comment: S) -> Self {
+        Self { comment: comment.into(), params: BTreeMap::new(), comment_type: None }
+    }
+
+    /// Adds a parameter to the comment.
+    pub fn with_param<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the comment's type.
+    pub fn with_type(mut self, comment_type: AnalyzerCommentType) -> Self {
+        self.comment_type = Some(comment_type);
+        self
+    }
+
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        let is_valid_segment = |segment: &str| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        };
+        let segments: Vec<_> = self.comment.split('.').collect();
+        if segments.len() < 2 || !segments.iter().all(|segment| is_valid_segment(segment)) {
+            errors.push(ValidationError::InvalidCommentId(self.comment.clone()));
+        }
+
+        if matches!(self.comment_type, Some(AnalyzerCommentType::Unknown(_))) {
+            errors.push(ValidationError::UnknownCommentType(self.comment.clone()));
+        }
+
+        errors.extend(
+            self.params
+                .iter()
+                .filter(|(_, value)| value.is_array() || value.is_object())
+                .map(|(name, _)| ValidationError::InvalidParam {
+                    comment: self.comment.clone(),
+                    param: name.clone(),
+                }),
+        );
+
+        errors
+    }
+
+    fn to_analyzer_comment<T>(&self, templates: &T) -> Result<AnalyzerComment, ValidationError>
+    where
+        T: CommentTemplates + ?Sized,
+    {
+        let template = templates
+            .template(&self.comment)
+            .ok_or_else(|| ValidationError::MissingTemplate(self.comment.clone()))?;
+        let markdown = detail::interpolate(&template, &self.params).map_err(|param| {
+            ValidationError::MissingParam { comment: self.comment.clone(), param }
+        })?;
+
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(&markdown));
+
+        Ok(AnalyzerComment { comment_type: self.comment_type.clone().unwrap_or_default(), html })
+    }
+}
+
+/// Source of analyzer comment templates, used to convert an [`Analysis`] to [`AnalyzerFeedback`].
+///
+/// Templates are Markdown documents in which parameters are referred to using `%{name}`
+/// (or `%<name>s`).
+pub trait CommentTemplates {
+    /// Returns the template for the given comment identifier, if any.
+    fn template(&self, comment: &str) -> Option<Cow<'_, str>>;
+}
+
+impl CommentTemplates for HashMap<String, String> {
+    fn template(&self, comment: &str) -> Option<Cow<'_, str>> {
+        self.get(comment).map(|template| template.into())
+    }
+}
+
+impl CommentTemplates for BTreeMap<String, String> {
+    fn template(&self, comment: &str) -> Option<Cow<'_, str>> {
+        self.get(comment).map(|template| template.into())
+    }
+}
+
+/// [`CommentTemplates`] read from a local clone of the [website-copy](https://github.com/exercism/website-copy)
+/// repository.
+///
+/// The template for comment `ruby.two-fer.string_interpolation` is read from
+/// `analyzer-comments/ruby/two-fer/string_interpolation.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebsiteCopy {
+    root: PathBuf,
+}
+
+impl WebsiteCopy {
+    /// Creates a new source of templates for the website-copy repository cloned at `root`.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl CommentTemplates for WebsiteCopy {
+    fn template(&self, comment: &str) -> Option<Cow<'_, str>> {
+        let mut path = self.root.join("analyzer-comments");
+        path.extend(comment.split('.'));
+        path.set_extension("md");
+
+        fs::read_to_string(path).ok().map(Cow::Owned)
+    }
+}
+
+/// Problem found in an [`Analysis`], either while [validating](Analysis::validate) it or while
+/// [converting](Analysis::to_feedback) it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    /// A comment identifier is not of the form `language.group.slug`.
+    #[error("invalid comment identifier: {0:?}")]
+    InvalidCommentId(String),
+
+    /// A comment has a type that is not part of the interface.
+    #[error("comment {0} has an unknown type")]
+    UnknownCommentType(String),
+
+    /// A comment has a parameter whose value is not a scalar.
+    #[error("parameter {param} of comment {comment} is not a scalar value")]
+    InvalidParam {
+        /// Identifier of the comment.
+        comment: String,
+
+        /// Name of the parameter.
+        param: String,
+    },
+
+    /// No template was found for a comment.
+    #[error("no template found for comment {0}")]
+    MissingTemplate(String),
+
+    /// A comment's template refers to a parameter that the comment does not provide.
+    #[error("template of comment {comment} refers to missing parameter {param}")]
+    MissingParam {
+        /// Identifier of the comment.
+        comment: String,
+
+        /// Name of the missing parameter.
+        param: String,
+    },
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::api::v2::submission::analysis::AnalyzerCommentType;
+use crate::tooling::analyzer::Comment;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum CommentRepr {
+    Id(String),
+    Full {
+        comment: String,
+        #[serde(default)]
+        params: BTreeMap<String, serde_json::Value>,
+        #[serde(default, rename = "type")]
+        comment_type: Option<AnalyzerCommentType>,
+    },
+}
+
+impl From<CommentRepr> for Comment {
+    fn from(value: CommentRepr) -> Self {
+        match value {
+            CommentRepr::Id(comment) => Comment::new(comment),
+            CommentRepr::Full { comment, params, comment_type } => {
+                Comment { comment, params, comment_type }
+            },
+        }
+    }
+}
+
+pub fn interpolate(
+    template: &str,
+    params: &BTreeMap<String, serde_json::Value>,
+) -> Result<String, String> {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find('%') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let reference = match rest.as_bytes().get(1) {
+            Some(b'%') => {
+                result.push('%');
+                rest = &rest[2..];
+                continue;
+            },
+            Some(b'{') => rest[2..].find('}').map(|end| (&rest[2..end + 2], end + 3)),
+            Some(b'<') => rest[2..].find(">s").map(|end| (&rest[2..end + 2], end + 4)),
+            _ => None,
+        };
+
+        match reference {
+            Some((name, len)) => {
+                let value = params.get(name).ok_or_else(|| name.to_string())?;
+                match value {
+                    serde_json::Value::String(value) => result.push_str(value),
+                    value => result.push_str(&value.to_string()),
+                }
+                rest = &rest[len..];
+            },
+            None => {
+                result.push('%');
+                rest = &rest[1..];
+            },
+        }
+    }
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    mod interpolate {
+        use rstest::rstest;
+
+        use super::*;
+
+        fn params() -> BTreeMap<String, serde_json::Value> {
+            BTreeMap::from([("name".to_string(), "sum".into()), ("count".to_string(), 3.into())])
+        }
+
+        #[rstest]
+        #[case::no_params("Use iterators.", "Use iterators.")]
+        #[case::braces("Use `%{name}`.", "Use `sum`.")]
+        #[case::angle_brackets("Use `%<name>s`.", "Use `sum`.")]
+        #[case::non_string("Found %{count} loops.", "Found 3 loops.")]
+        #[case::escaped_percent("100%% sure about %{name}", "100% sure about sum")]
+        #[case::lone_percent("50% of %{name}", "50% of sum")]
+        #[case::unterminated("%{name", "%{name")]
+        fn test_valid(#[case] template: &str, #[case] expected: &str) {
+            assert_eq!(Ok(expected.to_string()), interpolate(template, &params()));
+        }
+
+        #[test]
+        fn test_missing_param() {
+            assert_eq!(Err("other".to_string()), interpolate("%{name} %{other}", &params()));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Types used to read and write the output of an [Exercism](https://exercism.org) track [representer](https://exercism.org/docs/building/tooling/representers).
+//!
+//! Representers write their output to three files, whose format is documented
+//! [here](https://exercism.org/docs/building/tooling/representers/interface):
+//!
+//! | File                  | Type                               |
+//! |-----------------------|------------------------------------|
+//! | `representation.txt`  | [`Output::representation`]         |
+//! | `representation.json` | [`Metadata`]                       |
+//! | `mapping.json`        | [`Mapping`]                        |
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::tooling::representer::Output;
+//!
+//! fn print_mapping_changes(first_dir: &str, second_dir: &str) -> anyhow::Result<()> {
+//!     let first = Output::read_from(first_dir)?;
+//!     let second = Output::read_from(second_dir)?;
+//!
+//!     if first.representation == second.representation {
+//!         print!("{}", first.mapping.diff(&second.mapping));
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// Name of the file containing a submission's normalized representation.
+pub const REPRESENTATION_TXT: &str = "representation.txt";
+
+/// Name of the file containing the representation's [`Metadata`].
+pub const REPRESENTATION_JSON: &str = "representation.json";
+
+/// Name of the file containing the representation's placeholder [`Mapping`].
+pub const MAPPING_JSON: &str = "mapping.json";
+
+/// Output of a representer for a submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Output {
+    /// Normalized representation of the submission (contents of `representation.txt`).
+    ///
+    /// Two submissions with the same representation are considered equivalent, so feedback given
+    /// on one applies to the other.
+    pub representation: String,
+
+    /// Representation metadata (contents of `representation.json`).
+    ///
+    /// Will be `None` if the representer did not produce the file.
+    pub metadata: Option<Metadata>,
+
+    /// Mapping of placeholders to the original identifiers (contents of `mapping.json`).
+    pub mapping: Mapping,
+}
+
+impl Output {
+    /// Reads a representer's output files from the given directory.
+    ///
+    /// `representation.json` is optional; the other files are required.
+    ///
+    /// # Errors
+    ///
+    /// - [`ToolingIoError`]: Error while reading a file
+    /// - [`ToolingFormatError`]: A JSON file is not valid
+    ///
+    /// [`ToolingIoError`]: crate::Error::ToolingIoError
+    /// [`ToolingFormatError`]: crate::Error::ToolingFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn read_from<P>(dir: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+
+        let representation = read_file(&dir.join(REPRESENTATION_TXT))?;
+        let metadata = match read_file(&dir.join(REPRESENTATION_JSON)) {
+            Ok(json) => Some(parse_json(&dir.join(REPRESENTATION_JSON), &json)?),
+            Err(Error::ToolingIoError { source, .. }) if source.kind() == ErrorKind::NotFound => {
+                None
+            },
+            Err(err) => return Err(err),
+        };
+        let mapping = parse_json(&dir.join(MAPPING_JSON), &read_file(&dir.join(MAPPING_JSON))?)?;
+
+        Ok(Self { representation, metadata, mapping })
+    }
+
+    /// Writes the representer's output files to the given directory, which must exist.
+    ///
+    /// `representation.json` is only written if [`metadata`](Self::metadata) is set.
+    ///
+    /// # Errors
+    ///
+    /// - [`ToolingIoError`]: Error while writing a file
+    ///
+    /// [`ToolingIoError`]: crate::Error::ToolingIoError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn write_to<P>(&self, dir: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+
+        write_file(&dir.join(REPRESENTATION_TXT), &self.representation)?;
+        if let Some(metadata) = &self.metadata {
+            write_json(&dir.join(REPRESENTATION_JSON), metadata)?;
+        }
+        write_json(&dir.join(MAPPING_JSON), &self.mapping)
+    }
+}
+
+/// Metadata about a representation (contents of `representation.json`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Version of the representer's normalization.
+    ///
+    /// Incremented when the representer changes in a way that produces different
+    /// representations for the same submissions.
+    pub version: u32,
+}
+
+/// Mapping of placeholders to the original identifiers they replace in a representation
+/// (contents of `mapping.json`).
+///
+/// # Examples
+///
+/// ```
+/// use mini_exercism::tooling::representer::Mapping;
+///
+/// let first: Mapping = [("PLACEHOLDER_1", "total"), ("PLACEHOLDER_2", "add")]
+///     .into_iter()
+///     .collect();
+/// let second: Mapping = [("PLACEHOLDER_1", "sum"), ("PLACEHOLDER_3", "i")]
+///     .into_iter()
+///     .collect();
+///
+/// let diff = first.diff(&second);
+/// assert_eq!(
+///     "- PLACEHOLDER_2: add\n+ PLACEHOLDER_3: i\n~ PLACEHOLDER_1: total -> sum\n",
+///     diff.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Mapping {
+    /// Original identifiers, keyed by placeholder.
+    pub placeholders: BTreeMap<String, String>,
+}
+
+impl Mapping {
+    /// Returns the original identifier replaced by the given placeholder, if any.
+    pub fn get(&self, placeholder: &str) -> Option<&str> {
+        self.placeholders.get(placeholder).map(String::as_str)
+    }
+
+    /// Returns the differences between this mapping and `other`.
+    ///
+    /// Placeholders only in `other` are [`added`](MappingDiff::added), placeholders only in
+    /// this mapping are [`removed`](MappingDiff::removed).
+    pub fn diff(&self, other: &Mapping) -> MappingDiff {
+        let mut diff = MappingDiff::default();
+
+        for (placeholder, identifier) in &self.placeholders {
+            match other.placeholders.get(placeholder) {
+                None => {
+                    diff.removed.insert(placeholder.clone(), identifier.clone());
+                },
+                Some(other_identifier) if other_identifier != identifier => {
+                    diff.changed.insert(
+                        placeholder.clone(),
+                        (identifier.clone(), other_identifier.clone()),
+                    );
+                },
+                Some(_) => {},
+            }
+        }
+        for (placeholder, identifier) in &other.placeholders {
+            if !self.placeholders.contains_key(placeholder) {
+                diff.added.insert(placeholder.clone(), identifier.clone());
+            }
+        }
+
+        diff
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Mapping
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            placeholders: iter
+                .into_iter()
+                .map(|(placeholder, identifier)| (placeholder.into(), identifier.into()))
+                .collect(),
+        }
+    }
+}
+
+/// Differences between two placeholder [`Mapping`]s, as returned by [`Mapping::diff`].
+///
+/// When [displayed](fmt::Display), each difference is written on its own line, prefixed with
+/// `-` (removed), `+` (added) or `~` (changed).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MappingDiff {
+    /// Placeholders only present in the second mapping, with their identifier.
+    pub added: BTreeMap<String, String>,
+
+    /// Placeholders only present in the first mapping, with their identifier.
+    pub removed: BTreeMap<String, String>,
+
+    /// Placeholders present in both mappings but mapped to different identifiers,
+    /// with their identifier in the first and second mapping.
+    pub changed: BTreeMap<String, (String, String)>,
+}
+
+impl MappingDiff {
+    /// Returns `true` if both mappings were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for MappingDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (placeholder, identifier) in &self.removed {
+            writeln!(f, "- {placeholder}: {identifier}")?;
+        }
+        for (placeholder, identifier) in &self.added {
+            writeln!(f, "+ {placeholder}: {identifier}")?;
+        }
+        for (placeholder, (first, second)) in &self.changed {
+            writeln!(f, "~ {placeholder}: {first} -> {second}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::ToolingIoError { path: path.into(), source })
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::ToolingIoError { path: path.into(), source })
+}
+
+fn parse_json<T>(path: &Path, json: &str) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    serde_json::from_str(json)
+        .map_err(|source| Error::ToolingFormatError { path: path.into(), source })
+}
+
+fn write_json<T>(path: &Path, value: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|source| Error::ToolingFormatError { path: path.into(), source })?;
+    write_file(path, &json)
+}
@@ -0,0 +1,165 @@
+//! Types used to parse the output of an [Exercism](https://exercism.org) track [test runner](https://exercism.org/docs/building/tooling/test-runners).
+//!
+//! Test runners write their results to a `results.json` file, whose format is documented
+//! [here](https://exercism.org/docs/building/tooling/test-runners/interface). All versions
+//! of the format (1 to 3) are supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use mini_exercism::tooling::test_runner::{Results, Status};
+//!
+//! let json = r#"{
+//!     "version": 3,
+//!     "status": "fail",
+//!     "tests": [
+//!         { "name": "adds", "status": "pass", "test_code": "assert_eq!(2, add(1, 1));", "task_id": 1 },
+//!         { "name": "subtracts", "status": "fail", "message": "left: 1, right: 0", "test_code": "assert_eq!(0, sub(1, 1));", "task_id": 2 }
+//!     ]
+//! }"#;
+//!
+//! let results: Results = json.parse().unwrap();
+//! assert_eq!(Status::Fail, results.status);
+//! assert_eq!(
+//!     vec!["subtracts"],
+//!     results
+//!         .failing_tests()
+//!         .map(|test| test.name.as_str())
+//!         .collect::<Vec<_>>()
+//! );
+//! ```
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString, VariantNames};
+
+/// Version of the `results.json` format assumed when none is specified.
+pub const DEFAULT_VERSION: u32 = 1;
+
+/// Contents of a test runner's `results.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Results {
+    /// Version of the `results.json` format.
+    ///
+    /// - Version 1: only the overall [`status`](Self::status) and [`message`](Self::message) are provided.
+    /// - Version 2: [`tests`](Self::tests) are provided, with their [`test_code`](TestResult::test_code).
+    /// - Version 3: tests are linked to the exercise's tasks via their [`task_id`](TestResult::task_id).
+    ///
+    /// If the file does not specify a version, [`DEFAULT_VERSION`] is used.
+    #[serde(default = "default_version")]
+    pub version: u32,
+
+    /// Overall status of the test run.
+    pub status: Status,
+
+    /// Error message, when the test run's [`status`](Self::status) is [`Error`](Status::Error).
+    ///
+    /// Some test runners also use it to report a failure in version 1 of the format.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// Results of individual tests, in the order they were executed.
+    ///
+    /// Will be empty for version 1 of the format, or if the tests could not be run.
+    #[serde(default)]
+    pub tests: Vec<TestResult>,
+}
+
+impl Results {
+    /// Returns `true` if all tests passed.
+    pub fn passed(&self) -> bool {
+        self.status == Status::Pass
+    }
+
+    /// Returns the tests that did not pass (e.g. that [failed](Status::Fail) or [errored](Status::Error)).
+    pub fn failing_tests(&self) -> impl Iterator<Item = &TestResult> {
+        self.tests.iter().filter(|test| !test.passed())
+    }
+
+    /// Returns the tests linked to the given task.
+    ///
+    /// Only version 3 of the format links tests to tasks.
+    pub fn tests_for_task(&self, task_id: u32) -> impl Iterator<Item = &TestResult> {
+        self.tests
+            .iter()
+            .filter(move |test| test.task_id == Some(task_id))
+    }
+}
+
+impl FromStr for Results {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// Result of an individual test in a test runner's `results.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestResult {
+    /// Name of the test, as displayed to the student.
+    pub name: String,
+
+    /// Status of the test.
+    pub status: Status,
+
+    /// Message explaining why the test did not pass.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// Output produced by the student's code while running the test (for example, debug prints).
+    #[serde(default)]
+    pub output: Option<String>,
+
+    /// Code of the test, as displayed to the student.
+    ///
+    /// Only provided starting with version 2 of the format.
+    #[serde(default)]
+    pub test_code: Option<String>,
+
+    /// ID of the exercise task that this test is linked to.
+    ///
+    /// Only provided starting with version 3 of the format.
+    #[serde(default)]
+    pub task_id: Option<u32>,
+}
+
+impl TestResult {
+    /// Returns `true` if the test passed.
+    pub fn passed(&self) -> bool {
+        self.status == Status::Pass
+    }
+}
+
+/// Possible status of a test run, or of an individual test, in a test runner's `results.json` file.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AsRefStr, Display, EnumString, VariantNames,
+)]
+#[serde(rename_all = "snake_case", expecting = "invalid test runner status: expected a string")]
+#[strum(serialize_all = "snake_case")]
+pub enum Status {
+    /// All tests passed.
+    Pass,
+
+    /// At least one test failed.
+    Fail,
+
+    /// Tests could not be run (for example because of a compilation error), or
+    /// an individual test crashed.
+    Error,
+
+    /// Unknown status.
+    ///
+    /// Included so that if new statuses are introduced in the interface later,
+    /// this crate won't break (hopefully).
+    ///
+    /// Contains the status found in the file, which is serialized back unchanged.
+    #[serde(untagged)]
+    #[strum(default, transparent)]
+    Unknown(String),
+}
+
+fn default_version() -> u32 {
+    DEFAULT_VERSION
+}
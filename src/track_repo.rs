@@ -0,0 +1,8 @@
+//! Types used to work with the files of an [Exercism](https://exercism.org) language track repository.
+//!
+//! Each language track is stored in its own GitHub repository (like [`exercism/rust`](https://github.com/exercism/rust)),
+//! whose structure is documented [here](https://exercism.org/docs/building/tracks).
+
+pub mod canonical_data;
+pub mod config;
+pub mod exercise_config;
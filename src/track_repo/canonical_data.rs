@@ -0,0 +1,308 @@
+//! Types used to parse [`problem-specifications`](https://github.com/exercism/problem-specifications)
+//! canonical data and the `tests.toml` files that tracks use to keep track of it.
+//!
+//! Practice exercises shared between tracks are described in the `problem-specifications`
+//! repository, whose `exercises/<slug>/canonical-data.json` files contain the exercise's
+//! [test cases](https://github.com/exercism/problem-specifications/blob/main/CONTRIBUTING.md#test-data-format-canonical-datajson).
+//! In a track, each practice exercise stores the list of cases it implements in
+//! [`.meta/tests.toml`](https://exercism.org/docs/building/tracks/practice-exercises#h-file-meta-tests-toml).
+//!
+//! A [`SyncReport`] can be used to find out what needs to be updated in an exercise's tests.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::track_repo::canonical_data::{CanonicalData, SyncReport};
+//!
+//! fn print_missing_cases(exercise_dir: &str, canonical_data_path: &str) -> anyhow::Result<()> {
+//!     let canonical_data = CanonicalData::read_from(canonical_data_path)?;
+//!     let report = SyncReport::check_dir(exercise_dir, &canonical_data)?;
+//!     for case in report.missing {
+//!         println!("{}: {}", case.uuid, case.description);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// Path of an exercise's `tests.toml` file, relative to the exercise directory.
+pub const TESTS_TOML_PATH: &str = ".meta/tests.toml";
+
+/// Contents of an exercise's `canonical-data.json` file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanonicalData {
+    /// Slug of the exercise, like `forth`.
+    pub exercise: String,
+
+    /// Comments about the test cases.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+
+    /// Test cases and groups of test cases.
+    pub cases: Vec<Case>,
+}
+
+impl CanonicalData {
+    /// Reads canonical data from the given `canonical-data.json` file.
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while reading the file
+    /// - [`TrackRepoFormatError`]: The file is not valid
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    /// [`TrackRepoFormatError`]: crate::Error::TrackRepoFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn read_from<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|source| Error::TrackRepoIoError { path: path.into(), source })?;
+        json.parse()
+            .map_err(|source| Error::TrackRepoFormatError { path: path.into(), source })
+    }
+
+    /// Returns all test cases, including those nested in groups, in order.
+    pub fn test_cases(&self) -> Vec<&TestCase> {
+        let mut test_cases = Vec::new();
+        collect_test_cases(&self.cases, &mut test_cases);
+        test_cases
+    }
+}
+
+impl FromStr for CanonicalData {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// Item in the [`cases`](CanonicalData::cases) of canonical data: either a test case or a group of cases.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Case {
+    /// Single test case.
+    Test(TestCase),
+
+    /// Group of test cases.
+    Group(CaseGroup),
+}
+
+/// Group of test cases in canonical data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseGroup {
+    /// Description of the group.
+    pub description: String,
+
+    /// Comments about the group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+
+    /// Test cases and nested groups of test cases.
+    pub cases: Vec<Case>,
+}
+
+/// Single test case in canonical data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestCase {
+    /// Unique identifier of the test case.
+    pub uuid: String,
+
+    /// UUID of the test case replaced by this one, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reimplements: Option<String>,
+
+    /// Description of the test case.
+    pub description: String,
+
+    /// Comments about the test case.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+
+    /// Scenarios the test case belongs to, like `unicode`.
+    ///
+    /// Tracks can use scenarios to exclude groups of cases that do not apply to them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scenarios: Vec<String>,
+
+    /// Name of the property (function) tested, like `evaluate`.
+    pub property: String,
+
+    /// Input passed to the property.
+    pub input: serde_json::Value,
+
+    /// Expected output of the property.
+    pub expected: serde_json::Value,
+}
+
+/// Contents of an exercise's `.meta/tests.toml` file.
+///
+/// Lists the canonical test cases known to the track, keyed by UUID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TestsToml {
+    /// Known test cases, keyed by UUID.
+    pub cases: BTreeMap<String, TestsTomlEntry>,
+}
+
+impl TestsToml {
+    /// Reads the `tests.toml` file of the exercise stored in the given directory.
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while reading the file
+    /// - [`TrackRepoTomlError`]: The file is not valid
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    /// [`TrackRepoTomlError`]: crate::Error::TrackRepoTomlError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn read_from<P>(exercise_dir: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = exercise_dir.as_ref().join(TESTS_TOML_PATH);
+        let toml = fs::read_to_string(&path)
+            .map_err(|source| Error::TrackRepoIoError { path: path.clone(), source })?;
+        toml.parse()
+            .map_err(|source| Error::TrackRepoTomlError { path, source })
+    }
+
+    /// Returns `true` if the test case with the given UUID is implemented by the track.
+    pub fn is_included(&self, uuid: &str) -> bool {
+        self.cases
+            .get(uuid)
+            .is_some_and(TestsTomlEntry::is_included)
+    }
+}
+
+impl FromStr for TestsToml {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+/// Entry for a canonical test case in an exercise's `tests.toml` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestsTomlEntry {
+    /// Description of the test case.
+    pub description: String,
+
+    /// Whether the test case is implemented by the track.
+    ///
+    /// When absent, the test case is included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<bool>,
+
+    /// UUID of the test case replaced by this one, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reimplements: Option<String>,
+
+    /// Comment explaining why the test case is excluded, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl TestsTomlEntry {
+    /// Returns `true` if the test case is implemented by the track.
+    pub fn is_included(&self) -> bool {
+        self.include.unwrap_or(true)
+    }
+}
+
+/// Result of checking an exercise's `tests.toml` against [canonical data](CanonicalData).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    /// Canonical test cases that are not listed in `tests.toml`.
+    pub missing: Vec<TestCase>,
+
+    /// Canonical test cases that reimplement a test case still included in `tests.toml`.
+    ///
+    /// The track should probably replace the old test case with the new one.
+    pub reimplemented: Vec<TestCase>,
+
+    /// Test cases excluded in `tests.toml`, keyed by UUID.
+    pub excluded: BTreeMap<String, TestsTomlEntry>,
+
+    /// UUIDs of test cases listed in `tests.toml` but not found in canonical data.
+    pub unknown: Vec<String>,
+}
+
+impl SyncReport {
+    /// Checks the given `tests.toml` against canonical data.
+    pub fn check(canonical_data: &CanonicalData, tests_toml: &TestsToml) -> Self {
+        let test_cases = canonical_data.test_cases();
+        let canonical_uuids: HashSet<_> =
+            test_cases.iter().map(|case| case.uuid.as_str()).collect();
+
+        Self {
+            missing: test_cases
+                .iter()
+                .filter(|case| !tests_toml.cases.contains_key(&case.uuid))
+                .map(|&case| case.clone())
+                .collect(),
+            reimplemented: test_cases
+                .iter()
+                .filter(|case| {
+                    case.reimplements
+                        .as_deref()
+                        .is_some_and(|uuid| tests_toml.is_included(uuid))
+                })
+                .map(|&case| case.clone())
+                .collect(),
+            excluded: tests_toml
+                .cases
+                .iter()
+                .filter(|(_, entry)| !entry.is_included())
+                .map(|(uuid, entry)| (uuid.clone(), entry.clone()))
+                .collect(),
+            unknown: tests_toml
+                .cases
+                .keys()
+                .filter(|uuid| !canonical_uuids.contains(uuid.as_str()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Checks the `tests.toml` of the exercise stored in the given directory against canonical data.
+    ///
+    /// # Errors
+    ///
+    /// See [`TestsToml::read_from`].
+    pub fn check_dir<P>(exercise_dir: P, canonical_data: &CanonicalData) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::check(canonical_data, &TestsToml::read_from(exercise_dir)?))
+    }
+
+    /// Returns `true` if `tests.toml` is up-to-date with canonical data.
+    ///
+    /// Excluded test cases do not count as being out of date.
+    pub fn is_in_sync(&self) -> bool {
+        self.missing.is_empty() && self.reimplemented.is_empty() && self.unknown.is_empty()
+    }
+}
+
+fn collect_test_cases<'a>(cases: &'a [Case], test_cases: &mut Vec<&'a TestCase>) {
+    for case in cases {
+        match case {
+            Case::Test(test_case) => test_cases.push(test_case),
+            Case::Group(group) => collect_test_cases(&group.cases, test_cases),
+        }
+    }
+}
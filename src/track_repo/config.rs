@@ -0,0 +1,535 @@
+//! Types used to parse a language track's `config.json` file.
+//!
+//! The format of this file is documented [here](https://exercism.org/docs/building/tracks/config-json).
+//! Besides parsing it, this module can [check](Config::check_exercises) that the exercises it
+//! lists are consistent with those returned by the [Exercism website](https://exercism.org) v2 API.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::api;
+//! use mini_exercism::track_repo::config::Config;
+//!
+//! async fn check_track(config_path: &str) -> anyhow::Result<()> {
+//!     let config: Config = std::fs::read_to_string(config_path)?.parse()?;
+//!
+//!     let client = api::v2::Client::new()?;
+//!     let exercises = client.get_exercises(&config.slug, None).await?.exercises;
+//!     for inconsistency in config.check_exercises(&exercises) {
+//!         println!("{inconsistency}");
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display};
+
+use crate::api::v2::exercise::{Difficulty, Exercise, Type};
+use crate::api::v2::tag::Tag;
+use crate::api::v2::track::Track;
+
+/// Contents of a language track's `config.json` file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    /// Name of the language, like `Rust`.
+    pub language: String,
+
+    /// Slug of the track, like `rust`.
+    pub slug: String,
+
+    /// Whether the track is active on the website.
+    pub active: bool,
+
+    /// Status of the track's features.
+    pub status: TrackStatus,
+
+    /// Short description of the language.
+    pub blurb: String,
+
+    /// Version of the config format.
+    pub version: u32,
+
+    /// Settings of the website's online editor.
+    pub online_editor: OnlineEditor,
+
+    /// Test runner settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_runner: Option<TestRunner>,
+
+    /// Patterns of files used by the track's exercises.
+    #[serde(default)]
+    pub files: FilePatterns,
+
+    /// Exercises of the track.
+    #[serde(default)]
+    pub exercises: Exercises,
+
+    /// Concepts taught by the track.
+    #[serde(default)]
+    pub concepts: Vec<Concept>,
+
+    /// Key features of the language, displayed on the track's page.
+    #[serde(default)]
+    pub key_features: Vec<KeyFeature>,
+
+    /// Tags of the track.
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+impl Config {
+    /// Checks that the exercises in this config are consistent with those returned by
+    /// the [Exercism website](https://exercism.org) for the track (see
+    /// [`get_exercises`](crate::api::v2::Client::get_exercises)).
+    ///
+    /// Exercises that are [`Wip`](ExerciseStatus::Wip) or [`Deprecated`](ExerciseStatus::Deprecated)
+    /// are not expected to be returned by the website. Tutorial exercises returned by the website
+    /// are considered practice exercises.
+    pub fn check_exercises(&self, exercises: &[Exercise]) -> Vec<Inconsistency> {
+        let mut inconsistencies = Vec::new();
+
+        for config_exercise in self.exercises.all() {
+            let slug = config_exercise.slug();
+            match exercises.iter().find(|exercise| exercise.name == slug) {
+                None if config_exercise.status().is_listed() => {
+                    inconsistencies.push(Inconsistency::MissingFromApi(slug.into()));
+                },
+                None => {},
+                Some(exercise) => {
+                    if !config_exercise.matches_type(&exercise.exercise_type) {
+                        inconsistencies.push(Inconsistency::TypeMismatch {
+                            exercise: slug.into(),
+                            config: config_exercise.exercise_type(),
+                            api: exercise.exercise_type.clone(),
+                        });
+                    }
+                    if let ConfigExercise::Practice(practice) = config_exercise {
+                        let difficulty = Difficulty::from_rating(practice.difficulty);
+                        if difficulty != exercise.difficulty {
+                            inconsistencies.push(Inconsistency::DifficultyMismatch {
+                                exercise: slug.into(),
+                                config: practice.difficulty,
+                                api: exercise.difficulty.clone(),
+                            });
+                        }
+                    }
+                },
+            }
+        }
+
+        let config_slugs: HashSet<_> = self
+            .exercises
+            .all()
+            .map(|exercise| exercise.slug())
+            .collect();
+        inconsistencies.extend(
+            exercises
+                .iter()
+                .filter(|exercise| !config_slugs.contains(exercise.name.as_str()))
+                .map(|exercise| Inconsistency::MissingFromConfig(exercise.name.to_string())),
+        );
+
+        inconsistencies
+    }
+
+    /// Checks that this config is consistent with the track returned by the
+    /// [Exercism website](https://exercism.org) (see [`get_tracks`](crate::api::v2::Client::get_tracks)).
+    ///
+    /// Checks the track's slug, title and tags.
+    pub fn check_track(&self, track: &Track) -> Vec<Inconsistency> {
+        let mut inconsistencies = Vec::new();
+
+        if self.slug != track.name {
+            inconsistencies.push(Inconsistency::SlugMismatch {
+                config: self.slug.clone(),
+                api: track.name.to_string(),
+            });
+        }
+        if self.language != track.title {
+            inconsistencies.push(Inconsistency::TitleMismatch {
+                config: self.language.clone(),
+                api: track.title.clone(),
+            });
+        }
+
+        let track_tags: Vec<_> = track.typed_tags().collect();
+        inconsistencies.extend(
+            self.tags
+                .iter()
+                .filter(|tag| !track_tags.contains(tag))
+                .map(|tag| Inconsistency::TagMissingFromApi(tag.clone())),
+        );
+        inconsistencies.extend(
+            track_tags
+                .into_iter()
+                .filter(|tag| !self.tags.contains(tag))
+                .map(Inconsistency::TagMissingFromConfig),
+        );
+
+        inconsistencies
+    }
+}
+
+impl FromStr for Config {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// Status of a track's features, as stored in its `config.json` file.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackStatus {
+    /// Whether concept exercises (and the learning mode) are enabled.
+    pub concept_exercises: bool,
+
+    /// Whether the track has a [test runner](https://exercism.org/docs/building/tooling/test-runners).
+    pub test_runner: bool,
+
+    /// Whether the track has a [representer](https://exercism.org/docs/building/tooling/representers).
+    pub representer: bool,
+
+    /// Whether the track has an [analyzer](https://exercism.org/docs/building/tooling/analyzers).
+    pub analyzer: bool,
+}
+
+/// Settings of the [Exercism website](https://exercism.org)'s online editor for a track.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnlineEditor {
+    /// Indentation style.
+    pub indent_style: IndentStyle,
+
+    /// Size of an indentation level.
+    pub indent_size: u32,
+
+    /// Language used to highlight code with [highlight.js](https://highlightjs.org/).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlightjs_language: Option<String>,
+}
+
+/// Possible indentation style used by the [Exercism website](https://exercism.org)'s online editor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[serde(rename_all = "snake_case", expecting = "invalid indentation style: expected a string")]
+#[strum(serialize_all = "snake_case")]
+pub enum IndentStyle {
+    /// Indent using spaces.
+    Space,
+
+    /// Indent using tabs.
+    Tab,
+
+    /// Unknown indentation style.
+    ///
+    /// Included so that if new indentation styles are introduced later,
+    /// this crate will not break (hopefully).
+    ///
+    /// Contains the value found in the config, which is serialized back unchanged.
+    #[serde(untagged)]
+    #[strum(transparent)]
+    Unknown(String),
+}
+
+/// Test runner settings of a track.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestRunner {
+    /// Average time it takes to run the tests of a submission, in seconds.
+    pub average_run_time: f64,
+}
+
+/// Patterns of files used by a track's exercises.
+///
+/// Patterns can contain placeholders like `%{snake_slug}`; they are used to generate
+/// the `files` key of each exercise's `.meta/config.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePatterns {
+    /// Patterns of solution files.
+    #[serde(default)]
+    pub solution: Vec<String>,
+
+    /// Patterns of test files.
+    #[serde(default)]
+    pub test: Vec<String>,
+
+    /// Patterns of example solution files (for practice exercises).
+    #[serde(default)]
+    pub example: Vec<String>,
+
+    /// Patterns of exemplar solution files (for concept exercises).
+    #[serde(default)]
+    pub exemplar: Vec<String>,
+
+    /// Patterns of additional files shown in the editor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub editor: Vec<String>,
+
+    /// Patterns of files that make existing solutions out of date when modified.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalidator: Vec<String>,
+}
+
+/// Exercises of a track, as stored in its `config.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Exercises {
+    /// Concept exercises.
+    #[serde(default)]
+    pub concept: Vec<ConceptExercise>,
+
+    /// Practice exercises.
+    #[serde(default)]
+    pub practice: Vec<PracticeExercise>,
+
+    /// Slugs of exercises that will never be implemented in the track.
+    #[serde(default)]
+    pub foregone: Vec<String>,
+}
+
+impl Exercises {
+    /// Returns all exercises (concept exercises first).
+    pub fn all(&self) -> impl Iterator<Item = ConfigExercise<'_>> {
+        self.concept
+            .iter()
+            .map(ConfigExercise::Concept)
+            .chain(self.practice.iter().map(ConfigExercise::Practice))
+    }
+}
+
+/// Reference to an exercise in a track's `config.json` file, as returned by [`Exercises::all`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigExercise<'a> {
+    /// Concept exercise.
+    Concept(&'a ConceptExercise),
+
+    /// Practice exercise.
+    Practice(&'a PracticeExercise),
+}
+
+impl<'a> ConfigExercise<'a> {
+    /// Returns the exercise's slug.
+    pub fn slug(&self) -> &'a str {
+        match self {
+            Self::Concept(exercise) => &exercise.slug,
+            Self::Practice(exercise) => &exercise.slug,
+        }
+    }
+
+    /// Returns the exercise's status.
+    pub fn status(&self) -> ExerciseStatus {
+        match self {
+            Self::Concept(exercise) => exercise.status.clone(),
+            Self::Practice(exercise) => exercise.status.clone(),
+        }
+    }
+
+    /// Returns the type of the exercise.
+    pub fn exercise_type(&self) -> Type {
+        match self {
+            Self::Concept(_) => Type::Concept,
+            Self::Practice(_) => Type::Practice,
+        }
+    }
+
+    fn matches_type(&self, exercise_type: &Type) -> bool {
+        match self {
+            Self::Concept(_) => *exercise_type == Type::Concept,
+            Self::Practice(_) => matches!(exercise_type, Type::Practice | Type::Tutorial),
+        }
+    }
+}
+
+/// A concept exercise in a track's `config.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConceptExercise {
+    /// Slug of the exercise, like `lasagna`.
+    pub slug: String,
+
+    /// Name of the exercise, like `Lucian's Luscious Lasagna`.
+    pub name: String,
+
+    /// Unique identifier of the exercise.
+    pub uuid: String,
+
+    /// Slugs of the concepts taught by the exercise.
+    #[serde(default)]
+    pub concepts: Vec<String>,
+
+    /// Slugs of the concepts that must be learnt before starting the exercise.
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+
+    /// Status of the exercise.
+    #[serde(default)]
+    pub status: ExerciseStatus,
+}
+
+/// A practice exercise in a track's `config.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PracticeExercise {
+    /// Slug of the exercise, like `forth`.
+    pub slug: String,
+
+    /// Name of the exercise, like `Forth`.
+    pub name: String,
+
+    /// Unique identifier of the exercise.
+    pub uuid: String,
+
+    /// Slugs of the concepts practiced by the exercise.
+    #[serde(default)]
+    pub practices: Vec<String>,
+
+    /// Slugs of the concepts that must be learnt before starting the exercise.
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+
+    /// Difficulty rating of the exercise, between 1 and 10 (inclusive).
+    ///
+    /// See [`Difficulty::from_rating`] for the matching named difficulty.
+    pub difficulty: u8,
+
+    /// Status of the exercise.
+    #[serde(default)]
+    pub status: ExerciseStatus,
+
+    /// Topics of the exercise (deprecated).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
+}
+
+/// Possible status of an exercise in a track's `config.json` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Display, AsRefStr)]
+#[serde(rename_all = "snake_case", expecting = "invalid exercise status: expected a string")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExerciseStatus {
+    /// Work in progress; not shown on the website.
+    Wip,
+
+    /// Beta exercise; shown on the website with a beta label.
+    Beta,
+
+    /// Active exercise.
+    #[default]
+    Active,
+
+    /// Deprecated exercise; not shown on the website, except to users who already started it.
+    Deprecated,
+
+    /// Unknown exercise status.
+    ///
+    /// Included so that if new exercise statuses are introduced later,
+    /// this crate will not break (hopefully).
+    ///
+    /// Contains the value found in the config, which is serialized back unchanged.
+    #[serde(untagged)]
+    #[strum(transparent)]
+    Unknown(String),
+}
+
+impl ExerciseStatus {
+    /// Returns `true` if exercises with this status are listed on the website.
+    pub fn is_listed(&self) -> bool {
+        matches!(self, Self::Beta | Self::Active)
+    }
+}
+
+/// A concept taught by a track.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Concept {
+    /// Unique identifier of the concept.
+    pub uuid: String,
+
+    /// Slug of the concept, like `closures`.
+    pub slug: String,
+
+    /// Name of the concept, like `Closures`.
+    pub name: String,
+}
+
+/// A key feature of a language, displayed on the track's page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyFeature {
+    /// Title of the feature.
+    pub title: String,
+
+    /// Short description of the feature.
+    pub content: String,
+
+    /// Icon representing the feature.
+    pub icon: String,
+}
+
+/// Inconsistency between a track's `config.json` file and the data returned by the
+/// [Exercism website](https://exercism.org) v2 API.
+///
+/// Returned by [`Config::check_exercises`] and [`Config::check_track`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Inconsistency {
+    /// An exercise in the config is not returned by the API.
+    #[error("exercise {0} is in config.json but not returned by the API")]
+    MissingFromApi(String),
+
+    /// An exercise returned by the API is not in the config.
+    #[error("exercise {0} is returned by the API but not in config.json")]
+    MissingFromConfig(String),
+
+    /// An exercise has a different type in the config and in the API.
+    #[error(
+        "exercise {exercise} is a {config} exercise in config.json but a {api} exercise in the API"
+    )]
+    TypeMismatch {
+        /// Slug of the exercise.
+        exercise: String,
+
+        /// Type of the exercise in the config.
+        config: Type,
+
+        /// Type of the exercise in the API.
+        api: Type,
+    },
+
+    /// An exercise has a difficulty rating in the config that does not match its difficulty in the API.
+    #[error("exercise {exercise} has difficulty {config} in config.json but is {api} in the API")]
+    DifficultyMismatch {
+        /// Slug of the exercise.
+        exercise: String,
+
+        /// Difficulty rating of the exercise in the config.
+        config: u8,
+
+        /// Difficulty of the exercise in the API.
+        api: Difficulty,
+    },
+
+    /// The track slug in the config differs from the one in the API.
+    #[error("track slug is {config} in config.json but {api} in the API")]
+    SlugMismatch {
+        /// Slug in the config.
+        config: String,
+
+        /// Slug in the API.
+        api: String,
+    },
+
+    /// The language name in the config differs from the track title in the API.
+    #[error("track title is {config} in config.json but {api} in the API")]
+    TitleMismatch {
+        /// Language name in the config.
+        config: String,
+
+        /// Track title in the API.
+        api: String,
+    },
+
+    /// A tag in the config is not returned by the API.
+    #[error("tag {0} is in config.json but not returned by the API")]
+    TagMissingFromApi(Tag),
+
+    /// A tag returned by the API is not in the config.
+    #[error("tag {0} is returned by the API but not in config.json")]
+    TagMissingFromConfig(Tag),
+}
@@ -0,0 +1,320 @@
+//! Types used to parse an exercise's `.meta/config.json` file.
+//!
+//! The format of this file is documented [here](https://exercism.org/docs/building/tracks/practice-exercises#h-file-meta-config-json).
+//! Exercises downloaded using the [Exercism CLI](https://exercism.org/docs/using/solving-exercises/working-locally)
+//! contain a copy of this file in `.exercism/config.json`.
+//!
+//! The [`files`](ExerciseConfig::files) listed in this file can be used to [classify](ExerciseConfig::classify_dir)
+//! the files of an exercise directory, for example to find out which files need to be submitted.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use mini_exercism::track_repo::exercise_config::ExerciseConfig;
+//!
+//! fn print_solution_files(exercise_dir: &str) -> anyhow::Result<()> {
+//!     let config = ExerciseConfig::read_from(exercise_dir)?;
+//!     for file in config.classify_dir(exercise_dir)?.solution {
+//!         println!("{}", file.display());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, IntoStaticStr};
+
+use crate::{Error, Result};
+
+/// Paths where an exercise's config file can be found, relative to the exercise directory.
+///
+/// The first one is used in track repositories, the second one in downloaded exercises.
+pub const EXERCISE_CONFIG_PATHS: [&str; 2] = [".meta/config.json", ".exercism/config.json"];
+
+/// Contents of an exercise's `.meta/config.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExerciseConfig {
+    /// GitHub usernames of the exercise's authors.
+    #[serde(default)]
+    pub authors: Vec<String>,
+
+    /// GitHub usernames of the exercise's contributors.
+    #[serde(default)]
+    pub contributors: Vec<String>,
+
+    /// Files of the exercise, by role.
+    pub files: Files,
+
+    /// Short description of the exercise.
+    pub blurb: String,
+
+    /// Source of the exercise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// URL of the exercise's source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+
+    /// Slug of the exercise's icon, if it differs from the exercise slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Whether the exercise's tests can be run by the track's test runner.
+    ///
+    /// Defaults to `true` when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_runner: Option<bool>,
+}
+
+impl ExerciseConfig {
+    /// Reads the config of the exercise stored in the given directory.
+    ///
+    /// The config is looked for in each of the [`EXERCISE_CONFIG_PATHS`], in order.
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while reading the config file (or none was found)
+    /// - [`TrackRepoFormatError`]: The config file is not valid
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    /// [`TrackRepoFormatError`]: crate::Error::TrackRepoFormatError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn read_from<P>(exercise_dir: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let exercise_dir = exercise_dir.as_ref();
+        let path = EXERCISE_CONFIG_PATHS
+            .iter()
+            .map(|config_path| exercise_dir.join(config_path))
+            .find(|path| path.is_file())
+            .unwrap_or_else(|| exercise_dir.join(EXERCISE_CONFIG_PATHS[0]));
+
+        let json = fs::read_to_string(&path)
+            .map_err(|source| Error::TrackRepoIoError { path: path.clone(), source })?;
+        json.parse()
+            .map_err(|source| Error::TrackRepoFormatError { path, source })
+    }
+
+    /// Returns the kind of the given exercise file, or `None` if the file is not listed in
+    /// the config.
+    ///
+    /// `path` must be relative to the exercise directory. If a file is listed more than once,
+    /// the first matching kind in [`FileKind`] declaration order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_exercism::track_repo::exercise_config::{ExerciseConfig, FileKind};
+    ///
+    /// let config: ExerciseConfig = r#"{
+    ///     "files": {
+    ///         "solution": ["src/lib.rs", "Cargo.toml"],
+    ///         "test": ["tests/forth.rs"],
+    ///         "example": [".meta/example.rs"]
+    ///     },
+    ///     "blurb": "Implement an evaluator for a very simple subset of Forth."
+    /// }"#
+    /// .parse()?;
+    ///
+    /// assert_eq!(Some(FileKind::Solution), config.classify("src/lib.rs"));
+    /// assert_eq!(Some(FileKind::Test), config.classify("tests/forth.rs"));
+    /// assert_eq!(None, config.classify("README.md"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn classify<P>(&self, path: P) -> Option<FileKind>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        self.files
+            .by_kind()
+            .find(|(_, files)| files.iter().any(|file| Path::new(file) == path))
+            .map(|(kind, _)| kind)
+    }
+
+    /// Classifies the files found in the given exercise directory.
+    ///
+    /// The directory is walked recursively; returned paths are relative to it. Files listed in the
+    /// config but not found in the directory are returned in [`missing`](ClassifiedFiles::missing).
+    ///
+    /// # Errors
+    ///
+    /// - [`TrackRepoIoError`]: Error while walking the directory
+    ///
+    /// [`TrackRepoIoError`]: crate::Error::TrackRepoIoError
+    #[cfg_attr(not(coverage), tracing::instrument(skip_all, err))]
+    pub fn classify_dir<P>(&self, exercise_dir: P) -> Result<ClassifiedFiles>
+    where
+        P: AsRef<Path>,
+    {
+        let exercise_dir = exercise_dir.as_ref();
+
+        let mut paths = Vec::new();
+        walk_dir(exercise_dir, Path::new(""), &mut paths)?;
+        paths.sort();
+
+        let mut classified = ClassifiedFiles::default();
+        for path in paths {
+            let files = match self.classify(&path) {
+                Some(FileKind::Solution) => &mut classified.solution,
+                Some(FileKind::Test) => &mut classified.test,
+                Some(FileKind::Example) => &mut classified.example,
+                Some(FileKind::Exemplar) => &mut classified.exemplar,
+                Some(FileKind::Editor) => &mut classified.editor,
+                Some(FileKind::Invalidator) | None => &mut classified.other,
+            };
+            files.push(path);
+        }
+        classified.missing = self
+            .files
+            .by_kind()
+            .flat_map(|(_, files)| files)
+            .map(PathBuf::from)
+            .filter(|path| !exercise_dir.join(path).is_file())
+            .collect();
+        classified.missing.sort();
+        classified.missing.dedup();
+
+        Ok(classified)
+    }
+}
+
+impl FromStr for ExerciseConfig {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// Files of an exercise, by role, as listed in its `.meta/config.json` file.
+///
+/// Paths are relative to the exercise directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Files {
+    /// Files that users need to modify to solve the exercise.
+    #[serde(default)]
+    pub solution: Vec<String>,
+
+    /// Files containing the exercise's tests.
+    #[serde(default)]
+    pub test: Vec<String>,
+
+    /// Files containing an example solution (for practice exercises).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub example: Vec<String>,
+
+    /// Files containing an exemplar solution (for concept exercises).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exemplar: Vec<String>,
+
+    /// Additional read-only files shown in the editor.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub editor: Vec<String>,
+
+    /// Files that make existing solutions out of date when modified.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalidator: Vec<String>,
+}
+
+impl Files {
+    /// Returns the files of the given kind.
+    pub fn of_kind(&self, kind: FileKind) -> &[String] {
+        match kind {
+            FileKind::Solution => &self.solution,
+            FileKind::Test => &self.test,
+            FileKind::Example => &self.example,
+            FileKind::Exemplar => &self.exemplar,
+            FileKind::Editor => &self.editor,
+            FileKind::Invalidator => &self.invalidator,
+        }
+    }
+
+    fn by_kind(&self) -> impl Iterator<Item = (FileKind, &[String])> {
+        [
+            FileKind::Solution,
+            FileKind::Test,
+            FileKind::Example,
+            FileKind::Exemplar,
+            FileKind::Editor,
+            FileKind::Invalidator,
+        ]
+        .into_iter()
+        .map(|kind| (kind, self.of_kind(kind)))
+    }
+}
+
+/// Possible kind of file in an exercise, as listed in its `.meta/config.json` file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, AsRefStr, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum FileKind {
+    /// Solution file.
+    Solution,
+
+    /// Test file.
+    Test,
+
+    /// Example solution file.
+    Example,
+
+    /// Exemplar solution file.
+    Exemplar,
+
+    /// Read-only file shown in the editor.
+    Editor,
+
+    /// File that makes existing solutions out of date when modified.
+    Invalidator,
+}
+
+/// Files of an exercise directory, classified using its [`ExerciseConfig`].
+///
+/// Returned by [`ExerciseConfig::classify_dir`]. All paths are relative to the exercise
+/// directory and sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassifiedFiles {
+    /// Solution files.
+    pub solution: Vec<PathBuf>,
+
+    /// Test files.
+    pub test: Vec<PathBuf>,
+
+    /// Example solution files.
+    pub example: Vec<PathBuf>,
+
+    /// Exemplar solution files.
+    pub exemplar: Vec<PathBuf>,
+
+    /// Read-only files shown in the editor.
+    pub editor: Vec<PathBuf>,
+
+    /// Files not listed in the config (or only listed as invalidators).
+    pub other: Vec<PathBuf>,
+
+    /// Files listed in the config that were not found in the directory.
+    pub missing: Vec<PathBuf>,
+}
+
+fn walk_dir(root: &Path, relative: &Path, paths: &mut Vec<PathBuf>) -> Result<()> {
+    let dir = root.join(relative);
+    let io_error = |source| Error::TrackRepoIoError { path: dir.clone(), source };
+
+    for entry in fs::read_dir(&dir).map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        let path = relative.join(entry.file_name());
+        if entry.file_type().map_err(io_error)?.is_dir() {
+            walk_dir(root, &path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
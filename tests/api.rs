@@ -1,4 +1,6 @@
 #[cfg(all(mini_exercism_enable_tests_with_real_endpoints, not(tarpaulin), not(coverage)))]
 mod real_endpoints;
+#[cfg(feature = "schema")]
+mod schema;
 mod v1;
 mod v2;
@@ -2,6 +2,7 @@ mod get_tracks {
     use assert_matches::assert_matches;
     use mini_exercism::Error;
     use mini_exercism::api;
+    use mini_exercism::api::v2::tag::Paradigm;
     use mini_exercism::api::v2::tracks::Filters;
     use mini_exercism::api::v2::tracks::StatusFilter::Joined;
     use mini_exercism::http::StatusCode;
@@ -40,7 +41,7 @@ mod get_tracks {
     #[file_serial(real_endpoints)]
     async fn test_tags() {
         let client = api::v2::Client::new().unwrap();
-        let filters = Filters::builder().tag("Functional").build();
+        let filters = Filters::builder().tag(Paradigm::Functional).build();
         let tracks_response = client.get_tracks(Some(filters)).await;
 
         // Tags do not currently work.
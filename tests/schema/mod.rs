@@ -0,0 +1,91 @@
+use mini_exercism::api::schema::{openapi, schemars};
+use mini_exercism::api::v2::{solution, tracks};
+use serde_json::{Value, json};
+
+fn enum_values(schema: &Value) -> Vec<&str> {
+    schema["anyOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|variant| match (&variant["const"], &variant["enum"]) {
+            (Value::String(value), _) => vec![value.as_str()],
+            (_, Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+fn refs<'a>(value: &'a Value, found: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                found.push(reference);
+            }
+            map.values().for_each(|value| refs(value, found));
+        },
+        Value::Array(values) => values.iter().for_each(|value| refs(value, found)),
+        _ => {},
+    }
+}
+
+#[test]
+fn test_enum_schema() {
+    let schema = schemars::schema_for!(solution::Status).to_value();
+
+    assert_eq!(vec!["started", "iterated", "completed", "published"], enum_values(&schema));
+    assert!(
+        schema["anyOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|variant| variant.get("const").is_none() && variant["type"] == "string")
+    );
+}
+
+#[test]
+fn test_response_schema() {
+    let schema = schemars::schema_for!(tracks::Response).to_value();
+
+    assert_eq!(json!(["tracks"]), schema["required"]);
+    assert_eq!("#/$defs/Track", schema["properties"]["tracks"]["items"]["$ref"]);
+    assert!(schema["$defs"]["TrackLinks"].is_object());
+}
+
+#[test]
+fn test_openapi() {
+    let document = openapi();
+
+    assert_eq!("3.0.3", document["openapi"]);
+    assert_eq!(
+        "https://api.exercism.io",
+        document["paths"]["/v1/solutions/{uuid}"]["servers"][0]["url"]
+    );
+    assert_eq!(
+        "https://exercism.org",
+        document["paths"]["/api/v2/solutions/{uuid}"]["servers"][0]["url"]
+    );
+
+    let get_tracks = &document["paths"]["/api/v2/tracks"]["get"];
+    assert_eq!("v2GetTracks", get_tracks["operationId"]);
+    assert_eq!(json!(["all", "joined", "unjoined"]), get_tracks["parameters"][2]["schema"]["enum"]);
+    assert_eq!(
+        "#/components/schemas/TracksResponse",
+        get_tracks["responses"]["200"]["content"]["application/json"]["schema"]["$ref"]
+    );
+    assert!(document["paths"]["/api/v2/solutions/{solution_uuid}/sync"]["patch"].is_object());
+
+    let schemas = document["components"]["schemas"].as_object().unwrap();
+    assert!(schemas.contains_key("V1SolutionResponse"));
+    assert_eq!(
+        vec!["not_queued", "queued", "passed", "failed", "errored", "exceptioned", "cancelled"],
+        enum_values(&schemas["TestsStatus"])
+    );
+
+    let mut found = Vec::new();
+    refs(&document, &mut found);
+    assert!(!found.is_empty());
+    for reference in found {
+        let name = reference.strip_prefix("#/components/schemas/").unwrap();
+        assert!(schemas.contains_key(name), "unresolved reference {reference}");
+    }
+}
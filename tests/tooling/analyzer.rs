@@ -0,0 +1,195 @@
+mod analysis {
+    use std::collections::HashMap;
+
+    use mini_exercism::api::v2::submission::analysis::{
+        AnalyzerComment, AnalyzerCommentType, AnalyzerFeedback,
+    };
+    use mini_exercism::tooling::analyzer::{Analysis, Comment, ValidationError, WebsiteCopy};
+
+    fn templates() -> HashMap<String, String> {
+        HashMap::from([
+            ("rust.general.use_iterators".to_string(), "Use an iterator in `%{name}`.".to_string()),
+            ("rust.general.nice".to_string(), "**Nice** work!".to_string()),
+        ])
+    }
+
+    mod deserialize {
+        use super::*;
+
+        #[test]
+        fn test_all() {
+            let json = r#"{
+                "summary": "Good job overall.",
+                "comments": [
+                    "rust.general.nice",
+                    {
+                        "comment": "rust.general.use_iterators",
+                        "params": { "name": "sum" },
+                        "type": "actionable"
+                    }
+                ]
+            }"#;
+
+            let expected = Analysis {
+                summary: Some("Good job overall.".into()),
+                comments: vec![
+                    Comment::new("rust.general.nice"),
+                    Comment::new("rust.general.use_iterators")
+                        .with_param("name", "sum")
+                        .with_type(AnalyzerCommentType::Actionable),
+                ],
+            };
+            let actual: Analysis = json.parse().unwrap();
+            assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn test_empty() {
+            let actual: Analysis = "{}".parse().unwrap();
+            assert_eq!(Analysis::default(), actual);
+        }
+    }
+
+    mod serialize {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let analysis = Analysis {
+                summary: None,
+                comments: vec![
+                    Comment::new("rust.general.nice"),
+                    Comment::new("rust.general.use_iterators")
+                        .with_param("name", "sum")
+                        .with_type(AnalyzerCommentType::Essential),
+                ],
+            };
+
+            let json = serde_json::to_string(&analysis).unwrap();
+            assert_eq!(
+                r#"{"comments":[{"comment":"rust.general.nice"},{"comment":"rust.general.use_iterators","params":{"name":"sum"},"type":"essential"}]}"#,
+                json
+            );
+            assert_eq!(analysis, json.parse().unwrap());
+        }
+    }
+
+    mod validate {
+        use super::*;
+
+        #[test]
+        fn test_valid() {
+            let analysis = Analysis {
+                summary: None,
+                comments: vec![
+                    Comment::new("rust.two-fer.use_format_macro").with_param("count", 2),
+                ],
+            };
+            assert_eq!(Ok(()), analysis.validate());
+        }
+
+        #[test]
+        fn test_invalid() {
+            let json = r#"{
+                "comments": [
+                    "Rust.General.Nice",
+                    "nice",
+                    { "comment": "rust.general.nice", "type": "mandatory" },
+                    { "comment": "rust.general.use_iterators", "params": { "names": ["a", "b"] } }
+                ]
+            }"#;
+            let analysis: Analysis = json.parse().unwrap();
+
+            let expected = vec![
+                ValidationError::InvalidCommentId("Rust.General.Nice".into()),
+                ValidationError::InvalidCommentId("nice".into()),
+                ValidationError::UnknownCommentType("rust.general.nice".into()),
+                ValidationError::InvalidParam {
+                    comment: "rust.general.use_iterators".into(),
+                    param: "names".into(),
+                },
+            ];
+            assert_eq!(Err(expected), analysis.validate());
+        }
+    }
+
+    mod to_feedback {
+        use super::*;
+
+        #[test]
+        fn test_convert() {
+            let analysis = Analysis {
+                summary: Some("Summary".into()),
+                comments: vec![
+                    Comment::new("rust.general.nice"),
+                    Comment::new("rust.general.use_iterators")
+                        .with_param("name", "sum")
+                        .with_type(AnalyzerCommentType::Actionable),
+                ],
+            };
+
+            let expected = AnalyzerFeedback {
+                summary: Some("Summary".into()),
+                comments: vec![
+                    AnalyzerComment {
+                        comment_type: AnalyzerCommentType::Actionable,
+                        html: "<p>Use an iterator in <code>sum</code>.</p>\n".into(),
+                    },
+                    AnalyzerComment {
+                        comment_type: AnalyzerCommentType::Informative,
+                        html: "<p><strong>Nice</strong> work!</p>\n".into(),
+                    },
+                ],
+            };
+            assert_eq!(Ok(expected), analysis.to_feedback(&templates()));
+        }
+
+        #[test]
+        fn test_missing_template() {
+            let analysis =
+                Analysis { summary: None, comments: vec![Comment::new("rust.general.unknown")] };
+            assert_eq!(
+                Err(ValidationError::MissingTemplate("rust.general.unknown".into())),
+                analysis.to_feedback(&templates())
+            );
+        }
+
+        #[test]
+        fn test_missing_param() {
+            let analysis = Analysis {
+                summary: None,
+                comments: vec![Comment::new("rust.general.use_iterators")],
+            };
+            assert_eq!(
+                Err(ValidationError::MissingParam {
+                    comment: "rust.general.use_iterators".into(),
+                    param: "name".into(),
+                }),
+                analysis.to_feedback(&templates())
+            );
+        }
+
+        #[test]
+        fn test_website_copy() {
+            let root = tempfile::tempdir().unwrap();
+            let dir = root.path().join("analyzer-comments/rust/general");
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("nice.md"), "Nice!").unwrap();
+
+            let analysis =
+                Analysis { summary: None, comments: vec![Comment::new("rust.general.nice")] };
+            let feedback = analysis
+                .to_feedback(&WebsiteCopy::new(root.path()))
+                .unwrap();
+            assert_eq!("<p>Nice!</p>\n", feedback.comments[0].html);
+
+            let analysis =
+                Analysis { summary: None, comments: vec![Comment::new("rust.general.missing")] };
+            assert!(
+                analysis
+                    .to_feedback(&WebsiteCopy::new(root.path()))
+                    .is_err()
+            );
+        }
+    }
+}
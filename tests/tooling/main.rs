@@ -0,0 +1,3 @@
+mod analyzer;
+mod representer;
+mod test_runner;
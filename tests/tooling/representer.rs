@@ -0,0 +1,90 @@
+mod output {
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::tooling::representer::{
+        MAPPING_JSON, Mapping, Metadata, Output, REPRESENTATION_JSON, REPRESENTATION_TXT,
+    };
+
+    #[test]
+    fn test_write_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = Output {
+            representation: "fn PLACEHOLDER_1() {}\n".into(),
+            metadata: Some(Metadata { version: 2 }),
+            mapping: [("PLACEHOLDER_1", "main")].into_iter().collect(),
+        };
+
+        output.write_to(dir.path()).unwrap();
+        assert_eq!(
+            "fn PLACEHOLDER_1() {}\n",
+            std::fs::read_to_string(dir.path().join(REPRESENTATION_TXT)).unwrap()
+        );
+        assert_eq!(output, Output::read_from(dir.path()).unwrap());
+    }
+
+    #[test]
+    fn test_read_without_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(REPRESENTATION_TXT), "x").unwrap();
+        std::fs::write(dir.path().join(MAPPING_JSON), r#"{ "PLACEHOLDER_1": "x" }"#).unwrap();
+
+        let output = Output::read_from(dir.path()).unwrap();
+        assert_eq!(None, output.metadata);
+        assert_eq!(Some("x"), output.mapping.get("PLACEHOLDER_1"));
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_matches!(Output::read_from(dir.path()),
+            Err(Error::ToolingIoError { path, .. }) if path.ends_with(REPRESENTATION_TXT));
+
+        std::fs::write(dir.path().join(REPRESENTATION_TXT), "x").unwrap();
+        std::fs::write(dir.path().join(REPRESENTATION_JSON), "{}").unwrap();
+        assert_matches!(Output::read_from(dir.path()),
+            Err(Error::ToolingFormatError { path, .. }) if path.ends_with(REPRESENTATION_JSON));
+
+        std::fs::write(dir.path().join(REPRESENTATION_JSON), r#"{ "version": 1 }"#).unwrap();
+        assert_matches!(Output::read_from(dir.path()),
+            Err(Error::ToolingIoError { path, .. }) if path.ends_with(MAPPING_JSON));
+    }
+
+    #[test]
+    fn test_mapping_default() {
+        assert!(Mapping::default().placeholders.is_empty());
+    }
+}
+
+mod mapping_diff {
+    use mini_exercism::tooling::representer::{Mapping, MappingDiff};
+
+    #[test]
+    fn test_diff() {
+        let first: Mapping =
+            [("PLACEHOLDER_1", "total"), ("PLACEHOLDER_2", "add"), ("PLACEHOLDER_4", "x")]
+                .into_iter()
+                .collect();
+        let second: Mapping =
+            [("PLACEHOLDER_1", "sum"), ("PLACEHOLDER_3", "i"), ("PLACEHOLDER_4", "x")]
+                .into_iter()
+                .collect();
+
+        let diff = first.diff(&second);
+        let expected = MappingDiff {
+            added: [("PLACEHOLDER_3".into(), "i".into())].into(),
+            removed: [("PLACEHOLDER_2".into(), "add".into())].into(),
+            changed: [("PLACEHOLDER_1".into(), ("total".into(), "sum".into()))].into(),
+        };
+        assert_eq!(expected, diff);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_identical() {
+        let mapping: Mapping = [("PLACEHOLDER_1", "total")].into_iter().collect();
+
+        let diff = mapping.diff(&mapping);
+        assert!(diff.is_empty());
+        assert_eq!("", diff.to_string());
+    }
+}
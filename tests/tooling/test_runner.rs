@@ -0,0 +1,132 @@
+mod results {
+    mod deserialize {
+        use mini_exercism::tooling::test_runner::{DEFAULT_VERSION, Results, Status, TestResult};
+
+        #[test]
+        fn test_version_1() {
+            let json = r#"{
+                "status": "error",
+                "message": "error[E0425]: cannot find function `add` in this scope"
+            }"#;
+
+            let expected = Results {
+                version: DEFAULT_VERSION,
+                status: Status::Error,
+                message: Some("error[E0425]: cannot find function `add` in this scope".into()),
+                tests: vec![],
+            };
+            let actual: Results = json.parse().unwrap();
+            assert_eq!(expected, actual);
+            assert!(!actual.passed());
+        }
+
+        #[test]
+        fn test_version_2() {
+            let json = r#"{
+                "version": 2,
+                "status": "pass",
+                "tests": [
+                    {
+                        "name": "test_hello_world",
+                        "status": "pass",
+                        "test_code": "assert_eq!(\"Hello, World!\", hello());",
+                        "output": "debugging"
+                    }
+                ]
+            }"#;
+
+            let expected = Results {
+                version: 2,
+                status: Status::Pass,
+                message: None,
+                tests: vec![TestResult {
+                    name: "test_hello_world".into(),
+                    status: Status::Pass,
+                    message: None,
+                    output: Some("debugging".into()),
+                    test_code: Some("assert_eq!(\"Hello, World!\", hello());".into()),
+                    task_id: None,
+                }],
+            };
+            let actual: Results = json.parse().unwrap();
+            assert_eq!(expected, actual);
+            assert!(actual.passed());
+            assert_eq!(0, actual.failing_tests().count());
+        }
+
+        #[test]
+        fn test_version_3() {
+            let json = r#"{
+                "version": 3,
+                "status": "fail",
+                "tests": [
+                    { "name": "one", "status": "pass", "test_code": "one()", "task_id": 1 },
+                    { "name": "two", "status": "fail", "message": "expected 2", "test_code": "two()", "task_id": 2 },
+                    { "name": "three", "status": "error", "message": "panicked", "test_code": "three()", "task_id": 2 },
+                    { "name": "four", "status": "skipped", "test_code": "four()" }
+                ]
+            }"#;
+
+            let actual: Results = json.parse().unwrap();
+            assert_eq!(3, actual.version);
+            assert_eq!(Status::Fail, actual.status);
+            assert_eq!(Status::Unknown("skipped".into()), actual.tests[3].status);
+            assert_eq!(
+                vec!["two", "three", "four"],
+                actual
+                    .failing_tests()
+                    .map(|test| test.name.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec!["two", "three"],
+                actual
+                    .tests_for_task(2)
+                    .map(|test| test.name.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert!("{\"version\": 2}".parse::<Results>().is_err());
+        }
+    }
+
+    mod serialize {
+        use mini_exercism::tooling::test_runner::{Results, Status, TestResult};
+
+        #[test]
+        fn test_round_trip() {
+            let results = Results {
+                version: 3,
+                status: Status::Unknown("queued".into()),
+                message: None,
+                tests: vec![TestResult {
+                    name: "four".into(),
+                    status: Status::Unknown("skipped".into()),
+                    message: None,
+                    output: None,
+                    test_code: Some("four()".into()),
+                    task_id: None,
+                }],
+            };
+
+            let json = serde_json::to_string(&results).unwrap();
+            assert!(json.contains(r#""status":"queued""#));
+            assert!(json.contains(r#""status":"skipped""#));
+            assert_eq!(results, json.parse::<Results>().unwrap());
+        }
+    }
+
+    mod status {
+        use mini_exercism::tooling::test_runner::Status;
+
+        #[test]
+        fn test_parse() {
+            assert_eq!(Status::Pass, "pass".parse::<Status>().unwrap());
+            assert_eq!(Status::Unknown("skipped".into()), "skipped".parse::<Status>().unwrap());
+            assert_eq!(Status::Unknown("unknown".into()), "unknown".parse::<Status>().unwrap());
+        }
+    }
+}
@@ -0,0 +1,193 @@
+const CANONICAL_DATA: &str = r#"{
+    "exercise": "leap",
+    "comments": ["Leap years are fun."],
+    "cases": [
+        {
+            "uuid": "6466b30d-519c-438e-935d-388224ab5223",
+            "description": "year not divisible by 4 in common year",
+            "property": "leapYear",
+            "input": { "year": 2015 },
+            "expected": false
+        },
+        {
+            "description": "century years",
+            "cases": [
+                {
+                    "uuid": "78a7848f-9667-4192-ae53-87b30c9a02dd",
+                    "description": "year divisible by 100, not divisible by 400 in common year",
+                    "property": "leapYear",
+                    "input": { "year": 2100 },
+                    "expected": false
+                },
+                {
+                    "description": "far future",
+                    "cases": [
+                        {
+                            "uuid": "42ee56ad-d3e6-48f1-8e3f-c84078d916fc",
+                            "reimplements": "78a7848f-9667-4192-ae53-87b30c9a02dd",
+                            "description": "year divisible by 200, not divisible by 400 in common year",
+                            "comments": ["Reimplemented with a less common year."],
+                            "scenarios": ["big-years"],
+                            "property": "leapYear",
+                            "input": { "year": 1800 },
+                            "expected": false
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+const TESTS_TOML: &str = r#"
+# This is an auto-generated file.
+
+[6466b30d-519c-438e-935d-388224ab5223]
+description = "year not divisible by 4 in common year"
+
+[78a7848f-9667-4192-ae53-87b30c9a02dd]
+description = "year divisible by 100, not divisible by 400 in common year"
+
+[0b0f7cfb-c1be-4a42-8ae5-b7a9a5e8d8e8]
+description = "removed case"
+include = false
+comment = "Not applicable"
+"#;
+
+#[allow(clippy::module_inception)]
+mod canonical_data {
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::track_repo::canonical_data::{CanonicalData, Case};
+    use serde_json::json;
+
+    use super::CANONICAL_DATA;
+
+    #[test]
+    fn test_deserialize() {
+        let data: CanonicalData = CANONICAL_DATA.parse().unwrap();
+
+        assert_eq!("leap", data.exercise);
+        assert_eq!(vec!["Leap years are fun."], data.comments);
+        assert_matches!(&data.cases[0], Case::Test(case) if case.input == json!({ "year": 2015 }));
+        assert_matches!(&data.cases[1], Case::Group(group) if group.description == "century years");
+
+        let test_cases = data.test_cases();
+        assert_eq!(3, test_cases.len());
+        assert_eq!(
+            Some("78a7848f-9667-4192-ae53-87b30c9a02dd"),
+            test_cases[2].reimplements.as_deref()
+        );
+        assert_eq!(vec!["big-years"], test_cases[2].scenarios);
+        assert_eq!(json!(false), test_cases[2].expected);
+    }
+
+    #[test]
+    fn test_read_from() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), CANONICAL_DATA).unwrap();
+        assert_eq!(
+            CANONICAL_DATA.parse::<CanonicalData>().unwrap(),
+            CanonicalData::read_from(file.path()).unwrap()
+        );
+
+        std::fs::write(file.path(), "{}").unwrap();
+        assert_matches!(
+            CanonicalData::read_from(file.path()),
+            Err(Error::TrackRepoFormatError { .. })
+        );
+    }
+}
+
+mod tests_toml {
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::track_repo::canonical_data::{TESTS_TOML_PATH, TestsToml};
+
+    use super::TESTS_TOML;
+
+    #[test]
+    fn test_deserialize() {
+        let tests: TestsToml = TESTS_TOML.parse().unwrap();
+
+        assert_eq!(3, tests.cases.len());
+        assert!(tests.is_included("6466b30d-519c-438e-935d-388224ab5223"));
+        assert!(!tests.is_included("0b0f7cfb-c1be-4a42-8ae5-b7a9a5e8d8e8"));
+        assert!(!tests.is_included("unknown"));
+        assert_eq!(
+            Some("Not applicable"),
+            tests.cases["0b0f7cfb-c1be-4a42-8ae5-b7a9a5e8d8e8"]
+                .comment
+                .as_deref()
+        );
+    }
+
+    #[test]
+    fn test_read_from_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_matches!(TestsToml::read_from(dir.path()), Err(Error::TrackRepoIoError { path, .. }) if path.ends_with(TESTS_TOML_PATH));
+
+        std::fs::create_dir(dir.path().join(".meta")).unwrap();
+        std::fs::write(dir.path().join(TESTS_TOML_PATH), "[uuid\n").unwrap();
+        assert_matches!(TestsToml::read_from(dir.path()), Err(Error::TrackRepoTomlError { .. }));
+    }
+}
+
+mod sync_report {
+    use mini_exercism::track_repo::canonical_data::{SyncReport, TESTS_TOML_PATH, TestsToml};
+
+    use super::{CANONICAL_DATA, TESTS_TOML};
+
+    #[test]
+    fn test_check_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join(".meta")).unwrap();
+        std::fs::write(dir.path().join(TESTS_TOML_PATH), TESTS_TOML).unwrap();
+
+        let report = SyncReport::check_dir(dir.path(), &CANONICAL_DATA.parse().unwrap()).unwrap();
+        assert_eq!(
+            vec!["42ee56ad-d3e6-48f1-8e3f-c84078d916fc"],
+            report
+                .missing
+                .iter()
+                .map(|case| case.uuid.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["42ee56ad-d3e6-48f1-8e3f-c84078d916fc"],
+            report
+                .reimplemented
+                .iter()
+                .map(|case| case.uuid.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["0b0f7cfb-c1be-4a42-8ae5-b7a9a5e8d8e8"],
+            report.excluded.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["0b0f7cfb-c1be-4a42-8ae5-b7a9a5e8d8e8"], report.unknown);
+        assert!(!report.is_in_sync());
+    }
+
+    #[test]
+    fn test_in_sync() {
+        let tests: TestsToml = r#"
+            [6466b30d-519c-438e-935d-388224ab5223]
+            description = "year not divisible by 4 in common year"
+
+            [78a7848f-9667-4192-ae53-87b30c9a02dd]
+            description = "year divisible by 100, not divisible by 400 in common year"
+            include = false
+
+            [42ee56ad-d3e6-48f1-8e3f-c84078d916fc]
+            description = "year divisible by 200, not divisible by 400 in common year"
+            reimplements = "78a7848f-9667-4192-ae53-87b30c9a02dd"
+        "#
+        .parse()
+        .unwrap();
+
+        let report = SyncReport::check(&CANONICAL_DATA.parse().unwrap(), &tests);
+        assert!(report.is_in_sync());
+        assert_eq!(1, report.excluded.len());
+    }
+}
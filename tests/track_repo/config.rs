@@ -0,0 +1,489 @@
+#[allow(clippy::module_inception)]
+mod config {
+    mod deserialize {
+        use mini_exercism::api::v2::tag::{Paradigm, Tag, Typing};
+        use mini_exercism::track_repo::config::{
+            Config, ExerciseStatus, IndentStyle, TestRunner, TrackStatus,
+        };
+
+        #[test]
+        fn test_all() {
+            let json = r#"{
+                "language": "Rust",
+                "slug": "rust",
+                "active": true,
+                "status": {
+                    "concept_exercises": true,
+                    "test_runner": true,
+                    "representer": true,
+                    "analyzer": false
+                },
+                "blurb": "Rust is a systems programming language.",
+                "version": 3,
+                "online_editor": {
+                    "indent_style": "space",
+                    "indent_size": 4,
+                    "highlightjs_language": "rust"
+                },
+                "test_runner": {
+                    "average_run_time": 2.5
+                },
+                "files": {
+                    "solution": ["src/lib.rs", "Cargo.toml"],
+                    "test": ["tests/%{snake_slug}.rs"],
+                    "example": [".meta/example.rs"],
+                    "exemplar": [".meta/exemplar.rs"]
+                },
+                "exercises": {
+                    "concept": [
+                        {
+                            "slug": "lucians-luscious-lasagna",
+                            "name": "Lucian's Luscious Lasagna",
+                            "uuid": "29a2d3bd-eec8-454d-9dba-4b2d7d071925",
+                            "concepts": ["functions"],
+                            "prerequisites": [],
+                            "status": "beta"
+                        }
+                    ],
+                    "practice": [
+                        {
+                            "slug": "hello-world",
+                            "name": "Hello World",
+                            "uuid": "ef2d4e0b-7bd4-4a1a-8b2b-9f6c1b0b2f3c",
+                            "practices": [],
+                            "prerequisites": [],
+                            "difficulty": 1
+                        }
+                    ],
+                    "foregone": ["lens-person"]
+                },
+                "concepts": [
+                    {
+                        "uuid": "fe7a6e4d-6e0b-4d7c-9b3a-1c3e0e7b5c2d",
+                        "slug": "functions",
+                        "name": "Functions"
+                    }
+                ],
+                "key_features": [
+                    {
+                        "title": "Memory safe",
+                        "content": "Rust's ownership model guarantees memory safety.",
+                        "icon": "safe"
+                    }
+                ],
+                "tags": ["paradigm/functional", "typing/static", "future/tag"]
+            }"#;
+
+            let config: Config = json.parse().unwrap();
+            assert_eq!("rust", config.slug);
+            assert_eq!(
+                TrackStatus {
+                    concept_exercises: true,
+                    test_runner: true,
+                    representer: true,
+                    analyzer: false,
+                },
+                config.status
+            );
+            assert_eq!(IndentStyle::Space, config.online_editor.indent_style);
+            assert_eq!(Some(TestRunner { average_run_time: 2.5 }), config.test_runner);
+            assert_eq!(vec!["tests/%{snake_slug}.rs"], config.files.test);
+            assert!(config.files.editor.is_empty());
+            assert_eq!(ExerciseStatus::Beta, config.exercises.concept[0].status);
+            assert_eq!(ExerciseStatus::Active, config.exercises.practice[0].status);
+            assert_eq!(1, config.exercises.practice[0].difficulty);
+            assert_eq!(vec!["lens-person"], config.exercises.foregone);
+            assert_eq!("functions", config.concepts[0].slug);
+            assert_eq!("safe", config.key_features[0].icon);
+            assert_eq!(
+                vec![
+                    Tag::Paradigm(Paradigm::Functional),
+                    Tag::Typing(Typing::Static),
+                    Tag::Unknown("future/tag".into()),
+                ],
+                config.tags
+            );
+        }
+
+        #[test]
+        fn test_minimal() {
+            let json = r#"{
+                "language": "COBOL",
+                "slug": "cobol",
+                "active": false,
+                "status": {
+                    "concept_exercises": false,
+                    "test_runner": false,
+                    "representer": false,
+                    "analyzer": false
+                },
+                "blurb": "",
+                "version": 3,
+                "online_editor": {
+                    "indent_style": "tab",
+                    "indent_size": 8
+                }
+            }"#;
+
+            let config: Config = json.parse().unwrap();
+            assert_eq!(None, config.test_runner);
+            assert_eq!(None, config.online_editor.highlightjs_language);
+            assert_eq!(0, config.exercises.all().count());
+            assert!(config.tags.is_empty());
+        }
+    }
+
+    mod serialize {
+        use mini_exercism::track_repo::config::{Config, ExerciseStatus, IndentStyle};
+
+        #[test]
+        fn test_round_trip_unknown() {
+            let json = r#"{
+                "language": "COBOL",
+                "slug": "cobol",
+                "active": false,
+                "status": {
+                    "concept_exercises": false,
+                    "test_runner": false,
+                    "representer": false,
+                    "analyzer": false
+                },
+                "blurb": "",
+                "version": 3,
+                "online_editor": {
+                    "indent_style": "elastic_tabstop",
+                    "indent_size": 8
+                },
+                "exercises": {
+                    "practice": [
+                        {
+                            "slug": "hello-world",
+                            "name": "Hello World",
+                            "uuid": "ef2d4e0b-7bd4-4a1a-8b2b-9f6c1b0b2f3c",
+                            "practices": [],
+                            "prerequisites": [],
+                            "difficulty": 1,
+                            "status": "archived"
+                        }
+                    ]
+                }
+            }"#;
+
+            let config: Config = json.parse().unwrap();
+            assert_eq!(
+                IndentStyle::Unknown("elastic_tabstop".into()),
+                config.online_editor.indent_style
+            );
+            assert_eq!(
+                ExerciseStatus::Unknown("archived".into()),
+                config.exercises.practice[0].status
+            );
+
+            let serialized = serde_json::to_string(&config).unwrap();
+            assert!(serialized.contains(r#""indent_style":"elastic_tabstop""#));
+            assert!(serialized.contains(r#""status":"archived""#));
+            assert_eq!(config, serialized.parse::<Config>().unwrap());
+        }
+    }
+
+    mod check {
+        use mini_exercism::api::v2::exercise::{Difficulty, Exercise, Type};
+        use mini_exercism::api::v2::tag::{Paradigm, Tag, Typing};
+        use mini_exercism::api::v2::track::Track;
+        use mini_exercism::track_repo::config::{Config, Inconsistency};
+        use serde_json::json;
+
+        #[test]
+        fn test_check_exercises() {
+            let config: Config = serde_json::from_value(json!({
+                "language": "Rust",
+                "slug": "rust",
+                "active": true,
+                "status": {
+                    "concept_exercises": true,
+                    "test_runner": true,
+                    "representer": true,
+                    "analyzer": true
+                },
+                "blurb": "",
+                "version": 3,
+                "online_editor": { "indent_style": "space", "indent_size": 4 },
+                "exercises": {
+                    "concept": [
+                        { "slug": "lasagna", "name": "Lasagna", "uuid": "1" },
+                        { "slug": "semi-structured-logs", "name": "Logs", "uuid": "2" },
+                        { "slug": "wip-concept", "name": "WIP", "uuid": "3", "status": "wip" }
+                    ],
+                    "practice": [
+                        { "slug": "hello-world", "name": "Hello World", "uuid": "4", "difficulty": 1 },
+                        { "slug": "forth", "name": "Forth", "uuid": "5", "difficulty": 8 },
+                        { "slug": "poker", "name": "Poker", "uuid": "6", "difficulty": 6, "status": "beta" },
+                        { "slug": "old", "name": "Old", "uuid": "7", "difficulty": 2, "status": "deprecated" }
+                    ]
+                },
+                "tags": ["paradigm/functional", "typing/static"]
+            }))
+            .unwrap();
+
+            let exercises: Vec<Exercise> = serde_json::from_value(json!([
+                {
+                    "slug": "lasagna",
+                    "type": "concept",
+                    "title": "lasagna",
+                    "icon_url": "https://assets.exercism.org/exercises/lasagna.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/lasagna" }
+                },
+                {
+                    "slug": "hello-world",
+                    "type": "tutorial",
+                    "title": "hello-world",
+                    "icon_url": "https://assets.exercism.org/exercises/hello-world.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/hello-world" }
+                },
+                {
+                    "slug": "forth",
+                    "type": "practice",
+                    "title": "forth",
+                    "icon_url": "https://assets.exercism.org/exercises/forth.svg",
+                    "difficulty": "medium",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/forth" }
+                },
+                {
+                    "slug": "poker",
+                    "type": "concept",
+                    "title": "poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg",
+                    "difficulty": "medium",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/poker" }
+                },
+                {
+                    "slug": "clock",
+                    "type": "practice",
+                    "title": "clock",
+                    "icon_url": "https://assets.exercism.org/exercises/clock.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/clock" }
+                }
+            ]))
+            .unwrap();
+
+            let expected = vec![
+                Inconsistency::MissingFromApi("semi-structured-logs".into()),
+                Inconsistency::DifficultyMismatch {
+                    exercise: "forth".into(),
+                    config: 8,
+                    api: Difficulty::Medium,
+                },
+                Inconsistency::TypeMismatch {
+                    exercise: "poker".into(),
+                    config: Type::Practice,
+                    api: Type::Concept,
+                },
+                Inconsistency::MissingFromConfig("clock".into()),
+            ];
+            assert_eq!(expected, config.check_exercises(&exercises));
+        }
+
+        #[test]
+        fn test_check_exercises_consistent() {
+            let config: Config = serde_json::from_value(json!({
+                "language": "Rust",
+                "slug": "rust",
+                "active": true,
+                "status": {
+                    "concept_exercises": true,
+                    "test_runner": true,
+                    "representer": true,
+                    "analyzer": true
+                },
+                "blurb": "",
+                "version": 3,
+                "online_editor": { "indent_style": "space", "indent_size": 4 },
+                "exercises": {
+                    "concept": [
+                        { "slug": "lasagna", "name": "Lasagna", "uuid": "1" },
+                        { "slug": "semi-structured-logs", "name": "Logs", "uuid": "2" },
+                        { "slug": "wip-concept", "name": "WIP", "uuid": "3", "status": "wip" }
+                    ],
+                    "practice": [
+                        { "slug": "hello-world", "name": "Hello World", "uuid": "4", "difficulty": 1 },
+                        { "slug": "forth", "name": "Forth", "uuid": "5", "difficulty": 8 },
+                        { "slug": "poker", "name": "Poker", "uuid": "6", "difficulty": 6, "status": "beta" },
+                        { "slug": "old", "name": "Old", "uuid": "7", "difficulty": 2, "status": "deprecated" }
+                    ]
+                },
+                "tags": ["paradigm/functional", "typing/static"]
+            }))
+            .unwrap();
+
+            let exercises: Vec<Exercise> = serde_json::from_value(json!([
+                {
+                    "slug": "lasagna",
+                    "type": "concept",
+                    "title": "lasagna",
+                    "icon_url": "https://assets.exercism.org/exercises/lasagna.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/lasagna" }
+                },
+                {
+                    "slug": "semi-structured-logs",
+                    "type": "concept",
+                    "title": "semi-structured-logs",
+                    "icon_url": "https://assets.exercism.org/exercises/semi-structured-logs.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/semi-structured-logs" }
+                },
+                {
+                    "slug": "hello-world",
+                    "type": "tutorial",
+                    "title": "hello-world",
+                    "icon_url": "https://assets.exercism.org/exercises/hello-world.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/hello-world" }
+                },
+                {
+                    "slug": "forth",
+                    "type": "practice",
+                    "title": "forth",
+                    "icon_url": "https://assets.exercism.org/exercises/forth.svg",
+                    "difficulty": "hard",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/forth" }
+                },
+                {
+                    "slug": "poker",
+                    "type": "practice",
+                    "title": "poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg",
+                    "difficulty": "medium",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/poker" }
+                },
+                {
+                    "slug": "old",
+                    "type": "practice",
+                    "title": "old",
+                    "icon_url": "https://assets.exercism.org/exercises/old.svg",
+                    "difficulty": "easy",
+                    "blurb": "",
+                    "is_external": true,
+                    "is_unlocked": true,
+                    "is_recommended": false,
+                    "links": { "self": "/tracks/rust/exercises/old" }
+                }
+            ]))
+            .unwrap();
+
+            assert!(config.check_exercises(&exercises).is_empty());
+        }
+
+        #[test]
+        fn test_check_track() {
+            let config: Config = serde_json::from_value(json!({
+                "language": "Rust",
+                "slug": "rust",
+                "active": true,
+                "status": {
+                    "concept_exercises": true,
+                    "test_runner": true,
+                    "representer": true,
+                    "analyzer": true
+                },
+                "blurb": "",
+                "version": 3,
+                "online_editor": { "indent_style": "space", "indent_size": 4 },
+                "exercises": {
+                    "concept": [
+                        { "slug": "lasagna", "name": "Lasagna", "uuid": "1" },
+                        { "slug": "semi-structured-logs", "name": "Logs", "uuid": "2" },
+                        { "slug": "wip-concept", "name": "WIP", "uuid": "3", "status": "wip" }
+                    ],
+                    "practice": [
+                        { "slug": "hello-world", "name": "Hello World", "uuid": "4", "difficulty": 1 },
+                        { "slug": "forth", "name": "Forth", "uuid": "5", "difficulty": 8 },
+                        { "slug": "poker", "name": "Poker", "uuid": "6", "difficulty": 6, "status": "beta" },
+                        { "slug": "old", "name": "Old", "uuid": "7", "difficulty": 2, "status": "deprecated" }
+                    ]
+                },
+                "tags": ["paradigm/functional", "typing/static"]
+            }))
+            .unwrap();
+
+            let track: Track = serde_json::from_value(json!({
+                "slug": "rust",
+                "title": "Rust (beta)",
+                "num_concepts": 0,
+                "num_exercises": 0,
+                "web_url": "https://exercism.org/tracks/rust",
+                "icon_url": "https://assets.exercism.org/tracks/rust.svg",
+                "tags": ["Functional", "Imperative"],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust",
+                    "exercises": "https://exercism.org/tracks/rust/exercises",
+                    "concepts": "https://exercism.org/tracks/rust/concepts",
+                },
+            }))
+            .unwrap();
+
+            let expected = vec![
+                Inconsistency::TitleMismatch { config: "Rust".into(), api: "Rust (beta)".into() },
+                Inconsistency::TagMissingFromApi(Tag::Typing(Typing::Static)),
+                Inconsistency::TagMissingFromConfig(Tag::Paradigm(Paradigm::Imperative)),
+            ];
+            assert_eq!(expected, config.check_track(&track));
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(
+                "exercise forth has difficulty 8 in config.json but is medium in the API",
+                Inconsistency::DifficultyMismatch {
+                    exercise: "forth".into(),
+                    config: 8,
+                    api: Difficulty::Medium,
+                }
+                .to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#[allow(clippy::module_inception)]
+mod exercise_config {
+    mod deserialize {
+        use mini_exercism::track_repo::exercise_config::{ExerciseConfig, Files};
+
+        #[test]
+        fn test_all() {
+            let json = r#"{
+                "authors": ["EduardoBautista"],
+                "contributors": ["ashleygwilliams", "coriolinus"],
+                "files": {
+                    "solution": ["src/lib.rs", "Cargo.toml"],
+                    "test": ["tests/forth.rs"],
+                    "example": [".meta/example.rs"],
+                    "invalidator": ["Cargo.toml"]
+                },
+                "blurb": "Implement an evaluator for a very simple subset of Forth.",
+                "source": "Exercism",
+                "test_runner": false
+            }"#;
+
+            let expected = ExerciseConfig {
+                authors: vec!["EduardoBautista".into()],
+                contributors: vec!["ashleygwilliams".into(), "coriolinus".into()],
+                files: Files {
+                    solution: vec!["src/lib.rs".into(), "Cargo.toml".into()],
+                    test: vec!["tests/forth.rs".into()],
+                    example: vec![".meta/example.rs".into()],
+                    exemplar: vec![],
+                    editor: vec![],
+                    invalidator: vec!["Cargo.toml".into()],
+                },
+                blurb: "Implement an evaluator for a very simple subset of Forth.".into(),
+                source: Some("Exercism".into()),
+                source_url: None,
+                icon: None,
+                test_runner: Some(false),
+            };
+            let actual: ExerciseConfig = json.parse().unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    mod classify {
+        use std::fs;
+        use std::path::{Path, PathBuf};
+
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::track_repo::exercise_config::{
+            ClassifiedFiles, ExerciseConfig, FileKind,
+        };
+
+        const CONFIG: &str = r#"{
+            "files": {
+                "solution": ["src/lib.rs", "Cargo.toml"],
+                "test": ["tests/lasagna.rs"],
+                "exemplar": [".meta/exemplar.rs"],
+                "editor": ["src/helpers.rs"],
+                "invalidator": ["Cargo.toml", "build.rs"]
+            },
+            "blurb": "Learn about functions while cooking lasagna."
+        }"#;
+
+        fn write(dir: &Path, path: &str, contents: &str) {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        #[test]
+        fn test_classify() {
+            let config: ExerciseConfig = CONFIG.parse().unwrap();
+
+            assert_eq!(Some(FileKind::Solution), config.classify("Cargo.toml"));
+            assert_eq!(Some(FileKind::Exemplar), config.classify(".meta/exemplar.rs"));
+            assert_eq!(Some(FileKind::Editor), config.classify("src/helpers.rs"));
+            assert_eq!(Some(FileKind::Invalidator), config.classify("build.rs"));
+            assert_eq!(None, config.classify("src/main.rs"));
+        }
+
+        #[test]
+        fn test_classify_dir() {
+            let dir = tempfile::tempdir().unwrap();
+            write(dir.path(), ".meta/config.json", CONFIG);
+            write(dir.path(), "src/lib.rs", "");
+            write(dir.path(), "src/helpers.rs", "");
+            write(dir.path(), "tests/lasagna.rs", "");
+            write(dir.path(), "Cargo.toml", "");
+            write(dir.path(), "README.md", "");
+
+            let config = ExerciseConfig::read_from(dir.path()).unwrap();
+            let expected = ClassifiedFiles {
+                solution: vec!["Cargo.toml".into(), "src/lib.rs".into()],
+                test: vec!["tests/lasagna.rs".into()],
+                example: vec![],
+                exemplar: vec![],
+                editor: vec!["src/helpers.rs".into()],
+                other: vec![PathBuf::from(".meta/config.json"), "README.md".into()],
+                missing: vec![".meta/exemplar.rs".into(), "build.rs".into()],
+            };
+            assert_eq!(expected, config.classify_dir(dir.path()).unwrap());
+        }
+
+        #[test]
+        fn test_read_from_downloaded_exercise() {
+            let dir = tempfile::tempdir().unwrap();
+            write(dir.path(), ".exercism/config.json", CONFIG);
+
+            let config = ExerciseConfig::read_from(dir.path()).unwrap();
+            assert_eq!(vec!["tests/lasagna.rs"], config.files.test);
+        }
+
+        #[test]
+        fn test_read_from_errors() {
+            let dir = tempfile::tempdir().unwrap();
+            assert_matches!(ExerciseConfig::read_from(dir.path()),
+                Err(Error::TrackRepoIoError { path, .. }) if path.ends_with(".meta/config.json"));
+
+            write(dir.path(), ".meta/config.json", "{}");
+            assert_matches!(
+                ExerciseConfig::read_from(dir.path()),
+                Err(Error::TrackRepoFormatError { .. })
+            );
+
+            let config: ExerciseConfig = CONFIG.parse().unwrap();
+            assert_matches!(
+                config.classify_dir(dir.path().join("missing")),
+                Err(Error::TrackRepoIoError { .. })
+            );
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod canonical_data;
+mod config;
+mod exercise_config;
@@ -365,4 +365,91 @@ version = "1.1.0"
             assert!(status.database);
         }
     }
+
+    mod raw {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+
+        use super::*;
+
+        async fn mock_server() -> MockServer {
+            let mock_server = MockServer::start().await;
+
+            let ping_response = serde_json::json!({
+                "status": { "website": true, "database": false, "cache": true }
+            });
+            Mock::given(method(http::Method::GET))
+                .and(path("/ping"))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::OK)
+                        .insert_header("x-request-id", "42")
+                        .set_body_json(ping_response),
+                )
+                .mount(&mock_server)
+                .await;
+
+            mock_server
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_ping_with_raw() {
+            let mock_server = mock_server().await;
+            let client = api::v1::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .build()
+                .unwrap();
+
+            let ping_response = client.ping_with_raw().await.unwrap();
+            assert!(ping_response.response.status.website);
+            assert!(!ping_response.response.status.database);
+            assert_eq!(http::StatusCode::OK, ping_response.raw.status);
+            assert_eq!("42", ping_response.raw.headers["x-request-id"]);
+            assert_eq!(format!("{}/ping", mock_server.uri()), ping_response.raw.url.as_str());
+            assert_eq!(
+                serde_json::json!(true),
+                ping_response.raw.json().unwrap()["status"]["cache"]
+            );
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_raw() {
+            let mock_server = mock_server().await;
+            let client = api::v1::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap();
+
+            let raw = client.get_raw("/ping", ()).await.unwrap();
+            assert!(raw.text().contains(r#""cache":true"#));
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_request() {
+            let mock_server = mock_server().await;
+            let client = api::v1::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .build()
+                .unwrap();
+
+            let ping_response: api::v1::ping::Response = client
+                .request(http::Method::GET, "/ping")
+                .execute()
+                .await
+                .unwrap();
+            assert!(ping_response.status.website);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_raw_invalid_path() {
+            let client = api::v1::Client::new().unwrap();
+
+            let result = client.get_raw("ping", ()).await;
+            assert_matches!(result, Err(Error::InvalidPath(path)) if path == "ping");
+        }
+    }
 }
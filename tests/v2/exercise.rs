@@ -57,11 +57,11 @@ mod exercise {
 
             let expected = Exercise {
                 name: "rlyehian".into(),
-                exercise_type: Type::Unknown,
+                exercise_type: Type::Unknown("mglw'nafh".into()),
                 title: "R'lyehian".into(),
                 icon_url: "https://assets.exercism.org/exercises/rlyehian.svg"
                     .into(),
-                difficulty: Difficulty::Unknown,
+                difficulty: Difficulty::Unknown("syha'h".into()),
                 blurb: "Cahf ah nafl mglw'nafh hh' ahor syha'h ah'legeth, ng llll or'azath syha'hnahh n'ghftephai n'gha ahornah ah'mglw'nafh."
                     .into(),
                 is_external: true,
@@ -73,6 +73,10 @@ mod exercise {
             };
             let actual: Exercise = serde_json::from_str(json).unwrap();
             assert_eq!(expected, actual);
+
+            let round_trip = serde_json::to_value(&actual).unwrap();
+            assert_eq!(serde_json::json!("mglw'nafh"), round_trip["type"]);
+            assert_eq!(serde_json::json!("syha'h"), round_trip["difficulty"]);
         }
     }
 }
@@ -92,14 +92,14 @@ mod iteration {
                 uuid: "667beaee5e6d4a67a2679545879e6c3f".into(),
                 submission_uuid: Some("4a41c68afbf343268fe78dd3ce81f44e".into()),
                 index: 2,
-                status: iteration::Status::Unknown,
+                status: iteration::Status::Unknown("or'azath".into()),
                 num_essential_automated_comments: 0,
                 num_actionable_automated_comments: 0,
                 num_non_actionable_automated_comments: 3,
                 num_celebratory_automated_comments: 0,
                 submission_method: "mggoka'drn".into(),
                 created_at: "2023-03-26T05:22:23Z".into(),
-                tests_status: tests::Status::Unknown,
+                tests_status: tests::Status::Unknown("ah'mglw'nafh".into()),
                 representer_feedback: None,
                 analyzer_feedback: None,
                 is_published: true,
@@ -227,3 +227,140 @@ mod links {
         }
     }
 }
+
+mod diff {
+    use mini_exercism::api::v2::iteration::diff::{Diff, DiffStats, FileStatus};
+    use mini_exercism::api::v2::submission::files::File;
+
+    fn file(filename: &str, content: &str) -> File {
+        File {
+            filename: filename.into(),
+            content: content.into(),
+            digest: File::compute_digest(content),
+        }
+    }
+
+    const HELPERS: &str = "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n\npub fn sub(a: i32, b: i32) -> i32 {\n    a - b\n}\n";
+
+    fn old_files() -> Vec<File> {
+        vec![
+            file("src/lib.rs", "mod helpers;\n\npub fn answer() -> i32 {\n    41\n}\n"),
+            file("src/helpers.rs", HELPERS),
+            file("src/unused.rs", "// Nothing to see here\n"),
+            file("Cargo.toml", "[package]\nname = \"answer\"\n"),
+        ]
+    }
+
+    fn new_files() -> Vec<File> {
+        vec![
+            file("src/lib.rs", "mod utils;\n\npub fn answer() -> i32 {\n    42\n}\n"),
+            file(
+                "src/utils.rs",
+                &format!("{HELPERS}\npub fn mul(a: i32, b: i32) -> i32 {{\n    a * b\n}}\n"),
+            ),
+            file("src/new.rs", "pub struct New;\n"),
+            file("Cargo.toml", "[package]\nname = \"answer\"\n"),
+        ]
+    }
+
+    #[test]
+    fn test_between() {
+        let diff = Diff::between(&old_files(), &new_files());
+
+        let summary: Vec<_> = diff
+            .files
+            .iter()
+            .map(|file| (file.status, file.old_filename.as_deref(), file.new_filename.as_deref()))
+            .collect();
+        assert_eq!(
+            vec![
+                (FileStatus::Modified, Some("src/lib.rs"), Some("src/lib.rs")),
+                (FileStatus::Added, None, Some("src/new.rs")),
+                (FileStatus::Removed, Some("src/unused.rs"), None),
+                (FileStatus::Renamed, Some("src/helpers.rs"), Some("src/utils.rs")),
+            ],
+            summary
+        );
+
+        let lib_rs = &diff.files[0];
+        assert_eq!(DiffStats { insertions: 2, deletions: 2 }, lib_rs.stats);
+        let expected = "\
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,5 +1,5 @@
+-mod helpers;
++mod utils;
+ 
+ pub fn answer() -> i32 {
+-    41
++    42
+ }
+";
+        assert_eq!(expected, lib_rs.unified_diff);
+
+        assert_eq!(DiffStats { insertions: 7, deletions: 3 }, diff.stats());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_between_identical() {
+        let diff = Diff::between(&old_files(), &old_files());
+        assert!(diff.is_empty());
+        assert_eq!("0 file(s) changed (+0 -0)\n", diff.to_string());
+    }
+
+    #[test]
+    fn test_renamed_without_changes() {
+        let diff = Diff::between(&[file("src/a.rs", HELPERS)], &[file("src/b.rs", HELPERS)]);
+
+        assert_eq!(1, diff.files.len());
+        assert_eq!(FileStatus::Renamed, diff.files[0].status);
+        assert!(diff.files[0].unified_diff.is_empty());
+        assert_eq!(
+            "renamed src/a.rs => src/b.rs (+0 -0)\n1 file(s) changed (+0 -0)\n",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let diff = Diff::between(
+            &[file("src/lib.rs", "fn a() {}\n")],
+            &[file("src/lib.rs", "fn b() {}\n"), file("README.md", "# B\n")],
+        );
+
+        let expected = "\
+added README.md (+1 -0)
+modified src/lib.rs (+1 -1)
+2 file(s) changed (+2 -1)
+
+--- /dev/null
++++ b/README.md
+@@ -0,0 +1 @@
++# B
+
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1 +1 @@
+-fn a() {}
++fn b() {}
+";
+        assert_eq!(expected, diff.to_string());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let diff = Diff::between(&[], &[file("src/lib.rs", "fn a() {}\n")]);
+
+        let expected = serde_json::json!({
+            "files": [{
+                "status": "added",
+                "old_filename": null,
+                "new_filename": "src/lib.rs",
+                "stats": { "insertions": 1, "deletions": 0 },
+                "unified_diff": "--- /dev/null\n+++ b/src/lib.rs\n@@ -0,0 +1 @@\n+fn a() {}\n"
+            }]
+        });
+        assert_eq!(expected, serde_json::to_value(&diff).unwrap());
+    }
+}
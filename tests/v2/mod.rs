@@ -1,9 +1,14 @@
 mod exercise;
 mod exercises;
 mod iteration;
+mod offline;
+mod pool;
 mod solution;
 mod solutions;
+mod stats;
 mod submission;
+mod tag;
+mod tests;
 mod track;
 mod tracks;
 
@@ -85,7 +90,7 @@ mod client {
             Mock::given(method(http::Method::GET))
                 .and(path("/tracks"))
                 .and(query_param("criteria", "cpp"))
-                .and(query_param("tags[]", "Object-oriented"))
+                .and(query_param("tags[]", "paradigm/object_oriented"))
                 .and(query_param("status", "joined"))
                 .and(bearer_token(API_TOKEN))
                 .respond_with(
@@ -658,6 +663,7 @@ mod client {
     }
 
     mod get_submission_files {
+        use mini_exercism::api::id::{SolutionUuid, SubmissionUuid};
         use mini_exercism::api::v2::submission::files;
         use mini_exercism::api::v2::submission::files::File;
 
@@ -710,5 +716,1028 @@ mod client {
             assert!(cargo_toml.content.contains("edition = \"2021\""));
             assert!(cargo_toml.content.contains("thiserror"));
         }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_files_with_typed_ids() {
+            let mock_server = MockServer::start().await;
+
+            let files_response = files::Response { files: Vec::new() };
+            Mock::given(method(http::Method::GET))
+                .and(path("/solutions/00c717b68e1b4213b316df82636f5e0f/submissions/4da3f19906214f678d5aadaea8635250/files"))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(files_response))
+                .mount(&mock_server)
+                .await;
+
+            let solution_uuid: SolutionUuid = "00c717b68e1b4213b316df82636f5e0f".parse().unwrap();
+            let submission_uuid: SubmissionUuid =
+                "4da3f19906214f678d5aadaea8635250".parse().unwrap();
+
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .build()
+                .unwrap();
+            let files_response = client
+                .get_submission_files(&solution_uuid, submission_uuid)
+                .await
+                .unwrap();
+            assert!(files_response.files.is_empty());
+        }
+    }
+
+    mod get_test_run {
+        use mini_exercism::Error;
+        use mini_exercism::api::v2::offline::Snapshot;
+        use mini_exercism::api::v2::tests::run::Status;
+
+        use super::*;
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_test_run() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/solutions/00c717b68e1b4213b316df82636f5e0f/submissions/4da3f19906214f678d5aadaea8635250/test_run"))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(serde_json::json!({
+                    "test_run": {
+                        "submission_uuid": "4da3f19906214f678d5aadaea8635250",
+                        "version": 2,
+                        "status": "pass",
+                        "tests": [{ "name": "test_add", "status": "pass", "test_code": "add()" }],
+                        "tests_status": "passed"
+                    }
+                })))
+                .mount(&mock_server)
+                .await;
+
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap();
+            let test_run = client
+                .get_test_run(
+                    "00c717b68e1b4213b316df82636f5e0f",
+                    "4da3f19906214f678d5aadaea8635250",
+                )
+                .await
+                .unwrap()
+                .test_run
+                .unwrap();
+            assert_eq!(Status::Pass, test_run.status);
+            assert!(test_run.to_results().passed());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+            let result = client
+                .get_test_run(
+                    "00c717b68e1b4213b316df82636f5e0f",
+                    "4da3f19906214f678d5aadaea8635250",
+                )
+                .await;
+            assert!(matches!(result, Err(Error::OfflineUnsupported("get_test_run"))));
+        }
+    }
+
+    mod diff_iterations {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::v2::iteration::Iteration;
+        use mini_exercism::api::v2::iteration::diff::FileStatus;
+
+        use super::*;
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_diff_iterations() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/solutions/a0c9664059d345ac8d677b0154794ff2/submissions/ab542af6906349ebb37e7cbee4828554/files"))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(serde_json::json!({
+                    "files": [{
+                        "filename": "src/lib.rs",
+                        "content": "pub fn answer() -> i32 {\n    42\n}\n",
+                        "digest": "2edfab2886de7d3aadac30d6aee983e3eb965aed"
+                    }]
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap();
+            let old: Iteration = serde_json::from_value(serde_json::json!({
+                "uuid": "iteration-1",
+                "submission_uuid": "98f8b04515a8484ca211edc7c56d2aa2",
+                "idx": 1,
+                "status": "no_automated_feedback",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 0,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": false,
+                "files": [{
+                    "filename": "src/lib.rs",
+                    "content": "pub fn answer() -> i32 {\n    41\n}\n",
+                    "digest": "2edfab2886de7d3aadac30d6aee983e3eb965aed"
+                }],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations",
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            }))
+            .unwrap();
+            let new: Iteration = serde_json::from_value(serde_json::json!({
+                "uuid": "iteration-2",
+                "submission_uuid": "ab542af6906349ebb37e7cbee4828554",
+                "idx": 2,
+                "status": "no_automated_feedback",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 0,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": false,
+                "files": [],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations",
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            }))
+            .unwrap();
+
+            let diff = client
+                .diff_iterations("a0c9664059d345ac8d677b0154794ff2", &old, &new)
+                .await
+                .unwrap();
+            assert_eq!(1, diff.files.len());
+            assert_eq!(FileStatus::Modified, diff.files[0].status);
+            assert_eq!("src/lib.rs", diff.files[0].filename());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_deleted_iteration() {
+            let client = api::v2::Client::builder()
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap();
+            let old: Iteration = serde_json::from_value(serde_json::json!({
+                "uuid": "iteration-1",
+                "submission_uuid": null,
+                "idx": 1,
+                "status": "no_automated_feedback",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 0,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": false,
+                "files": [],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations",
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            }))
+            .unwrap();
+            let new: Iteration = serde_json::from_value(serde_json::json!({
+                "uuid": "iteration-2",
+                "submission_uuid": null,
+                "idx": 2,
+                "status": "no_automated_feedback",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 0,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": false,
+                "files": [],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations",
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            }))
+            .unwrap();
+
+            let result = client
+                .diff_iterations("a0c9664059d345ac8d677b0154794ff2", &old, &new)
+                .await;
+            assert_matches!(result, Err(Error::IterationDeleted(1)));
+        }
+    }
+
+    mod update_solutions {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::id::TrackSlug;
+        use mini_exercism::api::v2::offline::Snapshot;
+        use wiremock::matchers::body_string;
+
+        use super::*;
+
+        const SOLUTION_UUID: &str = "00c717b68e1b4213b316df82636f5e0f";
+
+        fn solution(uuid: &str, is_out_of_date: bool) -> serde_json::Value {
+            serde_json::json!({
+                "uuid": uuid,
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/clechasseur",
+                "status": "published",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 0,
+                "num_comments": 0,
+                "num_iterations": 2,
+                "is_out_of_date": is_out_of_date,
+                "updated_at": "2023-11-18T06:22:04Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "Poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            })
+        }
+
+        fn diff() -> serde_json::Value {
+            serde_json::json!({
+                "diff": {
+                    "files": [{
+                        "relative_path": "tests/poker.rs",
+                        "diff": "@@ -1 +1,2 @@\n use poker::winning_hands;\n+use std::collections::HashSet;\n"
+                    }],
+                    "links": {
+                        "update": format!("https://exercism.org/api/v2/solutions/{SOLUTION_UUID}/sync")
+                    }
+                }
+            })
+        }
+
+        async fn mock_server(expected_updates: u64) -> MockServer {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/solutions"))
+                .and(query_param("track_slug", "rust"))
+                .and(query_param("sync_status", "out_of_date"))
+                .and(query_param("page", "1"))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                    serde_json::json!({
+                        "results": [solution(SOLUTION_UUID, true)],
+                        "meta": { "current_page": 1, "total_count": 1, "total_pages": 1 }
+                    }),
+                ))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::GET))
+                .and(path(format!("/solutions/{SOLUTION_UUID}/diff")))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(diff()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::PATCH))
+                .and(path(format!("/solutions/{SOLUTION_UUID}/sync")))
+                .and(bearer_token(API_TOKEN))
+                .and(body_string(""))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                    serde_json::json!({ "solution": solution(SOLUTION_UUID, false) }),
+                ))
+                .expect(expected_updates)
+                .mount(&mock_server)
+                .await;
+
+            mock_server
+        }
+
+        fn client(mock_server: &MockServer) -> api::v2::Client {
+            api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap()
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_exercise_diff() {
+            let mock_server = mock_server(0).await;
+
+            let diff = client(&mock_server)
+                .get_exercise_diff(SOLUTION_UUID)
+                .await
+                .unwrap()
+                .diff;
+            assert_eq!(1, diff.files.len());
+            assert_eq!("tests/poker.rs", diff.files[0].relative_path);
+            assert!(
+                diff.files[0]
+                    .diff
+                    .contains("+use std::collections::HashSet;")
+            );
+            assert_eq!(
+                Some(format!("https://exercism.org/api/v2/solutions/{SOLUTION_UUID}/sync")),
+                diff.links.update,
+            );
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_update_solution() {
+            let mock_server = mock_server(1).await;
+
+            let solution = client(&mock_server)
+                .update_solution(SOLUTION_UUID)
+                .await
+                .unwrap()
+                .solution;
+            assert_eq!(SOLUTION_UUID, solution.uuid);
+            assert!(!solution.is_out_of_date);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_update_out_of_date_solutions() {
+            let mock_server = mock_server(1).await;
+
+            let updates = client(&mock_server)
+                .update_out_of_date_solutions(Some("rust"), false)
+                .await
+                .unwrap();
+            assert_eq!(1, updates.len());
+            assert!(updates[0].updated);
+            assert!(!updates[0].solution.is_out_of_date);
+            assert_eq!("tests/poker.rs", updates[0].diff.files[0].relative_path);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_update_out_of_date_solutions_partial_failure() {
+            const FAILING_SOLUTION_UUID: &str = "a0c9664059d345ac8d677b0154794ff2";
+
+            let mock_server = MockServer::start().await;
+            Mock::given(method(http::Method::GET))
+                .and(path("/solutions"))
+                .and(query_param("sync_status", "out_of_date"))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                    serde_json::json!({
+                        "results": [
+                            solution(FAILING_SOLUTION_UUID, true),
+                            solution(SOLUTION_UUID, true),
+                        ],
+                        "meta": { "current_page": 1, "total_count": 2, "total_pages": 1 }
+                    }),
+                ))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::GET))
+                .and(path(format!("/solutions/{FAILING_SOLUTION_UUID}/diff")))
+                .respond_with(ResponseTemplate::new(http::StatusCode::NOT_FOUND))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::GET))
+                .and(path(format!("/solutions/{SOLUTION_UUID}/diff")))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(diff()))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::PATCH))
+                .and(path(format!("/solutions/{SOLUTION_UUID}/sync")))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                    serde_json::json!({ "solution": solution(SOLUTION_UUID, false) }),
+                ))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let updates = client(&mock_server)
+                .update_out_of_date_solutions(None::<&str>, false)
+                .await
+                .unwrap();
+            assert_eq!(2, updates.len());
+
+            assert_eq!(FAILING_SOLUTION_UUID, updates[0].solution.uuid);
+            assert!(!updates[0].updated);
+            assert!(updates[0].diff.files.is_empty());
+            assert_matches!(&updates[0].error, Some(Error::ApiError(_)));
+
+            assert_eq!(SOLUTION_UUID, updates[1].solution.uuid);
+            assert!(updates[1].updated);
+            assert!(updates[1].error.is_none());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_update_out_of_date_solutions_dry_run() {
+            let mock_server = mock_server(0).await;
+
+            let updates = client(&mock_server)
+                .update_out_of_date_solutions(Some(TrackSlug::new("rust").unwrap()), true)
+                .await
+                .unwrap();
+            assert_eq!(1, updates.len());
+            assert!(!updates[0].updated);
+            assert!(updates[0].solution.is_out_of_date);
+            assert_eq!(1, updates[0].diff.files.len());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+
+            assert_matches!(
+                client.get_exercise_diff(SOLUTION_UUID).await,
+                Err(Error::OfflineUnsupported("get_exercise_diff"))
+            );
+            assert_matches!(
+                client.update_solution(SOLUTION_UUID).await,
+                Err(Error::OfflineUnsupported("update_solution"))
+            );
+            assert_matches!(
+                client
+                    .update_out_of_date_solutions(None::<&str>, true)
+                    .await,
+                Err(Error::OfflineUnsupported("update_out_of_date_solutions"))
+            );
+        }
+    }
+
+    mod schema_drift {
+        use std::sync::{Arc, Mutex};
+
+        use mini_exercism::api::schema_drift::{SchemaDrift, SchemaDriftKind};
+
+        use super::*;
+
+        const SOLUTION_UUID: &str = "00c717b68e1b4213b316df82636f5e0f";
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_on_schema_drift() {
+            let mock_server = MockServer::start().await;
+
+            let diff = serde_json::json!({
+                "diff": {
+                    "files": [],
+                    "summary": "no changes"
+                }
+            });
+            Mock::given(method(http::Method::GET))
+                .and(path(format!("/solutions/{SOLUTION_UUID}/diff")))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(diff))
+                .mount(&mock_server)
+                .await;
+
+            let drifts = Arc::new(Mutex::new(Vec::new()));
+            let handler_drifts = Arc::clone(&drifts);
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .on_schema_drift(move |drift| handler_drifts.lock().unwrap().push(drift.clone()))
+                .build()
+                .unwrap();
+
+            let diff = client.get_exercise_diff(SOLUTION_UUID).await.unwrap();
+            assert!(diff.diff.files.is_empty());
+
+            let endpoint = format!("GET /solutions/{SOLUTION_UUID}/diff");
+            let expected = vec![
+                SchemaDrift {
+                    endpoint: endpoint.clone(),
+                    kind: SchemaDriftKind::UnknownField { path: "diff.summary".into() },
+                },
+                SchemaDrift {
+                    endpoint,
+                    kind: SchemaDriftKind::MissingField { path: "diff.links".into() },
+                },
+            ];
+            assert_eq!(expected, *drifts.lock().unwrap());
+            assert_eq!(
+                format!("GET /solutions/{SOLUTION_UUID}/diff: unknown field `diff.summary`"),
+                expected[0].to_string(),
+            );
+        }
+    }
+
+    mod raw {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::v2::offline::Snapshot;
+        use mini_exercism::api::v2::tracks::Filters;
+
+        use super::*;
+
+        async fn mock_server() -> MockServer {
+            let mock_server = MockServer::start().await;
+
+            let tracks = serde_json::json!({
+                "tracks": [],
+                "new_field": { "answer": 42 }
+            });
+            Mock::given(method(http::Method::GET))
+                .and(path("/tracks"))
+                .and(query_param("criteria", "rust"))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(tracks))
+                .mount(&mock_server)
+                .await;
+
+            mock_server
+        }
+
+        fn client(mock_server: &MockServer) -> api::v2::Client {
+            api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap()
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_tracks_with_raw() {
+            let mock_server = mock_server().await;
+
+            let filters = Filters::builder().criteria("rust").build();
+            let tracks = client(&mock_server)
+                .get_tracks_with_raw(Some(filters))
+                .await
+                .unwrap();
+            assert!(tracks.response.tracks.is_empty());
+            assert_eq!(serde_json::json!(42), tracks.raw.json().unwrap()["new_field"]["answer"]);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_raw() {
+            let mock_server = mock_server().await;
+
+            let filters = Filters::builder().criteria("rust").build();
+            let raw = client(&mock_server)
+                .get_raw("/tracks", Some(filters))
+                .await
+                .unwrap();
+            assert_eq!(http::StatusCode::OK, raw.status);
+            assert_eq!(serde_json::json!([]), raw.json().unwrap()["tracks"]);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_raw_error() {
+            let mock_server = mock_server().await;
+
+            let result = client(&mock_server).get_raw("/unknown", ()).await;
+            assert_matches!(result, Err(Error::ApiError(err)) if err.status() == Some(http::StatusCode::NOT_FOUND));
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+
+            assert_matches!(
+                client.get_tracks_with_raw(None).await,
+                Err(Error::OfflineUnsupported("get_tracks_with_raw"))
+            );
+            assert_matches!(
+                client.get_raw("/tracks", ()).await,
+                Err(Error::OfflineUnsupported("request"))
+            );
+        }
+    }
+
+    mod request {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::request::Multipart;
+        use mini_exercism::api::v2::offline::Snapshot;
+        use serde::Deserialize;
+        use wiremock::matchers::{body_json, body_string_contains, header, header_regex};
+
+        use super::*;
+
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct Bookmark {
+            uuid: String,
+        }
+
+        fn client(mock_server: &MockServer) -> api::v2::Client {
+            api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap()
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_json() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::POST))
+                .and(path("/bookmarks"))
+                .and(query_param("source", "test"))
+                .and(bearer_token(API_TOKEN))
+                .and(body_json(serde_json::json!({ "solution_uuid": "some-uuid" })))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::CREATED)
+                        .set_body_json(serde_json::json!({ "uuid": "bookmark-uuid" })),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let bookmark = client(&mock_server)
+                .request(http::Method::POST, "/bookmarks")
+                .query(("source", Some("test")))
+                .json(&serde_json::json!({ "solution_uuid": "some-uuid" }))
+                .execute_with_raw::<Bookmark>()
+                .await
+                .unwrap();
+            assert_eq!(Bookmark { uuid: "bookmark-uuid".into() }, bookmark.response);
+            assert_eq!(http::StatusCode::CREATED, bookmark.raw.status);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_multipart() {
+            let mock_server = MockServer::start().await;
+
+            let form = Multipart::new().text("message", "Hello").file(
+                "files[]",
+                "src/lib.rs",
+                "pub fn hello() {}",
+            );
+            let (content_type, body) = form.encode();
+            let boundary = content_type
+                .strip_prefix("multipart/form-data; boundary=")
+                .unwrap();
+            let expected_body = format!(
+                "--{boundary}\r\n\
+                 Content-Disposition: form-data; name=\"message\"\r\n\r\n\
+                 Hello\r\n\
+                 --{boundary}\r\n\
+                 Content-Disposition: form-data; name=\"files[]\"; filename=\"src/lib.rs\"\r\n\
+                 Content-Type: application/octet-stream\r\n\r\n\
+                 pub fn hello() {{}}\r\n\
+                 --{boundary}--\r\n"
+            );
+            assert_eq!(expected_body.as_bytes(), &body[..]);
+
+            Mock::given(method(http::Method::PATCH))
+                .and(path("/solutions/some-uuid/submissions"))
+                .and(bearer_token(API_TOKEN))
+                .and(header_regex("content-type", "^multipart/form-data; boundary=mini-exercism-"))
+                .and(body_string_contains("pub fn hello() {}"))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_string("ok"))
+                .mount(&mock_server)
+                .await;
+
+            let raw = client(&mock_server)
+                .request(http::Method::PATCH, "/solutions/some-uuid/submissions")
+                .multipart(form)
+                .send_raw()
+                .await
+                .unwrap();
+            assert_eq!("ok", raw.text());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_retry() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::POST))
+                .and(path("/bookmarks"))
+                .respond_with(ResponseTemplate::new(http::StatusCode::SERVICE_UNAVAILABLE))
+                .up_to_n_times(1)
+                .mount(&mock_server)
+                .await;
+            Mock::given(method(http::Method::POST))
+                .and(path("/bookmarks"))
+                .and(header("content-type", "application/json"))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::OK)
+                        .set_body_json(serde_json::json!({ "uuid": "bookmark-uuid" })),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let bookmark: Bookmark = client(&mock_server)
+                .request(http::Method::POST, "/bookmarks")
+                .json(&serde_json::json!({}))
+                .execute()
+                .await
+                .unwrap();
+            assert_eq!("bookmark-uuid", bookmark.uuid);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_invalid_path() {
+            let mock_server = MockServer::start().await;
+
+            let result = client(&mock_server)
+                .request(http::Method::GET, "bookmarks")
+                .send_raw()
+                .await;
+            assert_matches!(result, Err(Error::InvalidPath(path)) if path == "bookmarks");
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+
+            let result = client
+                .request(http::Method::DELETE, "/bookmarks/some-uuid")
+                .send_raw()
+                .await;
+            assert_matches!(result, Err(Error::OfflineUnsupported("request")));
+        }
+    }
+
+    mod follow {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::link::{Link, WebPage};
+        use mini_exercism::api::v2::offline::Snapshot;
+        use mini_exercism::api::v2::tests;
+
+        use super::*;
+
+        fn client(mock_server: &MockServer) -> api::v2::Client {
+            api::v2::Client::builder()
+                .api_base_url(format!("{}/api/v2", mock_server.uri()).as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap()
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_follow() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/api/v2/solutions/some-solution/submissions/some-submission/test_run"))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::OK)
+                        .set_body_json(serde_json::json!({ "test_run": null })),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let link: Link<tests::run::Response> = "https://exercism.org/api/v2/solutions/some-solution/submissions/some-submission/test_run".into();
+            let response = client(&mock_server).follow(&link).await.unwrap();
+            assert_eq!(tests::run::Response { test_run: None }, response);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_follow_raw() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/tracks/rust/exercises/poker"))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::OK).set_body_string("<html></html>"),
+                )
+                .mount(&mock_server)
+                .await;
+
+            let link: Link<WebPage> = "/tracks/rust/exercises/poker".into();
+            let raw = client(&mock_server).follow_raw(&link).await.unwrap();
+            assert_eq!("<html></html>", raw.text());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_follow_other_host() {
+            let mock_server = MockServer::start().await;
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/some/resource"))
+                .respond_with(
+                    ResponseTemplate::new(http::StatusCode::OK)
+                        .set_body_json(serde_json::json!({ "test_run": null })),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let client = api::v2::Client::builder()
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap();
+            let link: Link<tests::run::Response> =
+                format!("{}/some/resource", mock_server.uri()).into();
+            let response = client.follow(&link).await.unwrap();
+            assert_eq!(tests::run::Response { test_run: None }, response);
+
+            let requests = mock_server.received_requests().await.unwrap();
+            assert_eq!(1, requests.len());
+            assert!(
+                !requests[0]
+                    .headers
+                    .contains_key(http::header::AUTHORIZATION)
+            );
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+
+            let link: Link<WebPage> = "/tracks/rust".into();
+            let result = client.follow_raw(&link).await;
+            assert_matches!(result, Err(Error::OfflineUnsupported("follow_raw")));
+        }
+    }
+
+    mod get_automated_feedback {
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+        use mini_exercism::api::v2::iteration::Iteration;
+        use mini_exercism::api::v2::offline::Snapshot;
+        use serde_json::Value;
+
+        use super::*;
+
+        fn client(mock_server: &MockServer) -> api::v2::Client {
+            api::v2::Client::builder()
+                .api_base_url(format!("{}/api/v2", mock_server.uri()).as_str())
+                .credentials(Credentials::from_api_token(API_TOKEN))
+                .build()
+                .unwrap()
+        }
+
+        fn iteration_json(uuid: &str, with_feedback: bool) -> Value {
+            let mut json = serde_json::json!({
+                "uuid": uuid,
+                "submission_uuid": "ab542af6906349ebb37e7cbee4828554",
+                "idx": 2,
+                "status": "non_actionable_automated_feedback",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 1,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": true,
+                "is_latest": true,
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations?idx=2",
+                    "automated_feedback": format!("https://exercism.org/api/v2/solutions/a0c9664059d345ac8d677b0154794ff2/iterations/{uuid}/automated_feedback"),
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            });
+            if with_feedback {
+                json["analyzer_feedback"] = serde_json::json!({
+                    "summary": "Nice work!",
+                    "comments": []
+                });
+            }
+            json
+        }
+
+        async fn mount_automated_feedback(mock_server: &MockServer, iteration_uuid: &str) {
+            Mock::given(method(http::Method::GET))
+                .and(path(format!(
+                    "/api/v2/solutions/a0c9664059d345ac8d677b0154794ff2/iterations/{iteration_uuid}/automated_feedback"
+                )))
+                .and(bearer_token(API_TOKEN))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                    serde_json::json!({ "iteration": iteration_json(iteration_uuid, true) }),
+                ))
+                .expect(1)
+                .named(format!("automated feedback of {iteration_uuid}"))
+                .mount(mock_server)
+                .await;
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_get_automated_feedback() {
+            let mock_server = MockServer::start().await;
+            mount_automated_feedback(&mock_server, "98f8b04515a8484ca211edc7c56d2aa2").await;
+
+            let response = client(&mock_server)
+                .get_automated_feedback(
+                    "a0c9664059d345ac8d677b0154794ff2",
+                    "98f8b04515a8484ca211edc7c56d2aa2",
+                )
+                .await
+                .unwrap();
+            assert!(response.iteration.representer_feedback.is_none());
+            let analyzer_feedback = response.iteration.analyzer_feedback.unwrap();
+            assert_eq!(Some("Nice work!".into()), analyzer_feedback.summary);
+            assert!(analyzer_feedback.comments.is_empty());
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_fill_automated_feedback() {
+            let mock_server = MockServer::start().await;
+            let iteration_uuids = [
+                "98f8b04515a8484ca211edc7c56d2aa2",
+                "667beaee5e6d4a67a2679545879e6c3f",
+                "da0ae7d7b6804c49ba988197ee88f072",
+            ];
+            for iteration_uuid in &iteration_uuids {
+                mount_automated_feedback(&mock_server, iteration_uuid).await;
+            }
+
+            let mut iterations: Vec<Iteration> = iteration_uuids
+                .iter()
+                .map(|uuid| serde_json::from_value(iteration_json(uuid, false)).unwrap())
+                .collect();
+            let deleted: Iteration = serde_json::from_value(serde_json::json!({
+                "uuid": "0c1f1ae7b0ba4d5c9fc4ac1d3b4f6a3e",
+                "idx": 7,
+                "status": "deleted",
+                "num_essential_automated_comments": 0,
+                "num_actionable_automated_comments": 0,
+                "num_non_actionable_automated_comments": 0,
+                "num_celebratory_automated_comments": 0,
+                "submission_method": "cli",
+                "created_at": "2023-03-26T05:22:23Z",
+                "tests_status": "passed",
+                "is_published": false,
+                "links": {
+                    "self": "https://exercism.org/tracks/rust/exercises/clock/iterations?idx=7",
+                    "solution": "https://exercism.org/tracks/rust/exercises/clock"
+                }
+            }))
+            .unwrap();
+            iterations.push(deleted.clone());
+
+            client(&mock_server)
+                .fill_automated_feedback(&mut iterations)
+                .await
+                .unwrap();
+
+            for (iteration, uuid) in iterations.iter().zip(iteration_uuids) {
+                assert_eq!(uuid, iteration.uuid);
+                assert_matches!(&iteration.analyzer_feedback, Some(feedback) if feedback.summary.as_deref() == Some("Nice work!"));
+            }
+            assert_eq!(deleted, iterations[3]);
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_offline() {
+            let client = api::v2::Client::builder()
+                .offline(Snapshot::default())
+                .build()
+                .unwrap();
+
+            let result = client
+                .get_automated_feedback("some-solution", "some-iteration")
+                .await;
+            assert_matches!(result, Err(Error::OfflineUnsupported("get_automated_feedback")));
+        }
     }
 }
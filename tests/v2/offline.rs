@@ -0,0 +1,508 @@
+mod snapshot {
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::api;
+    use mini_exercism::api::id::{SubmissionUuid, TrackSlug};
+    use mini_exercism::api::v2::offline::Snapshot;
+    use mini_exercism::api::v2::solution::{MentoringStatus, Solution, Status};
+    use mini_exercism::api::v2::solutions::{Filters, Paging, SortOrder};
+    use mini_exercism::api::v2::track::Track;
+    use mini_exercism::api::v2::{exercises, solution, submission, tests, tracks};
+    use serde_json::json;
+
+    fn snapshot() -> Snapshot {
+        let tracks: Vec<Track> = serde_json::from_value(json!([
+            {
+                "slug": "go",
+                "title": "Go",
+                "num_concepts": 0,
+                "num_exercises": 0,
+                "web_url": "https://exercism.org/tracks/go",
+                "icon_url": "https://assets.exercism.org/tracks/go.svg",
+                "tags": ["paradigm/imperative"],
+                "links": {
+                    "self": "https://exercism.org/tracks/go",
+                    "exercises": "https://exercism.org/tracks/go/exercises",
+                    "concepts": "https://exercism.org/tracks/go/concepts"
+                },
+                "is_joined": true
+            },
+            {
+                "slug": "rust",
+                "title": "Rust",
+                "num_concepts": 0,
+                "num_exercises": 0,
+                "web_url": "https://exercism.org/tracks/rust",
+                "icon_url": "https://assets.exercism.org/tracks/rust.svg",
+                "tags": ["paradigm/functional", "paradigm/imperative"],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust",
+                    "exercises": "https://exercism.org/tracks/rust/exercises",
+                    "concepts": "https://exercism.org/tracks/rust/concepts"
+                },
+                "is_joined": true
+            },
+            {
+                "slug": "cobol",
+                "title": "COBOL",
+                "num_concepts": 0,
+                "num_exercises": 0,
+                "web_url": "https://exercism.org/tracks/cobol",
+                "icon_url": "https://assets.exercism.org/tracks/cobol.svg",
+                "tags": ["paradigm/imperative"],
+                "links": {
+                    "self": "https://exercism.org/tracks/cobol",
+                    "exercises": "https://exercism.org/tracks/cobol/exercises",
+                    "concepts": "https://exercism.org/tracks/cobol/concepts"
+                },
+                "is_joined": false
+            }
+        ]))
+        .unwrap();
+        let solutions: Vec<Solution> = serde_json::from_value(json!([
+            {
+                "uuid": "s1",
+                "private_url": "https://exercism.org/tracks/rust/exercises/hello-world",
+                "public_url": "https://exercism.org/tracks/rust/exercises/hello-world/solutions/user",
+                "status": "published",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 1,
+                "is_out_of_date": false,
+                "updated_at": "2025-01-01T00:00:00Z",
+                "exercise": {
+                    "slug": "hello-world",
+                    "title": "HELLO-WORLD",
+                    "icon_url": "https://assets.exercism.org/exercises/hello-world.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "RUST",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            },
+            {
+                "uuid": "s2",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/user",
+                "status": "completed",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 0,
+                "num_comments": 0,
+                "num_iterations": 1,
+                "is_out_of_date": false,
+                "updated_at": "2025-01-01T00:00:00Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "POKER",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "RUST",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            },
+            {
+                "uuid": "s3",
+                "private_url": "https://exercism.org/tracks/go/exercises/hello-world",
+                "public_url": "https://exercism.org/tracks/go/exercises/hello-world/solutions/user",
+                "status": "published",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 1,
+                "is_out_of_date": false,
+                "updated_at": "2025-01-01T00:00:00Z",
+                "exercise": {
+                    "slug": "hello-world",
+                    "title": "HELLO-WORLD",
+                    "icon_url": "https://assets.exercism.org/exercises/hello-world.svg"
+                },
+                "track": {
+                    "slug": "go",
+                    "title": "GO",
+                    "icon_url": "https://assets.exercism.org/tracks/go.svg"
+                }
+            },
+            {
+                "uuid": "s4",
+                "private_url": "https://exercism.org/tracks/go/exercises/poker",
+                "public_url": "https://exercism.org/tracks/go/exercises/poker/solutions/user",
+                "status": "iterated",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 5,
+                "num_comments": 0,
+                "num_iterations": 1,
+                "is_out_of_date": false,
+                "updated_at": "2025-01-01T00:00:00Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "POKER",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "go",
+                    "title": "GO",
+                    "icon_url": "https://assets.exercism.org/tracks/go.svg"
+                }
+            }
+        ]))
+        .unwrap();
+        let solution_details = solutions
+            .iter()
+            .map(|solution| {
+                (
+                    solution.uuid.clone(),
+                    solution::Response { solution: solution.clone(), iterations: Vec::new() },
+                )
+            })
+            .collect();
+
+        Snapshot {
+            tracks: Some(tracks),
+            exercises: [(
+                TrackSlug::from("rust"),
+                exercises::Response { exercises: Vec::new(), solutions: solutions[..2].to_vec() },
+            )]
+            .into(),
+            solutions: Some(solutions),
+            solution_details,
+            submission_files: [(
+                SubmissionUuid::from("sub1"),
+                submission::files::Response { files: Vec::new() },
+            )]
+            .into(),
+        }
+    }
+
+    fn client(snapshot: Snapshot) -> api::v2::Client {
+        api::v2::Client::builder()
+            .offline(snapshot)
+            .build()
+            .unwrap()
+    }
+
+    fn uuids(response: &api::v2::solutions::Response) -> Vec<&str> {
+        response
+            .results
+            .iter()
+            .map(|solution| solution.uuid.as_str())
+            .collect()
+    }
+
+    #[test]
+    #[test_log::test]
+    fn test_is_offline() {
+        assert!(client(Snapshot::default()).is_offline());
+        assert!(!api::v2::Client::new().unwrap().is_offline());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_not_synced() {
+        let client = client(Snapshot::default());
+
+        assert_matches!(client.get_tracks(None).await, Err(Error::NotSynced(what)) if what == "tracks");
+        assert_matches!(client.get_exercises("rust", None).await, Err(Error::NotSynced(_)));
+        assert_matches!(client.get_solutions(None, None, None).await, Err(Error::NotSynced(_)));
+        assert_matches!(client.get_solution("s1", false).await, Err(Error::NotSynced(_)));
+        assert_matches!(client.get_submission_files("s1", "sub1").await, Err(Error::NotSynced(_)));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_tracks() {
+        let client = client(snapshot());
+
+        let filters = tracks::Filters::builder()
+            .status(tracks::StatusFilter::Joined)
+            .tag("paradigm/functional")
+            .build();
+        let tracks = client.get_tracks(Some(filters)).await.unwrap().tracks;
+        assert_eq!(vec!["rust"], tracks.iter().map(|t| t.name.as_str()).collect::<Vec<_>>());
+
+        let filters = tracks::Filters::builder().criteria("CoB").build();
+        let tracks = client.get_tracks(Some(filters)).await.unwrap().tracks;
+        assert_eq!(vec!["cobol"], tracks.iter().map(|t| t.name.as_str()).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_exercises() {
+        let client = client(snapshot());
+
+        let response = client.get_exercises("rust", None).await.unwrap();
+        assert!(response.solutions.is_empty());
+
+        let filters = exercises::Filters::builder()
+            .include_solutions(true)
+            .build();
+        let response = client.get_exercises("rust", Some(filters)).await.unwrap();
+        assert_eq!(2, response.solutions.len());
+
+        assert_matches!(client.get_exercises("cobol", None).await, Err(Error::NotSynced(_)));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_solutions_sort_order() {
+        let client = client(snapshot());
+
+        let response = client.get_solutions(None, None, None).await.unwrap();
+        assert_eq!(vec!["s4", "s3", "s1", "s2"], uuids(&response));
+
+        let response = client
+            .get_solutions(None, None, Some(SortOrder::NewestFirst))
+            .await
+            .unwrap();
+        assert_eq!(vec!["s4", "s3", "s2", "s1"], uuids(&response));
+
+        let response = client
+            .get_solutions(None, None, Some(SortOrder::OldestFirst))
+            .await
+            .unwrap();
+        assert_eq!(vec!["s1", "s2", "s3", "s4"], uuids(&response));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_solutions_filters() {
+        let client = client(snapshot());
+
+        let filters = Filters::builder()
+            .track("go")
+            .status(Status::Published)
+            .build();
+        let response = client
+            .get_solutions(Some(filters), None, None)
+            .await
+            .unwrap();
+        assert_eq!(vec!["s3"], uuids(&response));
+
+        let filters = Filters::builder().criteria("pOk").build();
+        let response = client
+            .get_solutions(Some(filters), None, Some(SortOrder::OldestFirst))
+            .await
+            .unwrap();
+        assert_eq!(vec!["s2", "s4"], uuids(&response));
+
+        let filters = Filters::builder()
+            .mentoring_status(MentoringStatus::Requested)
+            .build();
+        let response = client
+            .get_solutions(Some(filters), None, None)
+            .await
+            .unwrap();
+        assert!(response.results.is_empty());
+
+        let filters = Filters::builder()
+            .out_of_date()
+            .published_iteration_tests_status(tests::Status::Passed)
+            .build();
+        let response = client
+            .get_solutions(Some(filters), None, None)
+            .await
+            .unwrap();
+        assert!(response.results.is_empty());
+
+        let filters = Filters::builder()
+            .up_to_date()
+            .published_iteration_head_tests_status(tests::Status::Passed)
+            .build();
+        let response = client
+            .get_solutions(Some(filters), None, None)
+            .await
+            .unwrap();
+        assert_eq!(4, response.meta.total_count);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_solutions_paging() {
+        let client = client(snapshot());
+
+        let paging = Paging::for_page(2).and_per_page(3);
+        let response = client
+            .get_solutions(None, Some(paging), Some(SortOrder::OldestFirst))
+            .await
+            .unwrap();
+        assert_eq!(vec!["s4"], uuids(&response));
+        assert_eq!(2, response.meta.current_page);
+        assert_eq!(4, response.meta.total_count);
+        assert_eq!(2, response.meta.total_pages);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_solution() {
+        let client = client(snapshot());
+
+        let response = client.get_solution("s2", false).await.unwrap();
+        assert_eq!("s2", response.solution.uuid);
+        assert!(response.iterations.is_empty());
+
+        let files = client.get_submission_files("s1", "sub1").await.unwrap();
+        assert!(files.files.is_empty());
+    }
+
+    #[test]
+    #[test_log::test]
+    fn test_save_and_load() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let snapshot = snapshot();
+
+        snapshot.save(file.path()).unwrap();
+        assert_eq!(snapshot, Snapshot::load(file.path()).unwrap());
+    }
+
+    #[test]
+    #[test_log::test]
+    fn test_load_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.json");
+        assert_matches!(Snapshot::load(&missing), Err(Error::SnapshotIoError { path, .. }) if path == missing);
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "not json").unwrap();
+        assert_matches!(Snapshot::load(file.path()), Err(Error::SnapshotFormatError(_)));
+    }
+
+    mod sync {
+        use mini_exercism::api::v2::offline::SyncOptions;
+        use mini_exercism::core::Credentials;
+        use mini_exercism::http;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        use super::*;
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_sync() {
+            let mock_server = MockServer::start().await;
+
+            let tracks: Vec<Track> = serde_json::from_value(json!([
+                {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "num_concepts": 0,
+                    "num_exercises": 0,
+                    "web_url": "https://exercism.org/tracks/rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg",
+                    "tags": [],
+                    "links": {
+                        "self": "https://exercism.org/tracks/rust",
+                        "exercises": "https://exercism.org/tracks/rust/exercises",
+                        "concepts": "https://exercism.org/tracks/rust/concepts"
+                    },
+                    "is_joined": true
+                },
+                {
+                    "slug": "go",
+                    "title": "Go",
+                    "num_concepts": 0,
+                    "num_exercises": 0,
+                    "web_url": "https://exercism.org/tracks/go",
+                    "icon_url": "https://assets.exercism.org/tracks/go.svg",
+                    "tags": [],
+                    "links": {
+                        "self": "https://exercism.org/tracks/go",
+                        "exercises": "https://exercism.org/tracks/go/exercises",
+                        "concepts": "https://exercism.org/tracks/go/concepts"
+                    },
+                    "is_joined": false
+                }
+            ]))
+            .unwrap();
+            Mock::given(method("GET"))
+                .and(path("/tracks"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "tracks": tracks })))
+                .mount(&mock_server)
+                .await;
+
+            let rust_solution: Solution = serde_json::from_value(json!({
+                "uuid": "s1",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/user",
+                "status": "published",
+                "mentoring_status": "none",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 0,
+                "num_comments": 0,
+                "num_iterations": 1,
+                "is_out_of_date": false,
+                "updated_at": "2025-01-01T00:00:00Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "POKER",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "RUST",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            }))
+            .unwrap();
+            Mock::given(method("GET"))
+                .and(path("/tracks/rust/exercises"))
+                .and(query_param("sideload", "solutions"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "exercises": [],
+                    "solutions": [rust_solution],
+                })))
+                .mount(&mock_server)
+                .await;
+
+            Mock::given(method("GET"))
+                .and(path("/solutions"))
+                .and(query_param("order", "oldest_first"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "results": [rust_solution],
+                    "meta": { "current_page": 1, "total_count": 1, "total_pages": 1 },
+                })))
+                .mount(&mock_server)
+                .await;
+
+            Mock::given(method("GET"))
+                .and(path("/solutions/s1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "solution": rust_solution,
+                })))
+                .mount(&mock_server)
+                .await;
+
+            let online_client = api::v2::Client::builder()
+                .http_client(http::Client::new())
+                .api_base_url(mock_server.uri().as_str())
+                .credentials(Credentials::from_api_token("some_api_token"))
+                .build()
+                .unwrap();
+            let snapshot = Snapshot::sync(&online_client, &SyncOptions::default())
+                .await
+                .unwrap();
+
+            assert_eq!(Some(tracks), snapshot.tracks);
+            assert_eq!(vec!["rust"], snapshot.exercises.keys().collect::<Vec<_>>());
+            assert_eq!(Some(vec![rust_solution]), snapshot.solutions);
+            assert!(snapshot.solution_details.contains_key("s1"));
+            assert!(snapshot.submission_files.is_empty());
+        }
+    }
+}
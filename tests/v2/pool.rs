@@ -0,0 +1,143 @@
+mod client_pool {
+    use mini_exercism::api;
+    use mini_exercism::api::v2::pool::{ClientPool, DEFAULT_MAX_CONCURRENCY};
+    use mini_exercism::core::Credentials;
+    use mini_exercism::http;
+    use wiremock::matchers::{bearer_token, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    mod builder {
+        use super::*;
+
+        #[test]
+        #[test_log::test]
+        fn test_build() {
+            let pool = ClientPool::builder()
+                .account("alice", Credentials::from_api_token("alice_token"))
+                .account("bob", Credentials::from_api_token("bob_token"))
+                .account("alice", Credentials::from_api_token("other_alice_token"))
+                .max_concurrency(0)
+                .build()
+                .unwrap();
+
+            assert_eq!(vec!["alice", "bob"], pool.accounts().collect::<Vec<_>>());
+            assert_eq!(2, pool.len());
+            assert!(!pool.is_empty());
+            assert_eq!(1, pool.max_concurrency());
+            assert!(pool.client("alice").is_some());
+            assert!(pool.client("carol").is_none());
+        }
+
+        #[test]
+        #[test_log::test]
+        fn test_default() {
+            let pool = ClientPool::builder().build().unwrap();
+
+            assert!(pool.is_empty());
+            assert_eq!(DEFAULT_MAX_CONCURRENCY, pool.max_concurrency());
+        }
+    }
+
+    mod fan_out {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        use api::v2::tracks;
+        use assert_matches::assert_matches;
+        use mini_exercism::Error;
+
+        use super::*;
+
+        async fn mock_tracks_for(mock_server: &MockServer, api_token: &str, num_tracks: usize) {
+            let track_json = serde_json::json!({
+                "slug": "rust",
+                "title": "Rust",
+                "num_concepts": 0,
+                "num_exercises": 0,
+                "web_url": "https://exercism.org/tracks/rust",
+                "icon_url": "https://assets.exercism.org/tracks/rust.svg",
+                "tags": [],
+                "links": {
+                    "self": "https://exercism.org/tracks/rust",
+                    "exercises": "https://exercism.org/tracks/rust/exercises",
+                    "concepts": "https://exercism.org/tracks/rust/concepts"
+                }
+            });
+            let body = serde_json::json!({ "tracks": vec![track_json; num_tracks] });
+
+            Mock::given(method(http::Method::GET))
+                .and(path("/tracks"))
+                .and(bearer_token(api_token))
+                .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(body))
+                .mount(mock_server)
+                .await;
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_fan_out() {
+            let mock_server = MockServer::start().await;
+            mock_tracks_for(&mock_server, "alice_token", 1).await;
+            mock_tracks_for(&mock_server, "bob_token", 2).await;
+
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .num_retries(0)
+                .build()
+                .unwrap();
+            let pool = ClientPool::builder()
+                .client(client)
+                .account("alice", Credentials::from_api_token("alice_token"))
+                .account("carol", Credentials::from_api_token("invalid_token"))
+                .account("bob", Credentials::from_api_token("bob_token"))
+                .max_concurrency(2)
+                .build()
+                .unwrap();
+
+            let results = pool
+                .fan_out(|client| async move {
+                    let response: tracks::Response = client.get_tracks(None).await?;
+                    Ok(response.tracks.len())
+                })
+                .await;
+
+            assert_eq!(3, results.len());
+            assert_eq!("alice", results[0].account);
+            assert_matches!(results[0].result, Ok(1));
+            assert_eq!("carol", results[1].account);
+            assert_matches!(&results[1].result, Err(Error::ApiError(err)) => {
+                assert_eq!(Some(http::StatusCode::NOT_FOUND), err.status());
+            });
+            assert_eq!("bob", results[2].account);
+            assert_matches!(results[2].result, Ok(2));
+        }
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_shared_limit() {
+            let pool = ClientPool::builder()
+                .account("alice", Credentials::from_api_token("alice_token"))
+                .account("bob", Credentials::from_api_token("bob_token"))
+                .max_concurrency(1)
+                .build()
+                .unwrap();
+
+            let running = AtomicUsize::new(0);
+            let max_running = AtomicUsize::new(0);
+            let op = |_| async {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            };
+
+            let other_pool = pool.clone();
+            let (results, other_results) = tokio::join!(pool.fan_out(op), other_pool.fan_out(op));
+
+            assert_eq!(2, results.len());
+            assert_eq!(2, other_results.len());
+            assert_eq!(1, max_running.load(Ordering::SeqCst));
+        }
+    }
+}
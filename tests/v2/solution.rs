@@ -246,9 +246,11 @@ mod solution {
                 public_url:
                     "https://exercism.org/tracks/rust/exercises/rlyehian/solutions/clechasseur"
                         .into(),
-                status: Status::Unknown,
-                mentoring_status: MentoringStatus::Unknown,
-                published_iteration_head_tests_status: tests::Status::Unknown,
+                status: Status::Unknown("syha'hnahh".into()),
+                mentoring_status: MentoringStatus::Unknown("or'azath".into()),
+                published_iteration_head_tests_status: tests::Status::Unknown(
+                    "ah'mglw'nafh".into(),
+                ),
                 has_notifications: false,
                 num_views: 0,
                 num_stars: 0,
@@ -273,6 +275,11 @@ mod solution {
             };
             let actual: Solution = serde_json::from_str(json).unwrap();
             assert_eq!(expected, actual);
+
+            let round_trip = serde_json::to_value(&actual).unwrap();
+            assert_eq!(serde_json::from_str::<serde_json::Value>(json).unwrap(), round_trip);
+            assert_eq!("syha'hnahh", actual.status.to_string());
+            assert_eq!("or'azath", actual.mentoring_status.as_ref());
         }
     }
 }
@@ -322,3 +329,25 @@ mod track {
         }
     }
 }
+
+mod status {
+    mod parse {
+        use mini_exercism::api::v2::solution::{MentoringStatus, Status};
+
+        #[test]
+        fn test_known() {
+            assert_eq!(Status::Published, "published".parse::<Status>().unwrap());
+            assert_eq!(MentoringStatus::Finished, "finished".parse::<MentoringStatus>().unwrap());
+        }
+
+        #[test]
+        fn test_unknown() {
+            assert_eq!(Status::Unknown("archived".into()), "archived".parse::<Status>().unwrap());
+            assert_eq!(Status::Unknown("unknown".into()), "unknown".parse::<Status>().unwrap());
+            assert_eq!(
+                MentoringStatus::Unknown("paused".into()),
+                "paused".parse::<MentoringStatus>().unwrap()
+            );
+        }
+    }
+}
@@ -220,3 +220,349 @@ mod response_meta {
         }
     }
 }
+
+mod query {
+    use mini_exercism::api::v2::solution::{MentoringStatus, Solution, Status};
+    use mini_exercism::api::v2::solutions::query::{
+        Comparison, CountField, DateField, Operator, ParseError, Predicate, Query,
+    };
+    use mini_exercism::api::v2::tests;
+    use rstest::rstest;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_all_keys() {
+            let query: Query = r#"-"hello world" track:rust,go exercise:poker status:published
+                mentoring:finished head_tests:passed,failed out_of_date:false notifications:true
+                iterations>3 stars>=1 views<10 comments<=5 loc!=0 completed:2025
+                published=2025-01 updated>2024 iterated<2026"#
+                .parse()
+                .unwrap();
+
+            let expected = Query::builder()
+                .predicate(!Predicate::Criteria("hello world".into()))
+                .tracks(["rust", "go"])
+                .exercise("poker")
+                .status(Status::Published)
+                .mentoring_status(MentoringStatus::Finished)
+                .predicate(Predicate::HeadTestsStatus(vec![
+                    tests::Status::Passed,
+                    tests::Status::Failed,
+                ]))
+                .out_of_date(false)
+                .predicate(Predicate::HasNotifications(true))
+                .count(CountField::Iterations, Operator::Gt, 3)
+                .count(CountField::Stars, Operator::Ge, 1)
+                .count(CountField::Views, Operator::Lt, 10)
+                .count(CountField::Comments, Operator::Le, 5)
+                .count(CountField::Loc, Operator::Ne, 0)
+                .date(DateField::Completed, Operator::Eq, "2025")
+                .date(DateField::Published, Operator::Eq, "2025-01")
+                .date(DateField::Updated, Operator::Gt, "2024")
+                .date(DateField::LastIterated, Operator::Lt, "2026")
+                .build();
+            assert_eq!(expected, query);
+        }
+
+        #[rstest]
+        #[case::unknown_key("colour:red", ParseError::UnknownKey("colour".into()))]
+        #[case::unsupported_operator(
+            "track>rust",
+            ParseError::UnsupportedOperator { key: "track".into(), operator: Operator::Gt }
+        )]
+        #[case::invalid_count(
+            "stars>many",
+            ParseError::InvalidValue { key: "stars".into(), value: "many".into() }
+        )]
+        #[case::invalid_date(
+            "completed:yesterday",
+            ParseError::InvalidValue { key: "completed".into(), value: "yesterday".into() }
+        )]
+        #[case::empty_status(
+            "status:",
+            ParseError::InvalidValue { key: "status".into(), value: "".into() }
+        )]
+        #[case::empty_list(
+            "track:",
+            ParseError::InvalidValue { key: "track".into(), value: "".into() }
+        )]
+        #[case::unterminated_quote("\"hello", ParseError::UnterminatedQuote)]
+        fn test_errors(#[case] input: &str, #[case] expected: ParseError) {
+            assert_eq!(Err(expected), input.parse::<Query>());
+        }
+
+        #[test]
+        fn test_unknown_status() {
+            let query: Query = "status:done head_tests:skipped".parse().unwrap();
+
+            let expected = Query::builder()
+                .status(Status::Unknown("done".into()))
+                .predicate(Predicate::HeadTestsStatus(vec![tests::Status::Unknown(
+                    "skipped".into(),
+                )]))
+                .build();
+            assert_eq!(expected, query);
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        #[rstest]
+        #[case("", true)]
+        #[case("pOk", true)]
+        #[case("status:published iterations>3 stars>=1 completed:2025 track:rust,go", true)]
+        #[case("completed:2025-01-28", true)]
+        #[case("completed<2025", false)]
+        #[case("completed<=2025", true)]
+        #[case("-track:rust", false)]
+        #[case("mentoring:requested,finished", true)]
+        #[case("notifications:true", false)]
+        #[case("loc>300", false)]
+        #[case("exercise:forth", false)]
+        #[case("head_tests:failed", false)]
+        #[case("out_of_date:false", true)]
+        fn test_parsed(#[case] query: &str, #[case] expected: bool) {
+            let solution: Solution = serde_json::from_value(serde_json::json!({
+                "uuid": "00c717b68e1b4213b316df82636f5e0f",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/clechasseur",
+                "status": "published",
+                "mentoring_status": "finished",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 13,
+                "num_loc": 252,
+                "is_out_of_date": false,
+                "published_at": "2025-01-28T04:41:52Z",
+                "completed_at": "2025-01-28T04:41:52Z",
+                "updated_at": "2025-08-12T22:49:47Z",
+                "last_iterated_at": "2025-01-28T04:31:39Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "Poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            }))
+            .unwrap();
+
+            let query: Query = query.parse().unwrap();
+            assert_eq!(expected, query.matches(&solution));
+        }
+
+        #[test]
+        fn test_combinators() {
+            let solution: Solution = serde_json::from_value(serde_json::json!({
+                "uuid": "00c717b68e1b4213b316df82636f5e0f",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/clechasseur",
+                "status": "published",
+                "mentoring_status": "finished",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 13,
+                "num_loc": 252,
+                "is_out_of_date": false,
+                "published_at": "2025-01-28T04:41:52Z",
+                "completed_at": "2025-01-28T04:41:52Z",
+                "updated_at": "2025-08-12T22:49:47Z",
+                "last_iterated_at": "2025-01-28T04:31:39Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "Poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            }))
+            .unwrap();
+
+            let rust = Predicate::Track(vec!["rust".into()]);
+            let go = Predicate::Track(vec!["go".into()]);
+            let many_stars = Predicate::Count(CountField::Stars, Comparison::new(Operator::Gt, 10));
+
+            assert!(go.clone().or(rust.clone()).matches(&solution));
+            assert!(!rust.clone().and(many_stars.clone()).matches(&solution));
+            assert!(rust.clone().and(!many_stars).matches(&solution));
+            assert_eq!(rust, !!rust.clone());
+        }
+
+        #[test]
+        fn test_missing_values() {
+            let mut solution: Solution = serde_json::from_value(serde_json::json!({
+                "uuid": "00c717b68e1b4213b316df82636f5e0f",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/clechasseur",
+                "status": "published",
+                "mentoring_status": "finished",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 13,
+                "num_loc": 252,
+                "is_out_of_date": false,
+                "published_at": "2025-01-28T04:41:52Z",
+                "completed_at": "2025-01-28T04:41:52Z",
+                "updated_at": "2025-08-12T22:49:47Z",
+                "last_iterated_at": "2025-01-28T04:31:39Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "Poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            }))
+            .unwrap();
+            solution.num_loc = None;
+            solution.completed_at = None;
+
+            let query: Query = "loc>=0".parse().unwrap();
+            assert!(!query.matches(&solution));
+            let query: Query = "completed>2000".parse().unwrap();
+            assert!(!query.matches(&solution));
+        }
+    }
+
+    mod filters {
+        use super::*;
+
+        #[test]
+        fn test_push_down() {
+            let query: Query = "poker track:rust status:published mentoring:finished \
+                head_tests:passed out_of_date:true stars>1 rust track:go"
+                .parse()
+                .unwrap();
+
+            let filters = query.filters();
+            assert_eq!(Some("poker"), filters.criteria);
+            assert_eq!(Some("rust"), filters.track);
+            assert_eq!(Some(Status::Published), filters.status);
+            assert_eq!(Some(MentoringStatus::Finished), filters.mentoring_status);
+            assert_eq!(
+                vec![tests::Status::Passed],
+                filters.published_iteration_head_tests_statuses
+            );
+            assert_eq!(Some(true), filters.is_out_of_date);
+
+            let expected_local = vec![
+                Predicate::Count(CountField::Stars, Comparison::new(Operator::Gt, 1)),
+                Predicate::Criteria("rust".into()),
+                Predicate::Track(vec!["go".into()]),
+            ];
+            assert_eq!(
+                expected_local,
+                query
+                    .local_predicates()
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_multiple_values_are_local() {
+            let query: Query = "track:rust,go status:published,completed".parse().unwrap();
+
+            let filters = query.filters();
+            assert_eq!(None, filters.track);
+            assert_eq!(None, filters.status);
+            assert_eq!(2, query.local_predicates().len());
+        }
+    }
+
+    mod fetch {
+        use mini_exercism::api;
+        use mini_exercism::http;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        use super::*;
+
+        #[tokio::test]
+        #[test_log::test]
+        async fn test_fetch() {
+            let mock_server = MockServer::start().await;
+
+            let mut starred: Solution = serde_json::from_value(serde_json::json!({
+                "uuid": "00c717b68e1b4213b316df82636f5e0f",
+                "private_url": "https://exercism.org/tracks/rust/exercises/poker",
+                "public_url": "https://exercism.org/tracks/rust/exercises/poker/solutions/clechasseur",
+                "status": "published",
+                "mentoring_status": "finished",
+                "published_iteration_head_tests_status": "passed",
+                "has_notifications": false,
+                "num_views": 0,
+                "num_stars": 2,
+                "num_comments": 0,
+                "num_iterations": 13,
+                "num_loc": 252,
+                "is_out_of_date": false,
+                "published_at": "2025-01-28T04:41:52Z",
+                "completed_at": "2025-01-28T04:41:52Z",
+                "updated_at": "2025-08-12T22:49:47Z",
+                "last_iterated_at": "2025-01-28T04:31:39Z",
+                "exercise": {
+                    "slug": "poker",
+                    "title": "Poker",
+                    "icon_url": "https://assets.exercism.org/exercises/poker.svg"
+                },
+                "track": {
+                    "slug": "rust",
+                    "title": "Rust",
+                    "icon_url": "https://assets.exercism.org/tracks/rust.svg"
+                }
+            }))
+            .unwrap();
+            starred.uuid = "starred".into();
+            let mut unstarred = starred.clone();
+            unstarred.uuid = "unstarred".into();
+            unstarred.num_stars = 0;
+
+            for (page, results) in [("1", vec![starred.clone()]), ("2", vec![unstarred])] {
+                Mock::given(method(http::Method::GET))
+                    .and(path("/solutions"))
+                    .and(query_param("track_slug", "rust"))
+                    .and(query_param("page", page))
+                    .respond_with(ResponseTemplate::new(http::StatusCode::OK).set_body_json(
+                        serde_json::json!({
+                            "results": results,
+                            "meta": { "current_page": page.parse::<i64>().unwrap(), "total_count": 2, "total_pages": 2 },
+                        }),
+                    ))
+                    .mount(&mock_server)
+                    .await;
+            }
+
+            let client = api::v2::Client::builder()
+                .api_base_url(mock_server.uri().as_str())
+                .build()
+                .unwrap();
+            let query: Query = "track:rust stars>=1".parse().unwrap();
+            let solutions = query.fetch(&client, None).await.unwrap();
+
+            assert_eq!(vec![starred], solutions);
+        }
+    }
+}
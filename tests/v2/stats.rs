@@ -0,0 +1,212 @@
+use chrono::NaiveDate;
+use mini_exercism::api::v2::exercise::Difficulty;
+use mini_exercism::api::v2::exercises;
+use mini_exercism::api::v2::solution::{Solution, Status};
+use mini_exercism::api::v2::stats::{
+    DifficultyStats, Period, PeriodCount, Report, SolutionStats, TrackCompletion, completions,
+    exercises_by_difficulty, track_completion,
+};
+use mini_exercism::api::v2::track::Track;
+use serde_json::json;
+
+fn track(
+    name: &str,
+    num_exercises: usize,
+    num_completed_exercises: usize,
+    is_joined: bool,
+) -> Track {
+    serde_json::from_value(json!({
+        "slug": name,
+        "title": name.to_uppercase(),
+        "num_concepts": 0,
+        "num_exercises": num_exercises,
+        "web_url": format!("https://exercism.org/tracks/{name}"),
+        "icon_url": format!("https://assets.exercism.org/tracks/{name}.svg"),
+        "tags": [],
+        "links": {
+            "self": format!("https://exercism.org/tracks/{name}"),
+            "exercises": format!("https://exercism.org/tracks/{name}/exercises"),
+            "concepts": format!("https://exercism.org/tracks/{name}/concepts"),
+        },
+        "is_joined": is_joined,
+        "num_completed_exercises": num_completed_exercises,
+    }))
+    .unwrap()
+}
+
+fn solution(
+    exercise: &str,
+    status: Status,
+    num_iterations: i32,
+    head_tests_status: &str,
+    completed_at: Option<&str>,
+) -> Solution {
+    serde_json::from_value(json!({
+        "uuid": format!("{exercise}-uuid"),
+        "private_url": format!("https://exercism.org/tracks/rust/exercises/{exercise}"),
+        "public_url": format!("https://exercism.org/tracks/rust/exercises/{exercise}/solutions/user"),
+        "status": status,
+        "mentoring_status": "none",
+        "published_iteration_head_tests_status": head_tests_status,
+        "has_notifications": false,
+        "num_views": 0,
+        "num_stars": 0,
+        "num_comments": 0,
+        "num_iterations": num_iterations,
+        "is_out_of_date": false,
+        "completed_at": completed_at,
+        "updated_at": "2025-01-01T00:00:00Z",
+        "exercise": {
+            "slug": exercise,
+            "title": exercise.to_uppercase(),
+            "icon_url": format!("https://assets.exercism.org/exercises/{exercise}.svg"),
+        },
+        "track": {
+            "slug": "rust",
+            "title": "Rust",
+            "icon_url": "https://assets.exercism.org/tracks/rust.svg",
+        },
+    }))
+    .unwrap()
+}
+
+fn solutions() -> Vec<Solution> {
+    vec![
+        solution("hello-world", Status::Published, 1, "passed", Some("2025-01-28T04:41:52Z")),
+        solution("clock", Status::Completed, 3, "failed", Some("2025-02-01T10:00:00Z")),
+        solution("poker", Status::Published, 4, "errored", Some("2025-02-03T10:00:00.000Z")),
+        solution("forth", Status::Iterated, 2, "not_queued", None),
+        solution("luhn", Status::Started, 0, "not_queued", None),
+    ]
+}
+
+fn exercises() -> exercises::Response {
+    let exercise = |name: &str, difficulty: &str| {
+        json!({
+            "slug": name,
+            "type": "practice",
+            "title": name.to_uppercase(),
+            "icon_url": format!("https://assets.exercism.org/exercises/{name}.svg"),
+            "difficulty": difficulty,
+            "blurb": "",
+            "is_external": false,
+            "is_unlocked": true,
+            "is_recommended": false,
+            "links": { "self": format!("/tracks/rust/exercises/{name}") },
+        })
+    };
+
+    serde_json::from_value(json!({
+        "exercises": [
+            exercise("hello-world", "easy"),
+            exercise("clock", "medium"),
+            exercise("forth", "medium"),
+            exercise("poker", "hard"),
+            exercise("luhn", "easy"),
+            exercise("anagram", "easy"),
+        ],
+        "solutions": solutions(),
+    }))
+    .unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_track_completion() {
+    let tracks =
+        [track("rust", 100, 25, true), track("go", 50, 0, false), track("cpp", 0, 0, true)];
+
+    let expected = vec![
+        TrackCompletion {
+            name: "rust".into(),
+            title: "RUST".into(),
+            num_exercises: 100,
+            num_completed_exercises: 25,
+            completion: 0.25,
+        },
+        TrackCompletion {
+            name: "cpp".into(),
+            title: "CPP".into(),
+            num_exercises: 0,
+            num_completed_exercises: 0,
+            completion: 0.0,
+        },
+    ];
+    assert_eq!(expected, track_completion(&tracks));
+}
+
+#[test]
+fn test_solution_stats() {
+    let expected = SolutionStats {
+        num_solutions: 5,
+        num_completed: 3,
+        num_published: 2,
+        published_ratio: 2.0 / 3.0,
+        average_iterations: 2.0,
+        num_failing_head_tests: 2,
+        failing_head_tests_ratio: 2.0 / 3.0,
+    };
+    assert_eq!(expected, SolutionStats::compute(&solutions()));
+    assert_eq!(SolutionStats::default(), SolutionStats::compute(&[]));
+}
+
+#[test]
+fn test_exercises_by_difficulty() {
+    let expected = vec![
+        DifficultyStats { difficulty: Difficulty::Easy, num_exercises: 3, num_completed: 1 },
+        DifficultyStats { difficulty: Difficulty::Medium, num_exercises: 2, num_completed: 1 },
+        DifficultyStats { difficulty: Difficulty::Hard, num_exercises: 1, num_completed: 1 },
+    ];
+    assert_eq!(expected, exercises_by_difficulty(&[exercises()]));
+}
+
+#[test]
+fn test_completions() {
+    let weekly = vec![
+        PeriodCount { start: date(2025, 1, 27), count: 2 },
+        PeriodCount { start: date(2025, 2, 3), count: 1 },
+    ];
+    assert_eq!(weekly, completions(&solutions(), Period::Week));
+
+    let mut solutions = solutions();
+    solutions.push(solution(
+        "anagram",
+        Status::Completed,
+        1,
+        "passed",
+        Some("2025-04-30T23:59:59Z"),
+    ));
+    let monthly = vec![
+        PeriodCount { start: date(2025, 1, 1), count: 1 },
+        PeriodCount { start: date(2025, 2, 1), count: 2 },
+        PeriodCount { start: date(2025, 3, 1), count: 0 },
+        PeriodCount { start: date(2025, 4, 1), count: 1 },
+    ];
+    assert_eq!(monthly, completions(&solutions, Period::Month));
+
+    assert!(completions(&[], Period::Week).is_empty());
+}
+
+#[test]
+fn test_period_start_of() {
+    assert_eq!(date(2025, 1, 27), Period::Week.start_of(date(2025, 2, 2)));
+    assert_eq!(date(2025, 2, 3), Period::Week.start_of(date(2025, 2, 3)));
+    assert_eq!(date(2025, 2, 1), Period::Month.start_of(date(2025, 2, 28)));
+}
+
+#[test]
+fn test_report() {
+    let report = Report::compute(&[track("rust", 100, 3, true)], &[exercises()], &solutions());
+    assert_eq!(1, report.tracks.len());
+    assert_eq!(3, report.solutions.num_completed);
+    assert_eq!(3, report.difficulties.len());
+    assert_eq!(2, report.weekly_completions.len());
+    assert_eq!(2, report.monthly_completions.len());
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json!({ "start": "2025-01-01", "count": 1 }), json["monthly_completions"][0]);
+    assert_eq!(json!("easy"), json["difficulties"][0]["difficulty"]);
+}
@@ -74,7 +74,7 @@ mod analyzer_comment {
             }"#;
 
             let expected = AnalyzerComment {
-                comment_type: AnalyzerCommentType::Unknown,
+                comment_type: AnalyzerCommentType::Unknown("mglw'nafh".into()),
                 html: "<p>Cahf ah nafl mglw'nafh hh' ahor syha'h ah'legeth, ng llll or'azath syha'hnahh n'ghftephai n'gha ahornah ah'mglw'nafh</p>".into(),
             };
             let actual: AnalyzerComment = serde_json::from_str(json).unwrap();
@@ -146,3 +146,221 @@ mod feedback_author {
         }
     }
 }
+
+mod render {
+    use mini_exercism::api::v2::submission::analysis::render::{Format, render_html};
+
+    const HTML: &str = "<h2>Use <em>iterators</em></h2>\n\
+        <p>Instead of a <strong>loop</strong>, call <code>sum</code>:</p>\n\
+        <pre><code class=\"language-rust\">let total: i32 = values.iter().sum();\n</code></pre>\n\
+        <ol start=\"2\">\n<li>See <a href=\"https://doc.rust-lang.org/std/iter/\">the docs</a></li>\n\
+        <li>Nested:\n<ul>\n<li>one</li>\n<li>two</li>\n</ul>\n</li>\n</ol>\n\
+        <blockquote>\n<p>Don&#39;t repeat yourself &amp; keep it simple</p>\n</blockquote>\n";
+
+    #[test]
+    fn test_plain_text() {
+        let expected = "\
+Use iterators
+
+Instead of a loop, call sum:
+
+    let total: i32 = values.iter().sum();
+
+2. See the docs (https://doc.rust-lang.org/std/iter/)
+3. Nested:
+   - one
+   - two
+
+> Don't repeat yourself & keep it simple";
+        assert_eq!(expected, render_html(HTML, Format::PlainText));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let expected = "\
+## Use _iterators_
+
+Instead of a **loop**, call `sum`:
+
+```rust
+let total: i32 = values.iter().sum();
+```
+
+2. See [the docs](https://doc.rust-lang.org/std/iter/)
+3. Nested:
+   - one
+   - two
+
+> Don't repeat yourself & keep it simple";
+        assert_eq!(expected, render_html(HTML, Format::Markdown));
+    }
+
+    #[test]
+    fn test_ansi() {
+        let expected = "\
+\x1b[1mUse \x1b[3miterators\x1b[23m\x1b[22m
+
+Instead of a \x1b[1mloop\x1b[22m, call \x1b[36msum\x1b[39m:
+
+    \x1b[36mlet total: i32 = values.iter().sum();\x1b[39m
+
+2. See \x1b[4mthe docs\x1b[24m (https://doc.rust-lang.org/std/iter/)
+3. Nested:
+   - one
+   - two
+
+> Don't repeat yourself & keep it simple";
+        assert_eq!(expected, render_html(HTML, Format::Ansi));
+    }
+
+    #[test]
+    fn test_markdown_escaping() {
+        assert_eq!(
+            "Use `snake_case` for snake\\_case \\*names\\*\\\nplease",
+            render_html(
+                "<p>Use <code>snake_case</code> for snake_case *names*<br>please</p>",
+                Format::Markdown
+            )
+        );
+        assert_eq!("``a`b``", render_html("<code>a`b</code>", Format::Markdown));
+    }
+
+    #[test]
+    fn test_whitespace() {
+        assert_eq!(
+            "Some text\nafter a break",
+            render_html("\n  <p>Some\n   text<br/>\n after a  break</p>  \n", Format::PlainText)
+        );
+        assert_eq!("", render_html("  \n", Format::PlainText));
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!("éx", render_html("é<b>x</b>", Format::PlainText));
+        assert_eq!("a\n\n→ b", render_html("<p>a</p>→ b", Format::PlainText));
+        assert_eq!("**ü**ñ", render_html("<strong>ü</strong>ñ", Format::Markdown));
+    }
+
+    #[test]
+    fn test_control_characters() {
+        let html = "<p>a\x1b[2Jb&#27;c</p><pre><code>x\x07\ty\n</code></pre><code>\u{9b}z</code>";
+
+        assert_eq!("a[2Jbc\n\n    x\ty\n\nz", render_html(html, Format::PlainText));
+        assert_eq!(
+            "a[2Jbc\n\n    \x1b[36mx\ty\x1b[39m\n\n\x1b[36mz\x1b[39m",
+            render_html(html, Format::Ansi)
+        );
+    }
+
+    #[test]
+    fn test_list_start_overflow() {
+        let html = "<ol start=\"18446744073709551615\"><li>a</li><li>b</li></ol>";
+
+        assert_eq!(
+            "18446744073709551615. a\n18446744073709551615. b",
+            render_html(html, Format::PlainText)
+        );
+    }
+
+    mod feedback {
+        use mini_exercism::api::v2::submission::analysis::AnalyzerCommentType::{
+            Actionable, Celebratory, Essential,
+        };
+        use mini_exercism::api::v2::submission::analysis::render::Format;
+        use mini_exercism::api::v2::submission::analysis::{
+            AnalyzerComment, AnalyzerFeedback, FeedbackAuthor, RepresenterFeedback,
+        };
+
+        fn feedback() -> AnalyzerFeedback {
+            AnalyzerFeedback {
+                summary: Some("<p>Almost there!</p>\n".into()),
+                comments: vec![
+                    AnalyzerComment {
+                        comment_type: Celebratory,
+                        html: "<p>Nice use of <code>match</code>.</p>\n".into(),
+                    },
+                    AnalyzerComment {
+                        comment_type: Actionable,
+                        html: "<p>Avoid <code>clone</code>.</p>\n".into(),
+                    },
+                    AnalyzerComment {
+                        comment_type: Essential,
+                        html: "<p>Do not <em>panic</em>.</p>\n".into(),
+                    },
+                    AnalyzerComment {
+                        comment_type: Actionable,
+                        html: "<p>Prefer <code>&amp;str</code>.</p>\n".into(),
+                    },
+                ],
+            }
+        }
+
+        #[test]
+        fn test_comments_by_type() {
+            let feedback = feedback();
+            let groups: Vec<_> = feedback
+                .comments_by_type()
+                .into_iter()
+                .map(|(comment_type, comments)| {
+                    (
+                        comment_type,
+                        comments
+                            .iter()
+                            .map(|comment| comment.render(Format::PlainText))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect();
+
+            let expected = vec![
+                (Essential, vec!["Do not panic.".to_string()]),
+                (Actionable, vec!["Avoid clone.".to_string(), "Prefer &str.".to_string()]),
+                (Celebratory, vec!["Nice use of match.".to_string()]),
+            ];
+            assert_eq!(expected, groups);
+        }
+
+        #[test]
+        fn test_render() {
+            let expected = "\
+Almost there!
+
+## Essential
+
+Do not _panic_.
+
+## Actionable
+
+Avoid `clone`.
+
+Prefer `&str`.
+
+## Celebratory
+
+Nice use of `match`.";
+            assert_eq!(expected, feedback().render(Format::Markdown));
+
+            let no_summary = AnalyzerFeedback { summary: None, comments: vec![] };
+            assert_eq!(None, no_summary.render_summary(Format::PlainText));
+            assert_eq!("", no_summary.render(Format::PlainText));
+        }
+
+        #[test]
+        fn test_representer_feedback() {
+            let feedback = RepresenterFeedback {
+                html: "<p>Great <strong>job</strong>!</p>\n".into(),
+                author: FeedbackAuthor {
+                    name: "Jane Doe".into(),
+                    reputation: 42,
+                    flair: None,
+                    avatar_url: "https://avatars.example.com/jane".into(),
+                    profile_url: None,
+                },
+                editor: None,
+            };
+
+            assert_eq!("Great job!", feedback.render(Format::PlainText));
+            assert_eq!("Great \x1b[1mjob\x1b[22m!", feedback.render(Format::Ansi));
+        }
+    }
+}
@@ -92,3 +92,141 @@ mod file {
         }
     }
 }
+
+mod verify {
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::api::v2::submission::files;
+    use mini_exercism::api::v2::submission::files::File;
+
+    const CONTENT: &str = "[package]\nedition = \"2021\"\nname = \"poker\"\nversion = \"1.1.0\"\n\n[dependencies]\nderivative = \"2.2.0\"\nstrum = \"0.24.1\"\nstrum_macros = \"0.24.3\"\nthiserror = \"1.0.40\"\n";
+    const DIGEST: &str = "9ad1c8abd08fcc3111eaf728a9fb1f3717d10ad8";
+
+    #[test]
+    fn test_compute_digest() {
+        assert_eq!(DIGEST, File::compute_digest(CONTENT));
+    }
+
+    #[test]
+    fn test_valid() {
+        let valid =
+            File { filename: "Cargo.toml".into(), content: CONTENT.into(), digest: DIGEST.into() };
+        assert!(valid.has_valid_digest());
+        assert_matches!(valid.verify(), Ok(()));
+
+        let uppercase = File {
+            filename: "Cargo.toml".into(),
+            content: CONTENT.into(),
+            digest: DIGEST.to_uppercase(),
+        };
+        assert!(uppercase.has_valid_digest());
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let corrupted = File {
+            filename: "Cargo.toml".into(),
+            content: "[package]\n".into(),
+            digest: DIGEST.into(),
+        };
+        assert!(!corrupted.has_valid_digest());
+        assert_matches!(
+            corrupted.verify(),
+            Err(Error::DigestMismatch { filename, expected, actual })
+                if filename == "Cargo.toml" && expected == DIGEST && actual == File::compute_digest("[package]\n")
+        );
+
+        let valid =
+            File { filename: "Cargo.toml".into(), content: CONTENT.into(), digest: DIGEST.into() };
+        let response = files::Response { files: vec![valid, corrupted.clone()] };
+        assert_eq!(vec![&corrupted], response.corrupted_files().collect::<Vec<_>>());
+        assert_matches!(response.verify(), Err(Error::DigestMismatch { .. }));
+    }
+}
+
+mod diff {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use assert_matches::assert_matches;
+    use mini_exercism::Error;
+    use mini_exercism::api::v2::submission::files;
+    use mini_exercism::api::v2::submission::files::{File, FilesDiff};
+
+    fn write(dir: &Path, path: &str, contents: &str) {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn response() -> files::Response {
+        files::Response {
+            files: vec![
+                File {
+                    filename: "src/lib.rs".into(),
+                    content: "mod detail;\n".into(),
+                    digest: File::compute_digest("mod detail;\n"),
+                },
+                File {
+                    filename: "src/detail.rs".into(),
+                    content: "pub struct Hand;\n".into(),
+                    digest: File::compute_digest("pub struct Hand;\n"),
+                },
+                File {
+                    filename: "Cargo.toml".into(),
+                    content: "[package]\nname = \"poker\"\n".into(),
+                    digest: File::compute_digest("[package]\nname = \"poker\"\n"),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_diff_with_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            ".exercism/config.json",
+            r#"{
+                "files": {
+                    "solution": ["src/lib.rs", "Cargo.toml", "src/extra.rs"],
+                    "test": ["tests/poker.rs"]
+                },
+                "blurb": "Pick the best hand(s) from a list of poker hands."
+            }"#,
+        );
+        write(dir.path(), "src/lib.rs", "mod detail;\nmod extra;\n");
+        write(dir.path(), "src/detail.rs", "pub struct Hand;\n");
+        write(dir.path(), "src/extra.rs", "");
+        write(dir.path(), "tests/poker.rs", "");
+
+        let expected = FilesDiff {
+            added: vec![PathBuf::from("src/extra.rs")],
+            removed: vec![PathBuf::from("Cargo.toml")],
+            modified: vec![PathBuf::from("src/lib.rs")],
+        };
+        let actual = response().diff_with_dir(dir.path()).unwrap();
+        assert_eq!(expected, actual);
+        assert!(!actual.is_empty());
+    }
+
+    #[test]
+    fn test_diff_with_files_identical() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "src/lib.rs", "mod detail;\n");
+        write(dir.path(), "src/detail.rs", "pub struct Hand;\n");
+        write(dir.path(), "Cargo.toml", "[package]\nname = \"poker\"\n");
+
+        let actual = response()
+            .diff_with_files(dir.path(), ["src/lib.rs"])
+            .unwrap();
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_diff_with_dir_no_config() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert_matches!(response().diff_with_dir(dir.path()), Err(Error::TrackRepoIoError { .. }));
+    }
+}
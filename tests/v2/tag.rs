@@ -0,0 +1,63 @@
+#[allow(clippy::module_inception)]
+mod tag {
+    mod from_str {
+        use mini_exercism::api::v2::tag::{
+            ExecutionMode, Paradigm, Platform, Runtime, Tag, Typing, UsedFor,
+        };
+        use rstest::rstest;
+
+        #[rstest]
+        #[case::paradigm_slug("paradigm/object_oriented", Tag::Paradigm(Paradigm::ObjectOriented))]
+        #[case::paradigm_title("Object-oriented", Tag::Paradigm(Paradigm::ObjectOriented))]
+        #[case::typing_slug("typing/weak", Tag::Typing(Typing::Weak))]
+        #[case::execution_mode_slug(
+            "execution_mode/interpreted",
+            Tag::ExecutionMode(ExecutionMode::Interpreted)
+        )]
+        #[case::platform_title("Mac OSX", Tag::Platform(Platform::Mac))]
+        #[case::platform_title_ignore_case("ios", Tag::Platform(Platform::Ios))]
+        #[case::runtime_title("JVM (Java)", Tag::Runtime(Runtime::Jvm))]
+        #[case::used_for_slug("used_for/web_development", Tag::UsedFor(UsedFor::WebDevelopment))]
+        #[case::unknown_category("flavour/spicy", Tag::Unknown("flavour/spicy".into()))]
+        #[case::unknown_value("paradigm/funcional", Tag::Unknown("paradigm/funcional".into()))]
+        #[case::unknown_title("Quantum", Tag::Unknown("Quantum".into()))]
+        fn test_parse(#[case] input: &str, #[case] expected: Tag) {
+            assert_eq!(expected, input.parse::<Tag>().unwrap());
+            assert_eq!(expected, Tag::from(input.to_string()));
+        }
+    }
+
+    mod accessors {
+        use mini_exercism::api::v2::tag::{Runtime, Tag, TagCategory};
+
+        #[test]
+        fn test_known() {
+            let tag = Tag::from(Runtime::Clr);
+            assert_eq!(Some(TagCategory::Runtime), tag.category());
+            assert_eq!("clr", tag.value());
+            assert_eq!("CLR (.NET)", tag.title());
+            assert_eq!("runtime/clr", tag.to_string());
+        }
+
+        #[test]
+        fn test_unknown() {
+            let tag = Tag::Unknown("Quantum".into());
+            assert_eq!(None, tag.category());
+            assert_eq!("Quantum", tag.value());
+            assert_eq!("Quantum", tag.title());
+            assert_eq!("Quantum", tag.to_string());
+        }
+    }
+
+    mod serde {
+        use mini_exercism::api::v2::tag::{Paradigm, Tag};
+
+        #[test]
+        fn test_round_trip() {
+            let tags = vec![Tag::Paradigm(Paradigm::Logic), Tag::Unknown("Quantum".into())];
+            let json = serde_json::to_string(&tags).unwrap();
+            assert_eq!(r#"["paradigm/logic","Quantum"]"#, json);
+            assert_eq!(tags, serde_json::from_str::<Vec<Tag>>(&json).unwrap());
+        }
+    }
+}
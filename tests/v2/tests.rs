@@ -0,0 +1,183 @@
+mod run {
+    mod response {
+        mod deserialize {
+            use mini_exercism::api::v2::tests;
+            use mini_exercism::api::v2::tests::run::{Response, Status, Test, TestRun};
+
+            #[test]
+            fn test_all() {
+                let json = r#"{
+                    "test_run": {
+                        "id": 12345,
+                        "submission_uuid": "4da3f19906214f678d5aadaea8635250",
+                        "version": 3,
+                        "status": "fail",
+                        "message": null,
+                        "message_html": null,
+                        "output": null,
+                        "output_html": null,
+                        "tests": [
+                            {
+                                "name": "test_add",
+                                "status": "fail",
+                                "test_code": "assert_eq!(2, add(1, 1));",
+                                "message": "left: 2\nright: 3",
+                                "message_html": "left: 2<br>right: 3",
+                                "expected": null,
+                                "output": null,
+                                "output_html": null,
+                                "task_id": 1
+                            }
+                        ],
+                        "tests_status": "failed",
+                        "highlightjs_language": "rust",
+                        "links": {
+                            "self": "https://exercism.org/api/v2/solutions/00c717b68e1b4213b316df82636f5e0f/submissions/4da3f19906214f678d5aadaea8635250/test_run"
+                        }
+                    }
+                }"#;
+
+                let expected = Response {
+                    test_run: Some(TestRun {
+                        submission_uuid: "4da3f19906214f678d5aadaea8635250".into(),
+                        version: 3,
+                        status: Status::Fail,
+                        message: None,
+                        message_html: None,
+                        output: None,
+                        output_html: None,
+                        tests: vec![Test {
+                            name: "test_add".into(),
+                            status: Status::Fail,
+                            test_code: Some("assert_eq!(2, add(1, 1));".into()),
+                            message: Some("left: 2\nright: 3".into()),
+                            message_html: Some("left: 2<br>right: 3".into()),
+                            expected: None,
+                            output: None,
+                            output_html: None,
+                            task_id: Some(1),
+                        }],
+                        tests_status: tests::Status::Failed,
+                        highlightjs_language: Some("rust".into()),
+                    }),
+                };
+                let actual: Response = serde_json::from_str(json).unwrap();
+                assert_eq!(expected, actual);
+            }
+
+            #[test]
+            fn test_no_test_run() {
+                let actual: Response = serde_json::from_str("{}").unwrap();
+                assert_eq!(None, actual.test_run);
+            }
+        }
+    }
+
+    mod test_run {
+        mod to_results {
+            use mini_exercism::api::v2::tests;
+            use mini_exercism::api::v2::tests::run::{Status, Test, TestRun};
+            use mini_exercism::tooling::test_runner;
+
+            #[test]
+            fn test_convert() {
+                let test_run = TestRun {
+                    submission_uuid: "4da3f19906214f678d5aadaea8635250".into(),
+                    version: 2,
+                    status: Status::Timeout,
+                    message: Some("took too long".into()),
+                    message_html: None,
+                    output: None,
+                    output_html: None,
+                    tests: vec![Test {
+                        name: "test_add".into(),
+                        status: Status::Pass,
+                        test_code: Some("add()".into()),
+                        message: None,
+                        message_html: None,
+                        expected: None,
+                        output: Some("hi".into()),
+                        output_html: None,
+                        task_id: None,
+                    }],
+                    tests_status: tests::Status::Errored,
+                    highlightjs_language: None,
+                };
+
+                let expected = test_runner::Results {
+                    version: 2,
+                    status: test_runner::Status::Error,
+                    message: Some("took too long".into()),
+                    tests: vec![test_runner::TestResult {
+                        name: "test_add".into(),
+                        status: test_runner::Status::Pass,
+                        message: None,
+                        output: Some("hi".into()),
+                        test_code: Some("add()".into()),
+                        task_id: None,
+                    }],
+                };
+                assert_eq!(expected, test_run.to_results());
+            }
+
+            #[test]
+            fn test_queued() {
+                let test_run = TestRun {
+                    submission_uuid: "4da3f19906214f678d5aadaea8635250".into(),
+                    version: 3,
+                    status: Status::Queued,
+                    message: None,
+                    message_html: None,
+                    output: None,
+                    output_html: None,
+                    tests: vec![],
+                    tests_status: tests::Status::Queued,
+                    highlightjs_language: None,
+                };
+
+                let results = test_run.to_results();
+                assert_eq!(test_runner::Status::Unknown("queued".into()), results.status);
+                let json = serde_json::to_value(&results).unwrap();
+                assert_eq!("queued", json["status"]);
+            }
+        }
+    }
+}
+
+mod status {
+    mod parse {
+        use mini_exercism::api::v2::tests::Status;
+        use mini_exercism::api::v2::tests::run;
+
+        #[test]
+        fn test_known() {
+            assert_eq!(Status::Passed, "passed".parse::<Status>().unwrap());
+            assert_eq!(run::Status::Timeout, "timeout".parse::<run::Status>().unwrap());
+        }
+
+        #[test]
+        fn test_unknown() {
+            assert_eq!(Status::Unknown("weird".into()), "weird".parse::<Status>().unwrap());
+            assert_eq!(Status::Unknown("unknown".into()), "unknown".parse::<Status>().unwrap());
+            assert_eq!("weird", Status::Unknown("weird".into()).to_string());
+            assert_eq!(
+                run::Status::Unknown("unknown".into()),
+                "unknown".parse::<run::Status>().unwrap()
+            );
+        }
+    }
+
+    mod deserialize {
+        use mini_exercism::api::v2::tests::Status;
+
+        #[test]
+        fn test_invalid() {
+            let error = serde_json::from_str::<Status>("42").unwrap_err();
+            assert!(
+                error
+                    .to_string()
+                    .starts_with("invalid tests status: expected a string")
+            );
+        }
+    }
+}
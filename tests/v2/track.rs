@@ -168,6 +168,65 @@ mod track {
             assert_eq!(expected, actual);
         }
     }
+
+    mod tags {
+        use mini_exercism::api::v2::tag::{Paradigm, Platform, Tag, TagCategory, Typing};
+        use mini_exercism::api::v2::track::{Links, Track};
+
+        fn track() -> Track {
+            Track {
+                name: "cpp".into(),
+                title: "C++".into(),
+                num_concepts: 14,
+                num_exercises: 73,
+                web_url: "https://exercism.org/tracks/cpp".into(),
+                icon_url: "https://dg8krxphbh767.cloudfront.net/tracks/cpp.svg".into(),
+                tags: vec![
+                    "Object-oriented".into(),
+                    "Static".into(),
+                    "Strong".into(),
+                    "Mac OSX".into(),
+                    "Quantum".into(),
+                ],
+                links: Links {
+                    self_url: "https://exercism.org/tracks/cpp".into(),
+                    exercises: "https://exercism.org/tracks/cpp/exercises".into(),
+                    concepts: "https://exercism.org/tracks/cpp/concepts".into(),
+                },
+                is_joined: false,
+                num_learnt_concepts: 0,
+                num_completed_exercises: 0,
+            }
+        }
+
+        #[test]
+        fn test_typed_tags() {
+            let expected = vec![
+                Tag::Paradigm(Paradigm::ObjectOriented),
+                Tag::Typing(Typing::Static),
+                Tag::Typing(Typing::Strong),
+                Tag::Platform(Platform::Mac),
+                Tag::Unknown("Quantum".into()),
+            ];
+            assert_eq!(expected, track().typed_tags().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn test_tags_in() {
+            let expected = vec![Tag::Typing(Typing::Static), Tag::Typing(Typing::Strong)];
+            assert_eq!(expected, track().tags_in(TagCategory::Typing).collect::<Vec<_>>());
+            assert_eq!(0, track().tags_in(TagCategory::Runtime).count());
+        }
+
+        #[test]
+        fn test_has_tag() {
+            let track = track();
+            assert!(track.has_tag(Paradigm::ObjectOriented));
+            assert!(track.has_tag("platform/mac"));
+            assert!(track.has_tag("Quantum"));
+            assert!(!track.has_tag(Paradigm::Functional));
+        }
+    }
 }
 
 mod links {
@@ -1,6 +1,7 @@
 mod filters {
     mod builder {
         use assert_matches::assert_matches;
+        use mini_exercism::api::v2::tag::{ExecutionMode, Paradigm, Tag};
         use mini_exercism::api::v2::tracks::Filters;
         use mini_exercism::api::v2::tracks::StatusFilter::Joined;
 
@@ -10,11 +11,16 @@ mod filters {
             let filters = Filters::builder()
                 .criteria("csharp")
                 .tag("Functional")
-                .tag("Compiled")
+                .tag(ExecutionMode::Compiled)
+                .tag("used_for/scrips")
                 .status(Joined)
                 .build();
 
-            let expected_tags = vec!["Functional", "Compiled"];
+            let expected_tags = vec![
+                Tag::Paradigm(Paradigm::Functional),
+                Tag::ExecutionMode(ExecutionMode::Compiled),
+                Tag::Unknown("used_for/scrips".into()),
+            ];
             assert_matches!(filters.criteria, Some(criteria) if criteria == "csharp");
             assert_eq!(expected_tags, filters.tags);
             assert_matches!(filters.status, Some(status) if status == Joined);